@@ -0,0 +1,100 @@
+//! Lorenz-curve analytics for TREE assets across a cohort.
+//!
+//! Pure functions over already-logged values; intended for fairness panels
+//! and offline visualization, never for gating.
+
+/// Sample the Lorenz curve of `values` at `points` evenly spaced population
+/// fractions, returning `(cumulative_population, cumulative_share)` pairs that
+/// run from (0, 0) to (1, 1).
+///
+/// - NaN values are dropped and negatives treated as 0 (TREE assets are >= 0).
+/// - An empty cohort yields an empty curve.
+/// - A cohort whose total is 0 is treated as perfectly equal (the diagonal).
+/// - `points` below 2 is raised to 2 so both endpoints are always present.
+pub fn lorenz_curve(values: &[f32], points: usize) -> Vec<(f32, f32)> {
+    let mut sorted: Vec<f32> = values
+        .iter()
+        .copied()
+        .filter(|v| !v.is_nan())
+        .map(|v| v.max(0.0))
+        .collect();
+    if sorted.is_empty() {
+        return Vec::new();
+    }
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let points = points.max(2);
+    let n = sorted.len();
+    let total: f32 = sorted.iter().sum();
+
+    // cumulative[k] = share held by the poorest k members.
+    let mut cumulative = Vec::with_capacity(n + 1);
+    cumulative.push(0f32);
+    let mut running = 0f32;
+    for v in &sorted {
+        running += v;
+        cumulative.push(if total > 0.0 { running / total } else { 0.0 });
+    }
+
+    (0..points)
+        .map(|i| {
+            let p = i as f32 / (points - 1) as f32;
+            if total <= 0.0 {
+                return (p, p);
+            }
+            let x = p * n as f32;
+            let k = (x.floor() as usize).min(n);
+            let share = if k >= n {
+                1.0
+            } else {
+                cumulative[k] + (x - k as f32) * sorted[k] / total
+            };
+            (p, share.clamp(0.0, 1.0))
+        })
+        .collect()
+}
+
+/// Gini coefficient derived from a sampled Lorenz curve: 1 - 2·(area under curve),
+/// using the trapezoid rule. Returns `None` for an empty curve.
+pub fn gini_from_lorenz(curve: &[(f32, f32)]) -> Option<f32> {
+    if curve.is_empty() {
+        return None;
+    }
+    let area: f32 = curve
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0) * (w[0].1 + w[1].1) * 0.5)
+        .sum();
+    Some((1.0 - 2.0 * area).clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_cohort_is_diagonal_with_zero_gini() {
+        let curve = lorenz_curve(&[0.4, 0.4, 0.4, 0.4], 5);
+        assert_eq!(curve.len(), 5);
+        for (p, share) in &curve {
+            assert!((p - share).abs() < 1e-6);
+        }
+        assert!(gini_from_lorenz(&curve).unwrap().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_unequal_cohort_bows_below_diagonal() {
+        let curve = lorenz_curve(&[0.0, 0.0, 0.0, 1.0], 5);
+        assert_eq!(curve.first(), Some(&(0.0, 0.0)));
+        assert_eq!(curve.last(), Some(&(1.0, 1.0)));
+        assert!(curve[3].1 < 1e-6);
+        assert!((gini_from_lorenz(&curve).unwrap() - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty_and_zero_total_inputs() {
+        assert!(lorenz_curve(&[], 10).is_empty());
+        assert_eq!(gini_from_lorenz(&[]), None);
+        let zeros = lorenz_curve(&[0.0, 0.0, f32::NAN], 3);
+        assert_eq!(zeros, vec![(0.0, 0.0), (0.5, 0.5), (1.0, 1.0)]);
+    }
+}
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 
 /// Minimal view of capability tiers, aligned with your CapabilityState.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CapabilityTier {
     ModelOnly,
     LabBench,
@@ -21,7 +22,7 @@ pub struct PolicyStackView {
 }
 
 /// Minimal role tag to help Comparables(s, s', t) if needed.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RoleTag {
     Teacher,
     Learner,
@@ -61,6 +62,101 @@ pub struct UnfairDrainConfig {
     pub delta_unfair: f32,
     /// Minimum overload fraction required to flag unfair drain.
     pub overload_frac_min: f32,
+    /// When set, budgets are an exponential moving average with this weight
+    /// on the newest frame instead of the flat window mean. Must be in (0, 1].
+    #[serde(default)]
+    pub ema_alpha: Option<f32>,
+    /// Which snapshot fields peers must share to be comparable.
+    #[serde(default)]
+    pub comparable: ComparableKey,
+    /// Other comparable subjects required in the window before a flag may
+    /// assert unfairness. The default 0 never suppresses a flag.
+    #[serde(default)]
+    pub min_peer_count: usize,
+}
+
+/// Field mask for Comparables(s, s', t): each `true` field must match exactly.
+/// Default is tier + jurisdiction tag + task tag, role ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComparableKey {
+    pub tier: bool,
+    pub jurisdiction: bool,
+    pub task: bool,
+    pub role: bool,
+}
+
+impl Default for ComparableKey {
+    fn default() -> Self {
+        ComparableKey {
+            tier: true,
+            jurisdiction: true,
+            task: true,
+            role: false,
+        }
+    }
+}
+
+impl ComparableKey {
+    pub fn matches(&self, a: &SubjectSnapshot, b: &SubjectSnapshot) -> bool {
+        self.group_key(a) == self.group_key(b)
+    }
+
+    /// Peer-grouping key: masked-out fields collapse to `None`.
+    fn group_key<'a>(&self, s: &'a SubjectSnapshot) -> PeerGroupKey<'a> {
+        (
+            Some(s.capability_tier).filter(|_| self.tier),
+            Some(s.policy_view.jurisdiction_tag.as_str()).filter(|_| self.jurisdiction),
+            Some(s.task_tag.as_str()).filter(|_| self.task),
+            Some(s.role).filter(|_| self.role),
+        )
+    }
+}
+
+type PeerGroupKey<'a> = (
+    Option<CapabilityTier>,
+    Option<&'a str>,
+    Option<&'a str>,
+    Option<RoleTag>,
+);
+
+/// Rejected `UnfairDrainConfig` values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UnfairDrainConfigError {
+    /// `ema_alpha` set but not in (0, 1] (NaN included).
+    EmaAlphaOutOfRange { alpha: f32 },
+    /// Zero or negative `window_ms`.
+    WindowNotPositive { window_ms: i64 },
+    /// `window_ms` above `MAX_WINDOW_MS`.
+    WindowTooWide { window_ms: i64 },
+}
+
+/// Widest accepted window (7 days). Anything wider would fold a whole log
+/// into one peer group rather than compare subjects at the same time.
+pub const MAX_WINDOW_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+
+impl UnfairDrainConfig {
+    pub fn validate(&self) -> Result<(), UnfairDrainConfigError> {
+        self.validate_window()?;
+        match self.ema_alpha {
+            Some(alpha) if !(alpha > 0.0 && alpha <= 1.0) => {
+                Err(UnfairDrainConfigError::EmaAlphaOutOfRange { alpha })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// `window_ms` must be in `1..=MAX_WINDOW_MS`.
+    pub fn validate_window(&self) -> Result<(), UnfairDrainConfigError> {
+        let window_ms = self.window_ms;
+        if window_ms <= 0 {
+            Err(UnfairDrainConfigError::WindowNotPositive { window_ms })
+        } else if window_ms > MAX_WINDOW_MS {
+            Err(UnfairDrainConfigError::WindowTooWide { window_ms })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// Output flag: advisory-only UNFAIRDRAIN label per (subject, time).
@@ -74,88 +170,166 @@ pub struct UnfairDrainFlag {
     pub budget: f32,
     pub peer_median_budget: f32,
     pub overload_fraction: f32,
+    /// Confidence in the peer comparison from the number of other comparable
+    /// subjects in the window; see `peer_confidence`.
+    #[serde(default)]
+    pub confidence: f32,
 }
 
-fn comparable(a: &SubjectSnapshot, b: &SubjectSnapshot) -> bool {
-    // Same capability tier.
-    if a.capability_tier != b.capability_tier {
-        return false;
-    }
+/// `n / (n + 2)` for `n` other comparable subjects: 0 alone, 0.5 with two
+/// peers, approaching 1 as the peer group grows.
+pub fn peer_confidence(peer_count: usize) -> f32 {
+    let n = peer_count as f32;
+    n / (n + 2.0)
+}
 
-    // Same jurisdiction/policy context tag.
-    if a.policy_view.jurisdiction_tag != b.policy_view.jurisdiction_tag {
-        return false;
+/// Median of `budgets`, sorted in place. `budgets` must be non-empty.
+fn median_budget(budgets: &mut [f32]) -> f32 {
+    budgets.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = budgets.len() / 2;
+    if budgets.len().is_multiple_of(2) {
+        0.5 * (budgets[mid - 1] + budgets[mid])
+    } else {
+        budgets[mid]
     }
+}
 
-    // Same high-level task tag (e.g., "lesson_01").
-    if a.task_tag != b.task_tag {
-        return false;
-    }
+/// Per-frame budget `0.5 * (lifeforce + oxygen)`.
+fn frame_budget(s: &SubjectSnapshot) -> f32 {
+    0.5 * (s.lifeforce + s.oxygen)
+}
 
-    true
+/// EMA budget at every input position: per subject, in time order (input
+/// order on ties), `ema = alpha * budget + (1 - alpha) * ema`, seeded with the
+/// subject's first frame.
+fn ema_budgets(snapshots: &[SubjectSnapshot], alpha: f32) -> Vec<f32> {
+    let mut by_subject: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (pos, snap) in snapshots.iter().enumerate() {
+        by_subject.entry(snap.subject_id.as_str()).or_default().push(pos);
+    }
+    let mut ema = vec![0f32; snapshots.len()];
+    for positions in by_subject.values_mut() {
+        positions.sort_by_key(|&pos| snapshots[pos].t_ms);
+        let mut acc: Option<f32> = None;
+        for &pos in positions.iter() {
+            let b = frame_budget(&snapshots[pos]);
+            let next = acc.map_or(b, |prev| alpha * b + (1.0 - alpha) * prev);
+            ema[pos] = next;
+            acc = Some(next);
+        }
+    }
+    ema
 }
 
 /// Compute advisory UNFAIRDRAIN flags over a set of SubjectSnapshot records.
 /// Pure function: no I/O, no capability or policy mutations.
 /// Intended usage: log post-processing or simulation diagnostics.
+///
+/// Snapshots are indexed once, per subject and per peer group (fields
+/// selected by `cfg.comparable`), in time order; each frame then finds its
+/// `[t_start, t_center]` window by binary search instead of rescanning the
+/// whole cohort.
+///
+/// With `cfg.ema_alpha` set, the subject's budget is its EMA at the
+/// current frame and the peer median is taken over each comparable peer's
+/// EMA at its latest frame in the window. The EMA runs over the subject's
+/// whole series rather than restarting at the window edge, so a spike fades
+/// out instead of dropping off when it leaves the window.
+///
+/// `cfg` is checked with `UnfairDrainConfig::validate` up front, so a
+/// `window_ms` outside `1..=MAX_WINDOW_MS` or an `ema_alpha` outside (0, 1]
+/// is an error; window starts saturate at `i64::MIN` instead of wrapping.
 pub fn compute_unfair_drain(
     cfg: &UnfairDrainConfig,
     snapshots: &[SubjectSnapshot],
-) -> Vec<UnfairDrainFlag> {
+) -> Result<Vec<UnfairDrainFlag>, UnfairDrainConfigError> {
+    cfg.validate()?;
+    let ema = cfg.ema_alpha.map(|alpha| ema_budgets(snapshots, alpha));
+
     // Group snapshots by subject_id for sliding-window analysis.
-    let mut by_subject: HashMap<String, Vec<&SubjectSnapshot>> = HashMap::new();
-    for snap in snapshots {
+    let mut by_subject: HashMap<String, Vec<(usize, &SubjectSnapshot)>> = HashMap::new();
+    // Peer index: (t_ms, input position, budget), sorted by time then position.
+    let mut by_peer_group: HashMap<PeerGroupKey<'_>, Vec<(i64, usize, f32)>> = HashMap::new();
+    for (pos, snap) in snapshots.iter().enumerate() {
         by_subject
             .entry(snap.subject_id.clone())
             .or_default()
-            .push(snap);
+            .push((pos, snap));
+        by_peer_group
+            .entry(cfg.comparable.group_key(snap))
+            .or_default()
+            .push((snap.t_ms, pos, frame_budget(snap)));
+    }
+    for group in by_peer_group.values_mut() {
+        group.sort_by_key(|&(t_ms, pos, _)| (t_ms, pos));
     }
 
     let mut flags = Vec::new();
 
     for (subject_id, mut series) in by_subject {
         // Sort by time within subject.
-        series.sort_by_key(|s| s.t_ms);
+        series.sort_by_key(|(_, s)| s.t_ms);
 
         // For each snapshot in this subject's series, compute window-based metrics.
-        for (idx, &snap) in series.iter().enumerate() {
+        for &(pos, snap) in series.iter() {
             let t_center = snap.t_ms;
-            let t_start = t_center - cfg.window_ms;
+            let t_start = t_center.saturating_sub(cfg.window_ms);
 
             // 1. Collect this subject's window frames.
-            let mut self_count = 0usize;
-            let mut self_overload_count = 0usize;
-            let mut self_budget_sum = 0f32;
-
-            for &s in series.iter() {
-                if s.t_ms >= t_start && s.t_ms <= t_center {
-                    self_count += 1;
-                    self_budget_sum += 0.5 * (s.lifeforce + s.oxygen);
-                    if s.overloaded {
-                        self_overload_count += 1;
-                    }
-                }
-            }
+            let lo = series.partition_point(|(_, s)| s.t_ms < t_start);
+            let hi = series.partition_point(|(_, s)| s.t_ms <= t_center);
+            let window = &series[lo..hi.max(lo)];
 
+            let self_count = window.len();
             if self_count == 0 {
                 continue;
             }
 
-            let self_budget_avg = self_budget_sum / self_count as f32;
+            let mut self_overload_count = 0usize;
+            let mut self_budget_sum = 0f32;
+            for &(_, s) in window {
+                self_budget_sum += frame_budget(s);
+                if s.overloaded {
+                    self_overload_count += 1;
+                }
+            }
+
+            let self_budget_avg = match &ema {
+                Some(ema) => ema[pos],
+                None => self_budget_sum / self_count as f32,
+            };
             let self_overload_frac = self_overload_count as f32 / self_count as f32;
 
             // 2. Build peer group at this time across all subjects.
-            let mut peer_budgets: Vec<f32> = Vec::new();
-
-            for other in snapshots {
-                // Time window for peer is aligned to t_center; same window width for simplicity.
-                if other.t_ms >= t_start && other.t_ms <= t_center {
-                    if comparable(snap, other) {
-                        let b = 0.5 * (other.lifeforce + other.oxygen);
-                        peer_budgets.push(b);
-                    }
+            // Time window for peer is aligned to t_center; same window width for simplicity.
+            let group = &by_peer_group[&cfg.comparable.group_key(snap)];
+            let lo = group.partition_point(|&(t_ms, _, _)| t_ms < t_start);
+            let hi = group.partition_point(|&(t_ms, _, _)| t_ms <= t_center);
+            let mut peers = group[lo..hi.max(lo)].to_vec();
+            if let Some(ema) = &ema {
+                // One entry per peer subject: its EMA at its latest window frame.
+                let mut latest: HashMap<&str, (i64, usize)> = HashMap::new();
+                for &(t_ms, peer_pos, _) in &peers {
+                    let slot = latest
+                        .entry(snapshots[peer_pos].subject_id.as_str())
+                        .or_insert((t_ms, peer_pos));
+                    *slot = (*slot).max((t_ms, peer_pos));
                 }
+                peers = latest
+                    .into_values()
+                    .map(|(t_ms, peer_pos)| (t_ms, peer_pos, ema[peer_pos]))
+                    .collect();
             }
+            let peer_count = peers
+                .iter()
+                .map(|&(_, peer_pos, _)| snapshots[peer_pos].subject_id.as_str())
+                .filter(|peer| *peer != subject_id)
+                .collect::<HashSet<_>>()
+                .len();
+            let confidence = peer_confidence(peer_count);
+            // Input order keeps the median's tie-breaking identical to a full scan.
+            peers.sort_by_key(|&(_, pos, _)| pos);
+            let mut peer_budgets: Vec<f32> = peers.into_iter().map(|(_, _, b)| b).collect();
 
             if peer_budgets.is_empty() {
                 // No peers: cannot assess unfairness; default to no unfair drain.
@@ -166,24 +340,21 @@ pub fn compute_unfair_drain(
                     budget: self_budget_avg,
                     peer_median_budget: self_budget_avg,
                     overload_fraction: self_overload_frac,
+                    confidence,
                 });
                 continue;
             }
 
-            peer_budgets.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-            let mid = peer_budgets.len() / 2;
-            let peer_median = if peer_budgets.len() % 2 == 0 {
-                0.5 * (peer_budgets[mid - 1] + peer_budgets[mid])
-            } else {
-                peer_budgets[mid]
-            };
+            let peer_median = median_budget(&mut peer_budgets);
 
             // 3. Apply UNFAIRDRAIN predicate:
             //     B_s(t) <= Med_G(t) - delta_unfair
             //  AND overload_frac_s(t) >= overload_frac_min
+            //  AND enough comparable peers to trust the median
             let budget_deficit = peer_median - self_budget_avg;
             let unfair = budget_deficit >= cfg.delta_unfair
-                && self_overload_frac >= cfg.overload_frac_min;
+                && self_overload_frac >= cfg.overload_frac_min
+                && peer_count >= cfg.min_peer_count;
 
             flags.push(UnfairDrainFlag {
                 subject_id: subject_id.clone(),
@@ -192,9 +363,676 @@ pub fn compute_unfair_drain(
                 budget: self_budget_avg,
                 peer_median_budget: peer_median,
                 overload_fraction: self_overload_frac,
+                confidence,
+            });
+        }
+    }
+
+    Ok(flags)
+}
+
+/// Percentile rank (0.0..=100.0) of `subject` on `asset` within its
+/// comparable peer group in `cohort`, grouped by the caller's `comparable`
+/// key (normally `UnfairDrainConfig::comparable`, so percentiles and flags
+/// use the same peers).
+///
+/// Other records of the same subject are not peers. Ties count half, so a
+/// subject equal to every peer sits at 50. Returns `None` when there are no
+/// comparable peers or the subject's own value is NaN; NaN peer values are
+/// skipped. Advisory-only.
+pub fn subject_cohort_percentile(
+    subject: &SubjectSnapshot,
+    cohort: &[SubjectSnapshot],
+    comparable: &ComparableKey,
+    asset: fn(&SubjectSnapshot) -> f32,
+) -> Option<f32> {
+    let value = asset(subject);
+    if value.is_nan() {
+        return None;
+    }
+
+    let mut below = 0usize;
+    let mut equal = 0usize;
+    let mut total = 0usize;
+    for peer in cohort {
+        if peer.subject_id == subject.subject_id || !comparable.matches(subject, peer) {
+            continue;
+        }
+        let v = asset(peer);
+        if v.is_nan() {
+            continue;
+        }
+        total += 1;
+        if v < value {
+            below += 1;
+        } else if v == value {
+            equal += 1;
+        }
+    }
+
+    if total == 0 {
+        return None;
+    }
+    Some(100.0 * (below as f32 + 0.5 * equal as f32) / total as f32)
+}
+
+/// Fairness debt per subject: budget deficit below the peer median,
+/// integrated over the time spent flagged as UNFAIRDRAIN.
+///
+/// Per subject, in time order, each flagged record contributes
+/// `max(0, peer_median_budget - budget) * duration_s`, where `duration_s` is
+/// the gap to the subject's next record. The last record reuses the
+/// preceding gap; a subject with a single record is weighted as 1 second.
+/// Unflagged records add nothing but still bound their neighbours' durations.
+/// Pure rollup over `UnfairDrainFlag` fields; advisory-only.
+pub fn compute_fairness_debt(flags: &[UnfairDrainFlag]) -> HashMap<String, f32> {
+    let mut by_subject: HashMap<String, Vec<&UnfairDrainFlag>> = HashMap::new();
+    for flag in flags {
+        by_subject
+            .entry(flag.subject_id.clone())
+            .or_default()
+            .push(flag);
+    }
+
+    by_subject
+        .into_iter()
+        .map(|(subject_id, mut series)| {
+            series.sort_by_key(|f| f.t_ms);
+            let gaps: Vec<f32> = series
+                .windows(2)
+                .map(|w| (w[1].t_ms - w[0].t_ms).max(0) as f32 / 1000.0)
+                .collect();
+            let debt = series
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| f.unfair_drain)
+                .map(|(i, f)| {
+                    let duration_s = gaps.get(i).or(gaps.last()).copied().unwrap_or(1.0);
+                    (f.peer_median_budget - f.budget).max(0.0) * duration_s
+                })
+                .sum();
+            (subject_id, debt)
+        })
+        .collect()
+}
+
+/// Longest run of consecutive flagged frames for one subject.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnfairDrainStreak {
+    pub subject_id: String,
+    pub frames: usize,
+    pub start_t_ms: i64,
+    pub end_t_ms: i64,
+}
+
+/// Cohort-level summary of `UnfairDrainFlag`s.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnfairDrainRollup {
+    /// Distinct subjects with at least one flagged frame.
+    pub flagged_subjects: usize,
+    /// Flagged frame count per flagged subject.
+    pub flagged_frames: HashMap<String, usize>,
+    /// Worst offender: the longest streak across the cohort; ties go to the
+    /// earlier start, then the smaller subject_id. `None` if nothing is flagged.
+    pub longest_streak: Option<UnfairDrainStreak>,
+}
+
+/// Orders streaks longest first, then earliest start, then smallest subject_id.
+fn streak_rank(s: &UnfairDrainStreak) -> (usize, Reverse<i64>, Reverse<&str>) {
+    (s.frames, Reverse(s.start_t_ms), Reverse(s.subject_id.as_str()))
+}
+
+/// Count, for the whole cohort, which subjects were ever flagged, how many
+/// of their frames were flagged, and the single longest flagged streak.
+///
+/// Each subject's flags are ordered by `t_ms` first, so input order does not
+/// matter; a streak ends at the first unflagged frame. Subjects that were
+/// never flagged do not appear in `flagged_frames`.
+pub fn rollup_unfair_drain(flags: &[UnfairDrainFlag]) -> UnfairDrainRollup {
+    let mut by_subject: HashMap<&str, Vec<&UnfairDrainFlag>> = HashMap::new();
+    for flag in flags {
+        by_subject.entry(flag.subject_id.as_str()).or_default().push(flag);
+    }
+
+    let mut rollup = UnfairDrainRollup::default();
+    for (subject_id, mut series) in by_subject {
+        series.sort_by_key(|f| f.t_ms);
+
+        let mut frames = 0usize;
+        let mut run_start: Option<usize> = None;
+        for (i, f) in series.iter().enumerate() {
+            if !f.unfair_drain {
+                run_start = None;
+                continue;
+            }
+            frames += 1;
+            let start = *run_start.get_or_insert(i);
+            let streak = UnfairDrainStreak {
+                subject_id: subject_id.to_string(),
+                frames: i - start + 1,
+                start_t_ms: series[start].t_ms,
+                end_t_ms: f.t_ms,
+            };
+            let longer = rollup
+                .longest_streak
+                .as_ref()
+                .is_none_or(|best| streak_rank(&streak) > streak_rank(best));
+            if longer {
+                rollup.longest_streak = Some(streak);
+            }
+        }
+
+        if frames > 0 {
+            rollup.flagged_subjects += 1;
+            rollup.flagged_frames.insert(subject_id.to_string(), frames);
+        }
+    }
+    rollup
+}
+
+/// Decay model for cumulative unfair-exposure scoring.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExposureDecayConfig {
+    /// Half-life of accumulated exposure, in epochs. `None` disables decay,
+    /// so exposure is the plain count of flagged epochs.
+    pub half_life_epochs: Option<f32>,
+}
+
+impl ExposureDecayConfig {
+    /// Per-epoch retention factor: 0.5^(1 / half_life), or 1.0 without decay.
+    fn retention(&self) -> f32 {
+        match self.half_life_epochs {
+            Some(h) if h > 0.0 => 0.5f32.powf(1.0 / h),
+            Some(_) => 0.0,
+            None => 1.0,
+        }
+    }
+}
+
+/// Cumulative unfair-drain exposure per subject, treating each flag as one epoch.
+///
+/// Per subject, in time order: `exposure = exposure * retention + flagged`.
+/// Every epoch decays what has already accumulated, so a run of recovery
+/// (unflagged) epochs halves the score once per half-life, while flagged
+/// epochs keep adding 1. With `half_life_epochs: None` the score never
+/// decreases. Pure, advisory-only; intended for reporting, not gating.
+pub fn cumulative_unfair_exposure(
+    flags: &[UnfairDrainFlag],
+    decay: &ExposureDecayConfig,
+) -> HashMap<String, f32> {
+    let mut by_subject: HashMap<String, Vec<&UnfairDrainFlag>> = HashMap::new();
+    for flag in flags {
+        by_subject
+            .entry(flag.subject_id.clone())
+            .or_default()
+            .push(flag);
+    }
+
+    let retention = decay.retention();
+    by_subject
+        .into_iter()
+        .map(|(subject_id, mut series)| {
+            series.sort_by_key(|f| f.t_ms);
+            let exposure = series.iter().fold(0f32, |acc, f| {
+                acc * retention + if f.unfair_drain { 1.0 } else { 0.0 }
             });
+            (subject_id, exposure)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flag(t_ms: i64, unfair_drain: bool) -> UnfairDrainFlag {
+        UnfairDrainFlag {
+            subject_id: "subject-a".to_string(),
+            t_ms,
+            unfair_drain,
+            budget: 0.3,
+            peer_median_budget: 0.6,
+            overload_fraction: 0.5,
+            confidence: 1.0,
+        }
+    }
+
+    fn snapshot(subject_id: &str, lifeforce: f32, task_tag: &str) -> SubjectSnapshot {
+        SubjectSnapshot {
+            subject_id: subject_id.to_string(),
+            t_ms: 0,
+            capability_tier: CapabilityTier::ControlledHuman,
+            role: RoleTag::Learner,
+            policy_view: PolicyStackView {
+                jurisdiction_tag: "GLOBAL_BASE".to_string(),
+                base_medical_ok: true,
+                base_engineering_ok: true,
+                juris_local_ok: true,
+                quantum_ai_safety_ok: true,
+            },
+            lifeforce,
+            oxygen: 0.8,
+            overloaded: false,
+            task_tag: task_tag.to_string(),
+        }
+    }
+
+    /// Reference full-scan implementation the indexed version must match.
+    fn compute_unfair_drain_full_scan(
+        cfg: &UnfairDrainConfig,
+        snapshots: &[SubjectSnapshot],
+    ) -> Vec<UnfairDrainFlag> {
+        let mut by_subject: HashMap<String, Vec<&SubjectSnapshot>> = HashMap::new();
+        for snap in snapshots {
+            by_subject
+                .entry(snap.subject_id.clone())
+                .or_default()
+                .push(snap);
+        }
+
+        let mut flags = Vec::new();
+        for (subject_id, mut series) in by_subject {
+            series.sort_by_key(|s| s.t_ms);
+            for &snap in series.iter() {
+                let t_center = snap.t_ms;
+                let t_start = t_center.saturating_sub(cfg.window_ms);
+                let in_window = |s: &SubjectSnapshot| s.t_ms >= t_start && s.t_ms <= t_center;
+
+                let window: Vec<&SubjectSnapshot> =
+                    series.iter().copied().filter(|s| in_window(s)).collect();
+                let mut self_budget_sum = 0f32;
+                for s in &window {
+                    self_budget_sum += 0.5 * (s.lifeforce + s.oxygen);
+                }
+                let budget = self_budget_sum / window.len() as f32;
+                let overload_fraction =
+                    window.iter().filter(|s| s.overloaded).count() as f32 / window.len() as f32;
+
+                let mut peer_budgets: Vec<f32> = snapshots
+                    .iter()
+                    .filter(|o| in_window(o) && cfg.comparable.matches(snap, o))
+                    .map(|o| 0.5 * (o.lifeforce + o.oxygen))
+                    .collect();
+                let peer_median_budget = median_budget(&mut peer_budgets);
+                let peer_count = snapshots
+                    .iter()
+                    .filter(|o| in_window(o) && cfg.comparable.matches(snap, o))
+                    .filter(|o| o.subject_id != subject_id)
+                    .map(|o| o.subject_id.as_str())
+                    .collect::<HashSet<_>>()
+                    .len();
+                flags.push(UnfairDrainFlag {
+                    subject_id: subject_id.clone(),
+                    t_ms: t_center,
+                    unfair_drain: peer_median_budget - budget >= cfg.delta_unfair
+                        && overload_fraction >= cfg.overload_frac_min
+                        && peer_count >= cfg.min_peer_count,
+                    budget,
+                    peer_median_budget,
+                    overload_fraction,
+                    confidence: peer_confidence(peer_count),
+                });
+            }
+        }
+        flags
+    }
+
+    #[test]
+    fn test_indexed_unfair_drain_matches_full_scan_on_5000_snapshots() {
+        // SplitMix-style generator so the cohort is fixed without a rand dependency.
+        let mut state = 0x5EED_u64;
+        let mut next = move || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let z = (state ^ (state >> 31)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z ^ (z >> 29)
+        };
+
+        let tasks = ["lesson_01", "lesson_02", "lesson_03"];
+        let tiers = [CapabilityTier::LabBench, CapabilityTier::ControlledHuman];
+        let snapshots: Vec<SubjectSnapshot> = (0..5_000)
+            .map(|_| {
+                let mut s = snapshot(
+                    &format!("subject-{}", next() % 200),
+                    (next() % 1_000) as f32 / 1_000.0,
+                    tasks[(next() % 3) as usize],
+                );
+                s.t_ms = (next() % 60_000) as i64;
+                s.oxygen = (next() % 1_000) as f32 / 1_000.0;
+                s.overloaded = next() % 3 == 0;
+                s.capability_tier = tiers[(next() % 2) as usize];
+                s
+            })
+            .collect();
+        let cfg = UnfairDrainConfig {
+            window_ms: 5_000,
+            delta_unfair: 0.1,
+            overload_frac_min: 0.3,
+            ema_alpha: None,
+            comparable: ComparableKey::default(),
+            min_peer_count: 0,
+        };
+
+        // Flag order follows HashMap iteration, so compare on a canonical order.
+        let canonical = |flags: Vec<UnfairDrainFlag>| {
+            let mut rows: Vec<_> = flags
+                .into_iter()
+                .map(|f| {
+                    (
+                        f.subject_id,
+                        f.t_ms,
+                        f.unfair_drain,
+                        f.budget.to_bits(),
+                        f.peer_median_budget.to_bits(),
+                        f.overload_fraction.to_bits(),
+                        f.confidence.to_bits(),
+                    )
+                })
+                .collect();
+            rows.sort();
+            rows
+        };
+
+        let indexed = canonical(compute_unfair_drain(&cfg, &snapshots).unwrap());
+        let full_scan = canonical(compute_unfair_drain_full_scan(&cfg, &snapshots));
+        assert_eq!(indexed.len(), 5_000);
+        assert!(indexed.iter().any(|r| r.2));
+        assert_eq!(indexed, full_scan);
+    }
+
+    #[test]
+    fn test_low_budget_subject_lands_near_bottom_percentile() {
+        let poor = snapshot("subject-poor", 0.05, "lesson_01");
+        let mut cohort: Vec<SubjectSnapshot> = (0..19)
+            .map(|i| snapshot(&format!("peer-{}", i), 0.5 + 0.02 * i as f32, "lesson_01"))
+            .collect();
+        // A poorer subject on another task is not a comparable peer.
+        cohort.push(snapshot("other-task", 0.01, "lesson_02"));
+        cohort.push(poor.clone());
+
+        let lifeforce: fn(&SubjectSnapshot) -> f32 = |s| s.lifeforce;
+        let key = ComparableKey::default();
+        let pct = subject_cohort_percentile(&poor, &cohort, &key, lifeforce).unwrap();
+        assert!(pct < 5.0, "expected bottom percentile, got {}", pct);
+
+        let richest = &cohort[18];
+        assert!(subject_cohort_percentile(richest, &cohort, &key, lifeforce).unwrap() > 90.0);
+
+        let alone = snapshot("alone", 0.5, "lesson_03");
+        assert_eq!(subject_cohort_percentile(&alone, &cohort, &key, lifeforce), None);
+
+        // The caller's key decides the peers: ignoring the task tag pulls in
+        // the poorer subject on lesson_02.
+        let any_task = ComparableKey {
+            task: false,
+            ..ComparableKey::default()
+        };
+        assert_eq!(
+            subject_cohort_percentile(&poor, &cohort, &any_task, lifeforce),
+            Some(5.0)
+        );
+        assert!(subject_cohort_percentile(&alone, &cohort, &any_task, lifeforce).is_some());
+    }
+
+    #[test]
+    fn test_relaxing_task_constraint_enlarges_peer_group() {
+        // Three learners on lesson_01, two richer ones on lesson_02.
+        let mut cohort: Vec<SubjectSnapshot> = [0.2, 0.3, 0.4]
+            .iter()
+            .enumerate()
+            .map(|(i, &lf)| snapshot(&format!("l1-{}", i), lf, "lesson_01"))
+            .collect();
+        cohort.push(snapshot("l2-0", 0.9, "lesson_02"));
+        cohort.push(snapshot("l2-1", 1.0, "lesson_02"));
+        cohort[0].overloaded = true;
+
+        let flag_for = |cohort: &[SubjectSnapshot], comparable: ComparableKey| {
+            let cfg = UnfairDrainConfig {
+                window_ms: 1_000,
+                delta_unfair: 0.1,
+                overload_frac_min: 0.5,
+                ema_alpha: None,
+                comparable,
+                min_peer_count: 0,
+            };
+            compute_unfair_drain(&cfg, cohort)
+                .unwrap()
+                .into_iter()
+                .find(|f| f.subject_id == "l1-0")
+                .unwrap()
+        };
+
+        // Same task only: median lifeforce 0.3 -> budget 0.55; own budget 0.5.
+        let strict = flag_for(&cohort, ComparableKey::default());
+        assert!((strict.peer_median_budget - 0.55).abs() < 1e-6);
+        assert!(!strict.unfair_drain);
+
+        // Across tasks within the tier: median lifeforce 0.4 -> budget 0.6.
+        let relaxed = flag_for(&cohort, ComparableKey {
+            task: false,
+            ..ComparableKey::default()
+        });
+        assert!((relaxed.peer_median_budget - 0.6).abs() < 1e-6);
+        assert!(relaxed.unfair_drain);
+
+        // Requiring a matching role splits off a mentor.
+        cohort[2].role = RoleTag::Mentor;
+        let by_role = ComparableKey {
+            role: true,
+            ..ComparableKey::default()
+        };
+        assert!(by_role.matches(&cohort[0], &cohort[1]));
+        assert!(!by_role.matches(&cohort[0], &cohort[2]));
+        assert!((flag_for(&cohort, by_role).peer_median_budget - 0.525).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_ema_budget_is_smoother_than_flat_mean_on_spiky_series() {
+        // One frame per second; baseline budget 0.6 with a full drain every 7th frame.
+        let series: Vec<SubjectSnapshot> = (0..40)
+            .map(|i| {
+                let mut s = snapshot("subject-a", 0.4, "lesson_01");
+                s.t_ms = i * 1_000;
+                if i % 7 == 3 {
+                    s.lifeforce = 0.0;
+                    s.oxygen = 0.0;
+                }
+                s
+            })
+            .collect();
+        let budgets = |ema_alpha: Option<f32>| {
+            let cfg = UnfairDrainConfig {
+                window_ms: 4_000,
+                delta_unfair: 0.1,
+                overload_frac_min: 0.3,
+                ema_alpha,
+                comparable: ComparableKey::default(),
+                min_peer_count: 0,
+            };
+            let mut flags = compute_unfair_drain(&cfg, &series).unwrap();
+            flags.sort_by_key(|f| f.t_ms);
+            flags.into_iter().map(|f| f.budget).collect::<Vec<f32>>()
+        };
+        let total_variation = |b: &[f32]| b.windows(2).map(|w| (w[1] - w[0]).abs()).sum::<f32>();
+
+        let flat = budgets(None);
+        let smoothed = budgets(Some(0.1));
+        assert_eq!(flat.len(), smoothed.len());
+        assert!(total_variation(&smoothed) < 0.5 * total_variation(&flat));
+        // Still tracks the level: the baseline is 0.6, spikes pull it down a bit.
+        let last = *smoothed.last().unwrap();
+        assert!(last > 0.4 && last < 0.6, "got {}", last);
+
+        // alpha = 1 is "latest frame only"; invalid alphas are rejected.
+        assert_eq!(budgets(Some(1.0))[3], 0.0);
+        for alpha in [0.0, -0.5, 1.5, f32::NAN] {
+            let cfg = UnfairDrainConfig {
+                window_ms: 4_000,
+                delta_unfair: 0.1,
+                overload_frac_min: 0.3,
+                ema_alpha: Some(alpha),
+                comparable: ComparableKey::default(),
+                min_peer_count: 0,
+            };
+            assert!(matches!(
+                cfg.validate(),
+                Err(UnfairDrainConfigError::EmaAlphaOutOfRange { .. })
+            ));
+            assert!(matches!(
+                compute_unfair_drain(&cfg, &series),
+                Err(UnfairDrainConfigError::EmaAlphaOutOfRange { .. })
+            ));
         }
     }
 
-    flags
+    #[test]
+    fn test_window_ms_out_of_range_is_rejected_and_start_saturates() {
+        let cfg_with = |window_ms: i64| UnfairDrainConfig {
+            window_ms,
+            delta_unfair: 0.1,
+            overload_frac_min: 0.3,
+            ema_alpha: None,
+            comparable: ComparableKey::default(),
+            min_peer_count: 0,
+        };
+        let cohort = vec![snapshot("subject-a", 0.4, "lesson_01")];
+
+        for window_ms in [0, -1_000] {
+            assert_eq!(
+                compute_unfair_drain(&cfg_with(window_ms), &cohort).unwrap_err(),
+                UnfairDrainConfigError::WindowNotPositive { window_ms }
+            );
+        }
+        assert_eq!(
+            cfg_with(i64::MAX).validate(),
+            Err(UnfairDrainConfigError::WindowTooWide { window_ms: i64::MAX })
+        );
+        assert!(compute_unfair_drain(&cfg_with(i64::MAX), &cohort).is_err());
+
+        // A frame near i64::MIN no longer wraps its window start past i64::MAX.
+        let mut early = cohort.clone();
+        early[0].t_ms = i64::MIN + 10;
+        let flags = compute_unfair_drain(&cfg_with(MAX_WINDOW_MS), &early).unwrap();
+        assert_eq!(flags.len(), 1);
+        assert!((flags[0].budget - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_small_peer_groups_lower_confidence_and_respect_min_peer_count() {
+        // A drained, overloaded subject next to `peers` richer peers.
+        let flag_with = |peers: usize, min_peer_count: usize| {
+            let mut cohort = vec![snapshot("drained", 0.1, "lesson_01")];
+            cohort[0].overloaded = true;
+            cohort.extend((0..peers).map(|i| snapshot(&format!("peer-{}", i), 0.6, "lesson_01")));
+            let cfg = UnfairDrainConfig {
+                window_ms: 1_000,
+                delta_unfair: 0.1,
+                overload_frac_min: 0.5,
+                ema_alpha: None,
+                comparable: ComparableKey::default(),
+                min_peer_count,
+            };
+            compute_unfair_drain(&cfg, &cohort)
+                .unwrap()
+                .into_iter()
+                .find(|f| f.subject_id == "drained")
+                .unwrap()
+        };
+
+        // Default: a single peer still flags, as before, but at low confidence.
+        let one = flag_with(1, 0);
+        assert!(one.unfair_drain);
+        assert!((one.confidence - 1.0 / 3.0).abs() < 1e-6);
+
+        let two = flag_with(2, 3);
+        assert!(!two.unfair_drain, "below min_peer_count must not assert unfairness");
+        assert!((two.confidence - 0.5).abs() < 1e-6);
+        assert!(flag_with(2, 2).unfair_drain);
+
+        let ten = flag_with(10, 3);
+        assert!(ten.unfair_drain);
+        assert!(ten.confidence > 0.8 && ten.confidence > two.confidence);
+    }
+
+    #[test]
+    fn test_sustained_deficit_accrues_fairness_debt() {
+        // subject-a: flagged every second for a minute, 0.3 below the peer median.
+        let mut flags: Vec<UnfairDrainFlag> = (0..60).map(|t| flag(t * 1_000, true)).collect();
+        // subject-b: at the median, never flagged.
+        flags.extend((0..60).map(|t| UnfairDrainFlag {
+            subject_id: "subject-b".to_string(),
+            budget: 0.6,
+            ..flag(t * 1_000, false)
+        }));
+
+        let debt = compute_fairness_debt(&flags);
+        assert!((debt["subject-a"] - 0.3 * 60.0).abs() < 1e-3);
+        assert_eq!(debt["subject-b"], 0.0);
+    }
+
+    #[test]
+    fn test_rollup_counts_streaks_in_time_order() {
+        let named = |subject_id: &str, t_ms: i64, unfair_drain: bool| UnfairDrainFlag {
+            subject_id: subject_id.to_string(),
+            ..flag(t_ms, unfair_drain)
+        };
+        // Input deliberately out of time order.
+        let flags = vec![
+            // subject-a: flagged across three consecutive frames.
+            named("subject-a", 3_000, true),
+            named("subject-a", 1_000, true),
+            named("subject-a", 0, false),
+            named("subject-a", 2_000, true),
+            named("subject-a", 4_000, false),
+            // subject-b: flagged intermittently, four frames but never two in a row.
+            named("subject-b", 4_000, true),
+            named("subject-b", 0, true),
+            named("subject-b", 1_000, false),
+            named("subject-b", 2_000, true),
+            named("subject-b", 3_000, false),
+            named("subject-b", 6_000, true),
+            named("subject-b", 5_000, false),
+            // subject-c: never flagged.
+            named("subject-c", 0, false),
+        ];
+
+        let rollup = rollup_unfair_drain(&flags);
+        assert_eq!(rollup.flagged_subjects, 2);
+        assert_eq!(rollup.flagged_frames.len(), 2);
+        assert_eq!(rollup.flagged_frames["subject-a"], 3);
+        assert_eq!(rollup.flagged_frames["subject-b"], 4);
+        assert_eq!(
+            rollup.longest_streak,
+            Some(UnfairDrainStreak {
+                subject_id: "subject-a".to_string(),
+                frames: 3,
+                start_t_ms: 1_000,
+                end_t_ms: 3_000,
+            })
+        );
+
+        assert_eq!(rollup_unfair_drain(&flags[12..]), UnfairDrainRollup::default());
+    }
+
+    #[test]
+    fn test_recovery_reduces_exposure_only_under_decay() {
+        // Ten flagged epochs, then forty recovery epochs.
+        let flags: Vec<UnfairDrainFlag> = (0..50).map(|t| flag(t, t < 10)).collect();
+        let after_flagged = &flags[..10];
+
+        let pure = ExposureDecayConfig::default();
+        let decayed = ExposureDecayConfig {
+            half_life_epochs: Some(10.0),
+        };
+
+        let pure_peak = cumulative_unfair_exposure(after_flagged, &pure)["subject-a"];
+        let pure_final = cumulative_unfair_exposure(&flags, &pure)["subject-a"];
+        assert_eq!(pure_peak, 10.0);
+        assert_eq!(pure_final, 10.0);
+
+        let decayed_peak = cumulative_unfair_exposure(after_flagged, &decayed)["subject-a"];
+        let decayed_final = cumulative_unfair_exposure(&flags, &decayed)["subject-a"];
+        // Forty recovery epochs are four half-lives.
+        assert!((decayed_final - decayed_peak / 16.0).abs() < 1e-4);
+        assert!(decayed_final < pure_final);
+    }
 }
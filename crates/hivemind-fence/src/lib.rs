@@ -1,4 +1,7 @@
 use serde::{Serialize, Deserialize};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
 use capability_core::{CapabilityStateView};          // readonly view
 use envelope_core::{BiophysicalEnvelopeSnapshot};    // readonly view
 use treeoflife_core::{TreeOfLifeView};               // readonly view
@@ -17,10 +20,70 @@ pub struct HiveMindFenceFrame {
     pub cohort_imbalance_index: f32,
     pub collective_imbalance_flag: bool,
     pub cohort_cooldown_advised: bool,
-    pub juristags: Vec<String>,              // e.g. ["USFDA","EUMDR","CHILENEURORIGHTS2023"]
+    pub juristags: Vec<JurisTag>,            // e.g. ["USFDA","EUMDR","CHILENEURORIGHTS2023"]
     pub hivehash: Option<String>,            // filled by logging layer, not by fence logic
 }
 
+/// Jurisdiction tag carried on fence frames. Serializes to the same upper-case
+/// strings older logs used; tags this build does not know are kept verbatim
+/// in `Other` rather than rejected, so old and foreign logs still parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum JurisTag {
+    UsFda,
+    EuMdr,
+    ChileNeuroRights2023,
+    Other(String),
+}
+
+impl JurisTag {
+    pub fn as_str(&self) -> &str {
+        match self {
+            JurisTag::UsFda => "USFDA",
+            JurisTag::EuMdr => "EUMDR",
+            JurisTag::ChileNeuroRights2023 => "CHILENEURORIGHTS2023",
+            JurisTag::Other(tag) => tag,
+        }
+    }
+}
+
+impl FromStr for JurisTag {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "USFDA" => JurisTag::UsFda,
+            "EUMDR" => JurisTag::EuMdr,
+            "CHILENEURORIGHTS2023" => JurisTag::ChileNeuroRights2023,
+            other => JurisTag::Other(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for JurisTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for JurisTag {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(JurisTag::Other(_)) | Err(_) => JurisTag::Other(s),
+            Ok(known) => known,
+        }
+    }
+}
+
+impl From<JurisTag> for String {
+    fn from(tag: JurisTag) -> Self {
+        match tag {
+            JurisTag::Other(s) => s,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
 pub trait HiveMindFenceView {
     /// Pure, non-actuating diagnostic over immutable snapshots.
     fn compute_advisories(
@@ -45,3 +108,347 @@ pub struct PeerSnapshot {
     pub capability: CapabilityStateView,
     pub tol_view: TreeOfLifeView,
 }
+
+/// Cohort means and Ginis of FEAR/PAIN/DECAY/LIFEFORCE, matching the
+/// `cohort_mean_*` / `cohort_*_gini` inputs of HIVEMIND-FENCE. Each is `None`
+/// when no peer has a usable (non-NaN) value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CohortAggregates {
+    pub mean_fear: Option<f32>,
+    pub mean_pain: Option<f32>,
+    pub mean_decay: Option<f32>,
+    pub mean_lifeforce: Option<f32>,
+    pub fear_gini: Option<f32>,
+    pub pain_gini: Option<f32>,
+    pub decay_gini: Option<f32>,
+    pub lifeforce_gini: Option<f32>,
+}
+
+impl CohortStatsView {
+    /// Means and Ginis over `peer_subjects`; NaN peer values are skipped.
+    pub fn aggregate(&self) -> CohortAggregates {
+        let project = |f: fn(&TreeOfLifeView) -> f32| -> Vec<f32> {
+            self.peer_subjects.iter().map(|p| f(&p.tol_view)).collect()
+        };
+        let (fear, pain, decay, lifeforce) = (
+            project(|v| v.fear),
+            project(|v| v.pain),
+            project(|v| v.decay),
+            project(|v| v.lifeforce),
+        );
+        CohortAggregates {
+            mean_fear: mean(&fear),
+            mean_pain: mean(&pain),
+            mean_decay: mean(&decay),
+            mean_lifeforce: mean(&lifeforce),
+            fear_gini: gini(&fear),
+            pain_gini: gini(&pain),
+            decay_gini: gini(&decay),
+            lifeforce_gini: gini(&lifeforce),
+        }
+    }
+}
+
+/// Mean of the non-NaN `values`; `None` when nothing is left.
+fn mean(values: &[f32]) -> Option<f32> {
+    let (sum, n) = values
+        .iter()
+        .filter(|v| !v.is_nan())
+        .fold((0.0f32, 0usize), |(sum, n), v| (sum + v, n + 1));
+    (n > 0).then(|| sum / n as f32)
+}
+
+/// Cohort dispersion of DECAY/FEAR/PAIN, ready for the `cohort_*_gini`
+/// inputs of HIVEMIND-FENCE. `None` when there are no usable peer values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CohortGinis {
+    pub decay: Option<f32>,
+    pub fear: Option<f32>,
+    pub pain: Option<f32>,
+}
+
+/// Gini coefficient of `values` in [0, 1]: 0 is perfect equality, values
+/// approach 1 as one member holds everything.
+///
+/// NaN values are dropped and negatives treated as 0 (TREE assets are >= 0).
+/// Returns `None` when nothing is left; an all-zero cohort counts as equal.
+pub fn gini(values: &[f32]) -> Option<f32> {
+    let mut sorted: Vec<f32> = values
+        .iter()
+        .copied()
+        .filter(|v| !v.is_nan())
+        .map(|v| v.max(0.0))
+        .collect();
+    if sorted.is_empty() {
+        return None;
+    }
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let n = sorted.len() as f32;
+    let total: f32 = sorted.iter().sum();
+    if total <= 0.0 {
+        return Some(0.0);
+    }
+    // G = Σ (2i - n - 1)·x_i / (n·Σx), with 1-based rank i over ascending x.
+    let weighted: f32 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (2.0 * (i + 1) as f32 - n - 1.0) * x)
+        .sum();
+    Some((weighted / (n * total)).clamp(0.0, 1.0))
+}
+
+/// Project DECAY/FEAR/PAIN out of each peer's `TreeOfLifeView` and compute
+/// their Gini coefficients. Readonly over already-logged peers.
+pub fn compute_cohort_ginis(peers: &[PeerSnapshot]) -> CohortGinis {
+    let project = |f: fn(&TreeOfLifeView) -> f32| {
+        let values: Vec<f32> = peers.iter().map(|p| f(&p.tol_view)).collect();
+        gini(&values)
+    };
+    CohortGinis {
+        decay: project(|v| v.decay),
+        fear: project(|v| v.fear),
+        pain: project(|v| v.pain),
+    }
+}
+
+/// Advisory thresholds for `StandardFenceEvaluator`; defaults match the
+/// policy-engine HIVEMIND-FENCE config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandardFenceEvaluator {
+    /// Subject unfairdrain / unfairstress index at or above which the flag is set.
+    pub unfairdrain_risk: f32,
+    /// Cohort Gini at or above which collective imbalance is flagged.
+    pub cohesion_gini_risk: f32,
+    /// RoH (after) at or above which cohort-wide cooldown is advised.
+    pub roh_cooldown_threshold: f32,
+    /// Jurisdiction tags copied onto every frame.
+    pub juristags: Vec<JurisTag>,
+}
+
+impl Default for StandardFenceEvaluator {
+    fn default() -> Self {
+        Self {
+            unfairdrain_risk: 0.30,
+            cohesion_gini_risk: 0.35,
+            roh_cooldown_threshold: 0.25,
+            juristags: Vec::new(),
+        }
+    }
+}
+
+impl StandardFenceEvaluator {
+    /// Excess of a TREE asset over its baseline; parity or better reads 0.
+    fn excess_index(delta: f32) -> f32 {
+        delta.clamp(0.0, 1.0)
+    }
+}
+
+impl HiveMindFenceView for StandardFenceEvaluator {
+    /// Unfairdrain is DECAY in excess of LIFEFORCE and unfairstress is
+    /// FEAR/PAIN in excess of the cohort mean, so a subject at parity reads 0;
+    /// cohort imbalance is the largest DECAY/FEAR/PAIN Gini.
+    /// The envelope's axis states already feed RoH and the TREE assets, so it
+    /// is not re-read here. The subject's capability is taken from its own
+    /// cohort entry when present.
+    fn compute_advisories(
+        &self,
+        subject_id: &str,
+        epoch_ms: i64,
+        roh: &RoHProjection,
+        _envelope: &BiophysicalEnvelopeSnapshot,
+        tol_view: &TreeOfLifeView,
+        cohort_stats: &CohortStatsView,
+    ) -> HiveMindFenceFrame {
+        let peers = &cohort_stats.peer_subjects;
+        let aggregates = cohort_stats.aggregate();
+
+        let unfairdrain_index = Self::excess_index(tol_view.decay - tol_view.lifeforce);
+        let unfairstress_index = [
+            aggregates.mean_fear.map(|mu| tol_view.fear - mu),
+            aggregates.mean_pain.map(|mu| tol_view.pain - mu),
+        ]
+        .into_iter()
+        .flatten()
+        .map(Self::excess_index)
+        .fold(None, |acc: Option<f32>, x| Some(acc.map_or(x, |a| a.max(x))));
+
+        let cohort_imbalance_index = [
+            aggregates.decay_gini,
+            aggregates.fear_gini,
+            aggregates.pain_gini,
+        ]
+        .into_iter()
+        .flatten()
+        .fold(0.0f32, f32::max);
+
+        let collective_imbalance_flag = cohort_imbalance_index >= self.cohesion_gini_risk;
+        let cohort_cooldown_advised =
+            roh.after >= self.roh_cooldown_threshold || collective_imbalance_flag;
+
+        let capability = peers
+            .iter()
+            .find(|p| p.subject_id == subject_id)
+            .map(|p| p.capability.clone())
+            .unwrap_or_default();
+
+        HiveMindFenceFrame {
+            subject_id: subject_id.to_string(),
+            epoch_ms,
+            capability,
+            roh: roh.clone(),
+            tol_view: tol_view.clone(),
+            unfairdrain_index,
+            subject_unfairdrain_flag: unfairdrain_index >= self.unfairdrain_risk,
+            subject_unfairstress_flag: unfairstress_index
+                .is_some_and(|idx| idx >= self.unfairdrain_risk),
+            cohort_imbalance_index,
+            collective_imbalance_flag,
+            cohort_cooldown_advised,
+            juristags: self.juristags.clone(),
+            hivehash: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tol(decay: f32, fear: f32, pain: f32) -> TreeOfLifeView {
+        TreeOfLifeView {
+            decay,
+            lifeforce: 1.0 - decay,
+            fear,
+            pain,
+            ..TreeOfLifeView::default()
+        }
+    }
+
+    fn cohort(views: Vec<TreeOfLifeView>) -> CohortStatsView {
+        CohortStatsView {
+            peer_subjects: views
+                .into_iter()
+                .enumerate()
+                .map(|(i, tol_view)| PeerSnapshot {
+                    subject_id: format!("peer-{}", i),
+                    capability: CapabilityStateView::default(),
+                    tol_view,
+                })
+                .collect(),
+        }
+    }
+
+    fn advise(subject: &TreeOfLifeView, cohort_stats: &CohortStatsView) -> HiveMindFenceFrame {
+        let roh = RoHProjection {
+            before: 0.10,
+            after: 0.12,
+            ceiling: 0.30,
+        };
+        StandardFenceEvaluator::default().compute_advisories(
+            "subject-a",
+            1_000,
+            &roh,
+            &BiophysicalEnvelopeSnapshot::default(),
+            subject,
+            cohort_stats,
+        )
+    }
+
+    #[test]
+    fn test_drained_cohort_sets_collective_imbalance() {
+        // A few peers carry nearly all the DECAY while the rest are untouched.
+        let mut views = vec![tol(0.0, 0.1, 0.1); 8];
+        views.extend(vec![tol(0.95, 0.9, 0.8); 2]);
+        let frame = advise(&tol(0.95, 0.9, 0.8), &cohort(views));
+
+        assert!(frame.cohort_imbalance_index >= 0.35);
+        assert!(frame.collective_imbalance_flag);
+        assert!(frame.cohort_cooldown_advised);
+        assert!(frame.subject_unfairdrain_flag);
+        assert_eq!(frame.hivehash, None);
+    }
+
+    #[test]
+    fn test_calm_cohort_has_no_collective_imbalance() {
+        let views = vec![tol(0.2, 0.1, 0.1); 10];
+        let frame = advise(&tol(0.2, 0.1, 0.1), &cohort(views));
+
+        assert!(frame.cohort_imbalance_index < 1e-6);
+        assert!(!frame.collective_imbalance_flag);
+        assert!(!frame.cohort_cooldown_advised);
+        assert!(!frame.subject_unfairstress_flag);
+    }
+
+    #[test]
+    fn test_juristags_round_trip_and_unknown_becomes_other() {
+        let evaluator = StandardFenceEvaluator {
+            juristags: vec![JurisTag::UsFda, JurisTag::ChileNeuroRights2023],
+            ..StandardFenceEvaluator::default()
+        };
+        let frame = evaluator.compute_advisories(
+            "subject-a",
+            1_000,
+            &RoHProjection::default(),
+            &BiophysicalEnvelopeSnapshot::default(),
+            &tol(0.2, 0.1, 0.1),
+            &cohort(Vec::new()),
+        );
+        let json = serde_json::to_value(&frame).unwrap();
+        assert_eq!(json["juristags"], serde_json::json!(["USFDA", "CHILENEURORIGHTS2023"]));
+        let back: HiveMindFenceFrame = serde_json::from_value(json).unwrap();
+        assert_eq!(back.juristags, frame.juristags);
+
+        let old: Vec<JurisTag> =
+            serde_json::from_str(r#"["EUMDR", "KRNEURODATA2025"]"#).unwrap();
+        assert_eq!(
+            old,
+            vec![JurisTag::EuMdr, JurisTag::Other("KRNEURODATA2025".to_string())]
+        );
+        assert_eq!("usfda".parse::<JurisTag>(), Ok(JurisTag::Other("usfda".to_string())));
+        assert_eq!(JurisTag::Other("X".to_string()).to_string(), "X");
+    }
+
+    #[test]
+    fn test_gini_known_distributions() {
+        assert_eq!(gini(&[]), None);
+        assert_eq!(gini(&[f32::NAN]), None);
+        assert!(gini(&[0.4, 0.4, 0.4, 0.4]).unwrap().abs() < 1e-6);
+        assert_eq!(gini(&[0.0, 0.0, 0.0]), Some(0.0));
+
+        // One member holds everything: G = (n - 1) / n.
+        let mut unequal = vec![0.0; 99];
+        unequal.push(1.0);
+        assert!((gini(&unequal).unwrap() - 0.99).abs() < 1e-4);
+
+        // NaN is dropped, negatives count as 0.
+        assert_eq!(gini(&[f32::NAN, -0.5, 1.0]), gini(&[0.0, 1.0]));
+        assert!((gini(&[0.0, 1.0]).unwrap() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_aggregate_three_peer_cohort() {
+        let stats = cohort(vec![tol(0.0, 0.1, 0.2), tol(0.3, 0.4, 0.2), tol(0.6, f32::NAN, 0.2)]);
+        let agg = stats.aggregate();
+
+        let close = |a: Option<f32>, b: f32| (a.unwrap() - b).abs() < 1e-6;
+        assert!(close(agg.mean_decay, 0.3));
+        assert!(close(agg.mean_lifeforce, 0.7));
+        // The NaN FEAR peer is skipped rather than poisoning the mean.
+        assert!(close(agg.mean_fear, 0.25));
+        assert!(close(agg.mean_pain, 0.2));
+        assert!(close(agg.pain_gini, 0.0));
+        // DECAY 0, 0.3, 0.6: G = (-2·0 + 0·0.3 + 2·0.6) / (3·0.9) = 4/9.
+        assert!(close(agg.decay_gini, 4.0 / 9.0));
+        assert_eq!(agg.decay_gini, compute_cohort_ginis(&stats.peer_subjects).decay);
+        assert!(agg.fear_gini.is_some() && agg.lifeforce_gini.is_some());
+    }
+
+    #[test]
+    fn test_aggregate_empty_cohort_is_all_none() {
+        assert_eq!(cohort(Vec::new()).aggregate(), CohortAggregates::default());
+        let all_nan = cohort(vec![tol(f32::NAN, f32::NAN, f32::NAN)]).aggregate();
+        assert_eq!(all_nan.mean_fear, None);
+        assert_eq!(all_nan.decay_gini, None);
+    }
+}
@@ -1,4 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Deployment domain a nano-risk estimate belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NanoRiskDomain {
+    Bci,
+    Nanoswarm,
+    NeuromorphAi,
+    SmartCity,
+}
+
 pub trait NanoRiskGuard {
     fn nano_risk(&self) -> f32;           // 0.0 .. 1.0
     fn nano_risk_domain(&self) -> NanoRiskDomain; // BCI, Nanoswarm, NeuromorphAI, SmartCity
 }
+
+/// Nano-risk ceilings: a per-domain override map over a global default.
+///
+/// `Default` holds the capability-guard tiers: domains closer to neural tissue
+/// get stricter ceilings, BCI the tightest and SmartCity (ambient,
+/// non-contact) the loosest. An unlisted domain falls back to the BCI ceiling.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NanoCeilings {
+    /// Ceiling for any domain without an entry in `per_domain`.
+    pub default: f32,
+    #[serde(default)]
+    pub per_domain: HashMap<NanoRiskDomain, f32>,
+}
+
+impl Default for NanoCeilings {
+    fn default() -> Self {
+        Self::new(0.10)
+            .with_domain(NanoRiskDomain::Bci, 0.10)
+            .with_domain(NanoRiskDomain::Nanoswarm, 0.15)
+            .with_domain(NanoRiskDomain::NeuromorphAi, 0.20)
+            .with_domain(NanoRiskDomain::SmartCity, 0.30)
+    }
+}
+
+impl NanoCeilings {
+    pub fn new(default: f32) -> Self {
+        Self {
+            default,
+            per_domain: HashMap::new(),
+        }
+    }
+
+    pub fn with_domain(mut self, domain: NanoRiskDomain, ceiling: f32) -> Self {
+        self.per_domain.insert(domain, ceiling);
+        self
+    }
+
+    pub fn ceiling_for(&self, domain: NanoRiskDomain) -> f32 {
+        self.per_domain.get(&domain).copied().unwrap_or(self.default)
+    }
+}
+
+/// Worst-case nano-risk per domain across a set of guards.
+///
+/// A domain with no guard is `None`. A NaN risk counts as 1.0 (fail closed).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DomainRiskSummary {
+    pub bci: Option<f32>,
+    pub nanoswarm: Option<f32>,
+    pub neuromorph_ai: Option<f32>,
+    pub smart_city: Option<f32>,
+    /// Highest risk over all domains; `None` if there were no guards.
+    pub worst: Option<f32>,
+}
+
+impl DomainRiskSummary {
+    pub fn for_domain(&self, domain: NanoRiskDomain) -> Option<f32> {
+        match domain {
+            NanoRiskDomain::Bci => self.bci,
+            NanoRiskDomain::Nanoswarm => self.nanoswarm,
+            NanoRiskDomain::NeuromorphAi => self.neuromorph_ai,
+            NanoRiskDomain::SmartCity => self.smart_city,
+        }
+    }
+
+    fn slot(&mut self, domain: NanoRiskDomain) -> &mut Option<f32> {
+        match domain {
+            NanoRiskDomain::Bci => &mut self.bci,
+            NanoRiskDomain::Nanoswarm => &mut self.nanoswarm,
+            NanoRiskDomain::NeuromorphAi => &mut self.neuromorph_ai,
+            NanoRiskDomain::SmartCity => &mut self.smart_city,
+        }
+    }
+
+    /// True if the worst-case risk is above `threshold`.
+    pub fn exceeds_threshold(&self, threshold: f32) -> bool {
+        self.worst.is_some_and(|w| w > threshold)
+    }
+}
+
+/// Combine several guards into per-domain maxima and an overall worst case.
+pub fn aggregate_nano_risk(guards: &[&dyn NanoRiskGuard]) -> DomainRiskSummary {
+    let mut summary = DomainRiskSummary::default();
+    for guard in guards {
+        let risk = guard.nano_risk();
+        let risk = if risk.is_nan() { 1.0 } else { risk };
+        let slot = summary.slot(guard.nano_risk_domain());
+        *slot = Some(slot.map_or(risk, |r| r.max(risk)));
+        summary.worst = Some(summary.worst.map_or(risk, |w| w.max(risk)));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedRisk(f32, NanoRiskDomain);
+
+    impl NanoRiskGuard for FixedRisk {
+        fn nano_risk(&self) -> f32 {
+            self.0
+        }
+        fn nano_risk_domain(&self) -> NanoRiskDomain {
+            self.1
+        }
+    }
+
+    #[test]
+    fn test_aggregate_takes_per_domain_maxima() {
+        let bci_low = FixedRisk(0.05, NanoRiskDomain::Bci);
+        let bci_high = FixedRisk(0.12, NanoRiskDomain::Bci);
+        let swarm = FixedRisk(0.08, NanoRiskDomain::Nanoswarm);
+        let city = FixedRisk(0.25, NanoRiskDomain::SmartCity);
+
+        let summary = aggregate_nano_risk(&[&bci_low, &swarm, &bci_high, &city]);
+        assert_eq!(summary.bci, Some(0.12));
+        assert_eq!(summary.nanoswarm, Some(0.08));
+        assert_eq!(summary.neuromorph_ai, None);
+        assert_eq!(summary.smart_city, Some(0.25));
+        assert_eq!(summary.for_domain(NanoRiskDomain::Bci), Some(0.12));
+        assert_eq!(summary.worst, Some(0.25));
+        assert!(summary.exceeds_threshold(0.2));
+        assert!(!summary.exceeds_threshold(0.25));
+    }
+
+    #[test]
+    fn test_ceilings_fall_back_to_default() {
+        let ceilings = NanoCeilings::new(0.3).with_domain(NanoRiskDomain::Bci, 0.1);
+        assert_eq!(ceilings.ceiling_for(NanoRiskDomain::Bci), 0.1);
+        assert_eq!(ceilings.ceiling_for(NanoRiskDomain::SmartCity), 0.3);
+
+        let tiers = NanoCeilings::default();
+        assert_eq!(tiers.ceiling_for(NanoRiskDomain::Bci), 0.10);
+        assert_eq!(tiers.ceiling_for(NanoRiskDomain::SmartCity), 0.30);
+    }
+
+    #[test]
+    fn test_aggregate_empty_and_nan() {
+        let empty = aggregate_nano_risk(&[]);
+        assert_eq!(empty, DomainRiskSummary::default());
+        assert!(!empty.exceeds_threshold(0.0));
+
+        let nan = FixedRisk(f32::NAN, NanoRiskDomain::NeuromorphAi);
+        let summary = aggregate_nano_risk(&[&nan]);
+        assert_eq!(summary.neuromorph_ai, Some(1.0));
+        assert!(summary.exceeds_threshold(0.99));
+    }
+}
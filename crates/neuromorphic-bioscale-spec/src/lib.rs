@@ -0,0 +1,671 @@
+//! Typed view of the `neuro.print!` neuromorphic bioscale spec.
+//!
+//! - `neuro_print.rs` is spec text, not Rust: it is embedded via `include_str!`
+//!   and parsed by `NeuromorphicBioscaleSpec::from_str`.
+//! - Synapse energies are normalized to pJ; `esyn_fj_*` keys are divided by 1000.
+//! - `validate()` is the only sanity gate; parsing alone does not check bounds.
+
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The v2026.02 spec as shipped in this crate.
+pub const NEURO_PRINT_V2026_02: &str = include_str!("neuro_print.rs");
+
+/// Lowest plausible `core_c_max` (°C); below this the envelope would trip at rest.
+pub const CORE_C_MAX_FLOOR: f32 = 36.0;
+/// Highest tolerable `core_c_max` (°C); above this is tissue-damage territory.
+pub const CORE_C_MAX_CEILING: f32 = 39.0;
+
+/// `evidence.hex` entry backing the algo envelope: implant power and spike
+/// rate are both capped to keep cortical heating inside the thermal envelope.
+pub const ALGO_ENVELOPE_EVIDENCE: &str = "cortical_heating";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThermalEnvelope {
+    pub core_c_max: f32,
+    pub iface_delta_c: f32,
+    pub abort_delta_c: f32,
+}
+
+/// Per-synapse energy bounds for one hardware class, in pJ.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnergySynapse {
+    pub class: String,
+    pub esyn_pj_min: f32,
+    pub esyn_pj_max: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BioInterface {
+    pub material: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlgoEnvelope {
+    pub max_power_mw_implant: f32,
+    pub esyn_target_pj: f32,
+    pub spike_rate_hz_max: f32,
+    /// Hex of the `ALGO_ENVELOPE_EVIDENCE` entry, filled in at parse time.
+    #[serde(default)]
+    pub evidence_hex: Option<String>,
+}
+
+/// Short hex digest pointing at the evidence behind a bound.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvidenceHex {
+    pub name: String,
+    pub hex: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NeuromorphicBioscaleSpec {
+    /// Version segment of the spec header, e.g. `v2026_02`.
+    pub version: String,
+    pub thermal: ThermalEnvelope,
+    pub energy: Vec<EnergySynapse>,
+    pub interfaces: Vec<BioInterface>,
+    pub algo: AlgoEnvelope,
+    pub evidence: Vec<EvidenceHex>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpecError {
+    /// Malformed spec text.
+    Parse { line: usize, message: String },
+    /// A required section or key is absent.
+    Missing { path: String },
+    /// A key that this version of the spec does not define.
+    UnknownKey { path: String },
+    /// Parsed fine but fails `validate()`.
+    Invalid { message: String },
+}
+
+impl NeuromorphicBioscaleSpec {
+    /// Sanity-check the parsed bounds.
+    pub fn validate(&self) -> Result<(), SpecError> {
+        let t = &self.thermal;
+        if !(CORE_C_MAX_FLOOR..=CORE_C_MAX_CEILING).contains(&t.core_c_max) {
+            return Err(invalid(format!(
+                "thermal.envelope.core_c_max {} outside [{}, {}] °C",
+                t.core_c_max, CORE_C_MAX_FLOOR, CORE_C_MAX_CEILING
+            )));
+        }
+        if t.iface_delta_c.is_nan() || t.iface_delta_c <= 0.0 {
+            return Err(invalid(format!(
+                "thermal.envelope.iface_delta_c must be > 0 (got {})",
+                t.iface_delta_c
+            )));
+        }
+        if t.abort_delta_c.is_nan() || t.abort_delta_c <= t.iface_delta_c {
+            return Err(invalid(format!(
+                "thermal.envelope.abort_delta_c ({}) must exceed iface_delta_c ({})",
+                t.abort_delta_c, t.iface_delta_c
+            )));
+        }
+
+        if self.energy.is_empty() {
+            return Err(invalid("energy.synapse defines no classes".into()));
+        }
+        for e in &self.energy {
+            if e.esyn_pj_min.is_nan() || e.esyn_pj_min <= 0.0 || e.esyn_pj_max < e.esyn_pj_min {
+                return Err(invalid(format!(
+                    "energy.synapse.class.{}: need 0 < min <= max (got {} .. {} pJ)",
+                    e.class, e.esyn_pj_min, e.esyn_pj_max
+                )));
+            }
+        }
+
+        let a = &self.algo;
+        for (key, value) in [
+            ("max_power_mw_implant", a.max_power_mw_implant),
+            ("esyn_target_pj", a.esyn_target_pj),
+            ("spike_rate_hz_max", a.spike_rate_hz_max),
+        ] {
+            if value.is_nan() || value <= 0.0 {
+                return Err(invalid(format!("algo.envelope.{key} must be > 0 (got {value})")));
+            }
+        }
+
+        for ev in &self.evidence {
+            if ev.hex.is_empty() || !ev.hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(invalid(format!(
+                    "evidence.hex.{}: `{}` is not a hex digest",
+                    ev.name, ev.hex
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Bounds for a synapse class, if the spec defines it.
+    pub fn energy_class(&self, class: &str) -> Option<&EnergySynapse> {
+        self.energy.iter().find(|e| e.class == class)
+    }
+}
+
+impl FromStr for NeuromorphicBioscaleSpec {
+    type Err = SpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s)?;
+        let mut p = Parser { tokens: &tokens, pos: 0 };
+        let root = p.parse_document()?;
+        build_spec(root)
+    }
+}
+
+fn invalid(message: String) -> SpecError {
+    SpecError::Invalid { message }
+}
+
+// ---------------------------------------------------------------------------
+// Tokenizer
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Number(f32),
+    Str(String),
+    Punct(char),
+}
+
+fn tokenize(s: &str) -> Result<Vec<(Tok, usize)>, SpecError> {
+    let mut out = Vec::new();
+    let mut chars = s.chars().peekable();
+    let mut line = 1;
+    while let Some(&c) = chars.peek() {
+        match c {
+            '\n' => {
+                line += 1;
+                chars.next();
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '/' => {
+                chars.next();
+                if chars.next_if_eq(&'/').is_none() {
+                    return Err(parse_err(line, "stray `/`"));
+                }
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\n') | None => return Err(parse_err(line, "unterminated string")),
+                        Some(c) => text.push(c),
+                    }
+                }
+                out.push((Tok::Str(text), line));
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                let mut text = String::new();
+                text.push(c);
+                chars.next();
+                while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || matches!(c, '.' | '_')) {
+                    if c != '_' {
+                        text.push(c);
+                    }
+                }
+                let n = text
+                    .parse::<f32>()
+                    .map_err(|_| parse_err(line, &format!("bad number `{text}`")))?;
+                out.push((Tok::Number(n), line));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut text = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                    text.push(c);
+                }
+                out.push((Tok::Ident(text), line));
+            }
+            '{' | '}' | '(' | ')' | '=' | ';' | '.' | '!' => {
+                chars.next();
+                out.push((Tok::Punct(c), line));
+            }
+            other => return Err(parse_err(line, &format!("unexpected `{other}`"))),
+        }
+    }
+    Ok(out)
+}
+
+fn parse_err(line: usize, message: &str) -> SpecError {
+    SpecError::Parse {
+        line,
+        message: message.to_string(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Generic block tree
+// ---------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum Value {
+    Number(f32),
+    Str(String),
+}
+
+#[derive(Debug)]
+enum Item {
+    Assign { path: Vec<String>, value: Value, line: usize },
+    Block(Block),
+}
+
+#[derive(Debug)]
+struct Block {
+    path: Vec<String>,
+    items: Vec<Item>,
+}
+
+struct Parser<'a> {
+    tokens: &'a [(Tok, usize)],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |(_, l)| *l)
+    }
+
+    fn peek(&self) -> Option<&Tok> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn bump(&mut self) -> Option<&Tok> {
+        let t = self.tokens.get(self.pos).map(|(t, _)| t);
+        self.pos += 1;
+        t
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SpecError> {
+        // Report against the token before the gap, where the fix belongs.
+        let line = self.tokens[..self.pos.min(self.tokens.len())]
+            .last()
+            .map_or(1, |(_, l)| *l);
+        match self.bump() {
+            Some(Tok::Punct(p)) if *p == c => Ok(()),
+            other => Err(parse_err(line, &format!("expected `{c}`, found {other:?}"))),
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Tok::Punct(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// `neuro.print!( <block> );` or a bare `<block>`.
+    fn parse_document(&mut self) -> Result<Block, SpecError> {
+        let wrapped = matches!(
+            self.tokens.get(..4),
+            Some([(Tok::Ident(a), _), (Tok::Punct('.'), _), (Tok::Ident(b), _), (Tok::Punct('!'), _)])
+                if a == "neuro" && b == "print"
+        );
+        if wrapped {
+            self.pos = 4;
+            self.expect('(')?;
+        }
+        let line = self.line();
+        let path = self.parse_path()?;
+        if !self.eat('{') {
+            return Err(parse_err(line, "expected spec block"));
+        }
+        let root = Block {
+            path,
+            items: self.parse_items()?,
+        };
+        if wrapped {
+            self.expect(')')?;
+            self.eat(';');
+        }
+        if self.pos < self.tokens.len() {
+            return Err(parse_err(self.line(), "trailing input after spec"));
+        }
+        Ok(root)
+    }
+
+    fn parse_path(&mut self) -> Result<Vec<String>, SpecError> {
+        let mut path = Vec::new();
+        loop {
+            let line = self.line();
+            match self.bump() {
+                Some(Tok::Ident(s)) => path.push(s.clone()),
+                other => return Err(parse_err(line, &format!("expected identifier, found {other:?}"))),
+            }
+            if !self.eat('.') {
+                return Ok(path);
+            }
+        }
+    }
+
+    /// Items up to and including the closing `}`.
+    fn parse_items(&mut self) -> Result<Vec<Item>, SpecError> {
+        let mut items = Vec::new();
+        while !self.eat('}') {
+            if self.peek().is_none() {
+                return Err(parse_err(self.line(), "unclosed `{`"));
+            }
+            let line = self.line();
+            let path = self.parse_path()?;
+            if self.eat('{') {
+                items.push(Item::Block(Block {
+                    path,
+                    items: self.parse_items()?,
+                }));
+                continue;
+            }
+            self.expect('=')?;
+            let value = match self.bump() {
+                Some(Tok::Number(n)) => Value::Number(*n),
+                Some(Tok::Str(s)) => Value::Str(s.clone()),
+                other => return Err(parse_err(line, &format!("expected value, found {other:?}"))),
+            };
+            self.expect(';')?;
+            items.push(Item::Assign { path, value, line });
+        }
+        Ok(items)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Block tree -> typed spec
+// ---------------------------------------------------------------------------
+
+fn build_spec(root: Block) -> Result<NeuromorphicBioscaleSpec, SpecError> {
+    match root.path.first().map(String::as_str) {
+        Some("neuromorphic_bioscale_spec") => {}
+        _ => {
+            return Err(SpecError::UnknownKey {
+                path: root.path.join("."),
+            })
+        }
+    }
+    let version = root.path.get(1).cloned().unwrap_or_default();
+
+    let mut thermal = None;
+    let mut energy = None;
+    let mut interfaces = None;
+    let mut algo = None;
+    let mut evidence = None;
+
+    for item in root.items {
+        let Item::Block(block) = item else {
+            return Err(unknown(&item_path(&item)));
+        };
+        match block.path.join(".").as_str() {
+            "thermal.envelope" => {
+                let mut f = Fields::numbers(block)?;
+                thermal = Some(ThermalEnvelope {
+                    core_c_max: f.take("core_c_max")?,
+                    iface_delta_c: f.take("iface_delta_c")?,
+                    abort_delta_c: f.take("abort_delta_c")?,
+                });
+                f.finish()?;
+            }
+            "energy.synapse" => energy = Some(build_energy(block)?),
+            "bio.interface" => {
+                interfaces = Some(
+                    Fields::strings(block, "material")?
+                        .into_iter()
+                        .map(|(material, description)| BioInterface {
+                            material,
+                            description,
+                        })
+                        .collect(),
+                );
+            }
+            "algo.envelope" => {
+                let mut f = Fields::numbers(block)?;
+                algo = Some(AlgoEnvelope {
+                    max_power_mw_implant: f.take("max_power_mw_implant")?,
+                    esyn_target_pj: f.take("esyn_target_pj")?,
+                    spike_rate_hz_max: f.take("spike_rate_hz_max")?,
+                    evidence_hex: None,
+                });
+                f.finish()?;
+            }
+            "evidence.hex" => {
+                evidence = Some(
+                    Fields::strings(block, "")?
+                        .into_iter()
+                        .map(|(name, hex)| EvidenceHex { name, hex })
+                        .collect(),
+                );
+            }
+            other => return Err(unknown(other)),
+        }
+    }
+
+    let evidence: Vec<EvidenceHex> = evidence.ok_or_else(|| missing("evidence.hex"))?;
+    let mut algo: AlgoEnvelope = algo.ok_or_else(|| missing("algo.envelope"))?;
+    algo.evidence_hex = evidence
+        .iter()
+        .find(|e| e.name == ALGO_ENVELOPE_EVIDENCE)
+        .map(|e| e.hex.clone());
+
+    Ok(NeuromorphicBioscaleSpec {
+        version,
+        thermal: thermal.ok_or_else(|| missing("thermal.envelope"))?,
+        energy: energy.ok_or_else(|| missing("energy.synapse"))?,
+        interfaces: interfaces.ok_or_else(|| missing("bio.interface"))?,
+        algo,
+        evidence,
+    })
+}
+
+fn build_energy(block: Block) -> Result<Vec<EnergySynapse>, SpecError> {
+    let mut classes = Vec::new();
+    for item in block.items {
+        let Item::Block(class) = item else {
+            return Err(unknown(&format!("energy.synapse.{}", item_path(&item))));
+        };
+        let name = match class.path.as_slice() {
+            [head, name] if head == "class" => name.clone(),
+            _ => return Err(unknown(&format!("energy.synapse.{}", class.path.join(".")))),
+        };
+        let prefix = format!("energy.synapse.class.{name}");
+        let mut f = Fields::numbers(class)?;
+        let (min, max) = if f.has("esyn_fj_min") || f.has("esyn_fj_max") {
+            (f.take("esyn_fj_min")? / 1000.0, f.take("esyn_fj_max")? / 1000.0)
+        } else {
+            (f.take("esyn_pj_min")?, f.take("esyn_pj_max")?)
+        };
+        f.finish().map_err(|e| match e {
+            SpecError::UnknownKey { path } => unknown(&format!("{prefix}.{path}")),
+            e => e,
+        })?;
+        classes.push(EnergySynapse {
+            class: name,
+            esyn_pj_min: min,
+            esyn_pj_max: max,
+        });
+    }
+    Ok(classes)
+}
+
+fn item_path(item: &Item) -> String {
+    match item {
+        Item::Assign { path, .. } => path.join("."),
+        Item::Block(b) => b.path.join("."),
+    }
+}
+
+fn missing(path: &str) -> SpecError {
+    SpecError::Missing {
+        path: path.to_string(),
+    }
+}
+
+fn unknown(path: &str) -> SpecError {
+    SpecError::UnknownKey {
+        path: path.to_string(),
+    }
+}
+
+/// Flat `key = number;` assignments of one block, consumed by name.
+struct Fields {
+    block: String,
+    values: Vec<(String, f32)>,
+}
+
+impl Fields {
+    fn numbers(block: Block) -> Result<Self, SpecError> {
+        let name = block.path.join(".");
+        let mut values = Vec::new();
+        for item in block.items {
+            match item {
+                Item::Assign {
+                    path,
+                    value: Value::Number(n),
+                    ..
+                } => values.push((path.join("."), n)),
+                Item::Assign { path, line, .. } => {
+                    return Err(parse_err(
+                        line,
+                        &format!("{name}.{}: expected a number", path.join(".")),
+                    ))
+                }
+                Item::Block(b) => return Err(unknown(&format!("{name}.{}", b.path.join(".")))),
+            }
+        }
+        Ok(Self {
+            block: name,
+            values,
+        })
+    }
+
+    /// `prefix.name = "text";` (or `name = "text";` when `prefix` is empty).
+    fn strings(block: Block, prefix: &str) -> Result<Vec<(String, String)>, SpecError> {
+        let name = block.path.join(".");
+        let mut out = Vec::new();
+        for item in block.items {
+            let (path, value, line) = match item {
+                Item::Assign { path, value, line } => (path, value, line),
+                Item::Block(b) => return Err(unknown(&format!("{name}.{}", b.path.join(".")))),
+            };
+            let key = match (prefix, path.as_slice()) {
+                ("", [key]) => key.clone(),
+                (p, [head, key]) if head == p => key.clone(),
+                _ => return Err(unknown(&format!("{name}.{}", path.join(".")))),
+            };
+            let Value::Str(text) = value else {
+                return Err(parse_err(line, &format!("{name}.{}: expected a string", path.join("."))));
+            };
+            out.push((key, text));
+        }
+        Ok(out)
+    }
+
+    fn has(&self, key: &str) -> bool {
+        self.values.iter().any(|(k, _)| k == key)
+    }
+
+    fn take(&mut self, key: &str) -> Result<f32, SpecError> {
+        match self.values.iter().position(|(k, _)| k == key) {
+            Some(i) => Ok(self.values.remove(i).1),
+            None => Err(missing(&format!("{}.{key}", self.block))),
+        }
+    }
+
+    /// Reject anything left over so typos don't silently drop a bound.
+    fn finish(self) -> Result<(), SpecError> {
+        match self.values.first() {
+            Some((k, _)) => Err(unknown(&format!("{}.{k}", self.block))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shipped() -> NeuromorphicBioscaleSpec {
+        NEURO_PRINT_V2026_02.parse().expect("shipped spec parses")
+    }
+
+    #[test]
+    fn test_parses_v2026_02_thermal_and_energy_bounds() {
+        let spec = shipped();
+        assert_eq!(spec.version, "v2026_02");
+        assert_eq!(
+            spec.thermal,
+            ThermalEnvelope {
+                core_c_max: 37.8,
+                iface_delta_c: 0.7,
+                abort_delta_c: 2.0,
+            }
+        );
+
+        let bio = spec.energy_class("bio_proximal").unwrap();
+        assert!((bio.esyn_pj_min - 0.000_05).abs() < 1e-9);
+        assert!((bio.esyn_pj_max - 0.001).abs() < 1e-9);
+        let edge = spec.energy_class("edge_accel").unwrap();
+        assert_eq!((edge.esyn_pj_min, edge.esyn_pj_max), (0.2, 1.0));
+        let cmos = spec.energy_class("legacy_cmos").unwrap();
+        assert_eq!((cmos.esyn_pj_min, cmos.esyn_pj_max), (10.0, 400.0));
+
+        assert_eq!(spec.algo.spike_rate_hz_max, 1_000.0);
+        assert_eq!(spec.algo.evidence_hex.as_deref(), Some("a1f3c9b2"));
+        assert_eq!(spec.interfaces.len(), 3);
+        assert_eq!(spec.interfaces[1].material, "droplet_synapse");
+        assert_eq!(spec.evidence[0].name, "cortical_heating");
+        assert_eq!(spec.evidence[0].hex, "a1f3c9b2");
+        assert_eq!(spec.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_thermal_envelope() {
+        let mut spec = shipped();
+        spec.thermal.abort_delta_c = 0.7;
+        assert!(matches!(spec.validate(), Err(SpecError::Invalid { .. })));
+
+        let mut spec = shipped();
+        spec.thermal.core_c_max = 42.0;
+        assert!(matches!(spec.validate(), Err(SpecError::Invalid { .. })));
+
+        let mut spec = shipped();
+        spec.thermal.core_c_max = f32::NAN;
+        assert!(matches!(spec.validate(), Err(SpecError::Invalid { .. })));
+    }
+
+    #[test]
+    fn test_validate_rejects_inverted_energy_and_bad_hex() {
+        let mut spec = shipped();
+        spec.energy[2].esyn_pj_max = 1.0;
+        assert!(matches!(spec.validate(), Err(SpecError::Invalid { .. })));
+
+        let mut spec = shipped();
+        spec.evidence[0].hex = "not-hex".into();
+        assert!(matches!(spec.validate(), Err(SpecError::Invalid { .. })));
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_offending_key() {
+        let typo = NEURO_PRINT_V2026_02.replace("abort_delta_c", "abort_delta");
+        assert_eq!(
+            typo.parse::<NeuromorphicBioscaleSpec>(),
+            Err(SpecError::Missing {
+                path: "thermal.envelope.abort_delta_c".into()
+            })
+        );
+
+        let no_semicolon = NEURO_PRINT_V2026_02.replace("iface_delta_c = 0.7;", "iface_delta_c = 0.7");
+        assert!(matches!(
+            no_semicolon.parse::<NeuromorphicBioscaleSpec>(),
+            Err(SpecError::Parse { line: 5, .. })
+        ));
+    }
+}
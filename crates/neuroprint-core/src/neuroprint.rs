@@ -1,4 +1,10 @@
+use aln_core::ROH_CEILING;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// NATURE token attached to a view; the one enum shared with `neuroprint_core`,
+/// including its `Unknown` fallback for labels this build does not know.
+pub use neuroprint_core::nature::NatureLabel;
 
 /// View-only snapshot of governed neuromorph state.
 /// In your stack, this would be constructed from existing
@@ -22,6 +28,121 @@ pub struct NeuroPrintInput {
     pub biofield_intensity: f32, // 0.0–1.0 local field load
 }
 
+/// Why `NeuroPrintInputBuilder::build` rejected an input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NeuroPrintInputError {
+    /// A required field was never set.
+    Missing { field: &'static str },
+    /// A rail is NaN, infinite, or outside [0, 1].
+    OutOfRange { field: &'static str, value: f32 },
+    /// `roh_ceiling` must be finite and > 0.
+    InvalidRohCeiling { value: f32 },
+}
+
+/// Checked construction of `NeuroPrintInput` from device data.
+///
+/// - `subject_id`, `roh_ceiling`, and every physiology / capability rail are
+///   required; `epoch_index` defaults to 0.
+/// - Geometry rails (`bio_1d_coord`, `biofield_intensity`) default to 0.0.
+/// - Setters only record values; `build` checks each is finite and in [0, 1]
+///   and reports the first failure in field order.
+#[derive(Debug, Clone, Default)]
+pub struct NeuroPrintInputBuilder {
+    subject_id: Option<String>,
+    epoch_index: u64,
+    roh_after: Option<f32>,
+    roh_ceiling: Option<f32>,
+    hr_norm: Option<f32>,
+    hrv_norm: Option<f32>,
+    eeg_wave_norm: Option<f32>,
+    eda_norm: Option<f32>,
+    motion_norm: Option<f32>,
+    capability_tier: Option<f32>,
+    evolve_index: Option<f32>,
+    bio_1d_coord: Option<f32>,
+    biofield_intensity: Option<f32>,
+}
+
+macro_rules! rail_setters {
+    ($($field:ident),* $(,)?) => {
+        $(
+            pub fn $field(mut self, value: f32) -> Self {
+                self.$field = Some(value);
+                self
+            }
+        )*
+    };
+}
+
+impl NeuroPrintInput {
+    pub fn builder() -> NeuroPrintInputBuilder {
+        NeuroPrintInputBuilder::default()
+    }
+}
+
+impl NeuroPrintInputBuilder {
+    pub fn subject_id(mut self, subject_id: impl Into<String>) -> Self {
+        self.subject_id = Some(subject_id.into());
+        self
+    }
+
+    pub fn epoch_index(mut self, epoch_index: u64) -> Self {
+        self.epoch_index = epoch_index;
+        self
+    }
+
+    rail_setters!(
+        roh_after,
+        roh_ceiling,
+        hr_norm,
+        hrv_norm,
+        eeg_wave_norm,
+        eda_norm,
+        motion_norm,
+        capability_tier,
+        evolve_index,
+        bio_1d_coord,
+        biofield_intensity,
+    );
+
+    pub fn build(self) -> Result<NeuroPrintInput, NeuroPrintInputError> {
+        fn unit(field: &'static str, value: Option<f32>) -> Result<f32, NeuroPrintInputError> {
+            let value = value.ok_or(NeuroPrintInputError::Missing { field })?;
+            if (0.0..=1.0).contains(&value) {
+                Ok(value)
+            } else {
+                Err(NeuroPrintInputError::OutOfRange { field, value })
+            }
+        }
+
+        let subject_id = self
+            .subject_id
+            .ok_or(NeuroPrintInputError::Missing { field: "subject_id" })?;
+        let roh_ceiling = self
+            .roh_ceiling
+            .ok_or(NeuroPrintInputError::Missing { field: "roh_ceiling" })?;
+        if !(roh_ceiling.is_finite() && roh_ceiling > 0.0) {
+            return Err(NeuroPrintInputError::InvalidRohCeiling { value: roh_ceiling });
+        }
+
+        Ok(NeuroPrintInput {
+            subject_id,
+            epoch_index: self.epoch_index,
+            roh_after: unit("roh_after", self.roh_after)?,
+            roh_ceiling,
+            hr_norm: unit("hr_norm", self.hr_norm)?,
+            hrv_norm: unit("hrv_norm", self.hrv_norm)?,
+            eeg_wave_norm: unit("eeg_wave_norm", self.eeg_wave_norm)?,
+            eda_norm: unit("eda_norm", self.eda_norm)?,
+            motion_norm: unit("motion_norm", self.motion_norm)?,
+            capability_tier: unit("capability_tier", self.capability_tier)?,
+            evolve_index: unit("evolve_index", self.evolve_index)?,
+            bio_1d_coord: unit("bio_1d_coord", self.bio_1d_coord.or(Some(0.0)))?,
+            biofield_intensity: unit("biofield_intensity", self.biofield_intensity.or(Some(0.0)))?,
+        })
+    }
+}
+
 /// TREE-style diagnostic view (all 0.0–1.0, read-only).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuroPrintView {
@@ -46,7 +167,194 @@ pub struct NeuroPrintView {
     pub bio_coord_1d: f32,
     pub biofield_load: f32,
     // Optional advisory labels (CALM_STABLE, OVERLOADED, etc.)
-    pub nature_labels: Vec<String>,
+    pub nature_labels: Vec<NatureLabel>,
+}
+
+/// Signed per-rail change `self - prev` between two views of one subject.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NeuroPrintDiff {
+    pub subject_id: String,
+    pub from_epoch: u64,
+    pub to_epoch: u64,
+    pub blood: f32,
+    pub oxygen: f32,
+    pub wave: f32,
+    pub time: f32,
+    pub decay: f32,
+    pub lifeforce: f32,
+    pub brain: f32,
+    pub smart: f32,
+    pub evolve: f32,
+    pub power: f32,
+    pub tech: f32,
+    pub fear: f32,
+    pub pain: f32,
+    pub nano: f32,
+    pub bio_coord_1d: f32,
+    pub biofield_load: f32,
+    /// Labels present now but not in `prev`, in current order.
+    pub labels_added: Vec<NatureLabel>,
+    /// Labels present in `prev` but not now, in previous order.
+    pub labels_removed: Vec<NatureLabel>,
+}
+
+impl NeuroPrintView {
+    /// Per-rail deltas from `prev` to `self`. Returns `None` when the views
+    /// belong to different subjects.
+    pub fn diff(&self, prev: &NeuroPrintView) -> Option<NeuroPrintDiff> {
+        if self.subject_id != prev.subject_id {
+            return None;
+        }
+        let missing_from = |labels: &[NatureLabel], other: &[NatureLabel]| {
+            let mut out: Vec<NatureLabel> = Vec::new();
+            for label in labels {
+                if !other.contains(label) && !out.contains(label) {
+                    out.push(*label);
+                }
+            }
+            out
+        };
+
+        Some(NeuroPrintDiff {
+            subject_id: self.subject_id.clone(),
+            from_epoch: prev.epoch_index,
+            to_epoch: self.epoch_index,
+            blood: self.blood - prev.blood,
+            oxygen: self.oxygen - prev.oxygen,
+            wave: self.wave - prev.wave,
+            time: self.time - prev.time,
+            decay: self.decay - prev.decay,
+            lifeforce: self.lifeforce - prev.lifeforce,
+            brain: self.brain - prev.brain,
+            smart: self.smart - prev.smart,
+            evolve: self.evolve - prev.evolve,
+            power: self.power - prev.power,
+            tech: self.tech - prev.tech,
+            fear: self.fear - prev.fear,
+            pain: self.pain - prev.pain,
+            nano: self.nano - prev.nano,
+            bio_coord_1d: self.bio_coord_1d - prev.bio_coord_1d,
+            biofield_load: self.biofield_load - prev.biofield_load,
+            labels_added: missing_from(&self.nature_labels, &prev.nature_labels),
+            labels_removed: missing_from(&prev.nature_labels, &self.nature_labels),
+        })
+    }
+}
+
+/// Cells per rail in `NeuroPrintView::summary_bar`.
+pub const SUMMARY_BAR_WIDTH: usize = 10;
+
+/// One-line HUD summary, e.g.
+/// `subj=subject-a epoch=42 DECAY=0.40 LIFEFORCE=0.60 FEAR=0.34 PAIN=0.20 [CALM_STABLE]`.
+impl fmt::Display for NeuroPrintView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "subj={} epoch={} DECAY={:.2} LIFEFORCE={:.2} FEAR={:.2} PAIN={:.2} [",
+            self.subject_id, self.epoch_index, self.decay, self.lifeforce, self.fear, self.pain
+        )?;
+        for (i, label) in self.nature_labels.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(label.as_str())?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl NeuroPrintView {
+    /// Distress rails as text bars, e.g. `DECAY [####......] FEAR [###.......] PAIN [##........]`.
+    /// Each cell is 1/`SUMMARY_BAR_WIDTH` of the rail; NaN renders empty.
+    pub fn summary_bar(&self) -> String {
+        let rails = [("DECAY", self.decay), ("FEAR", self.fear), ("PAIN", self.pain)];
+        let mut out = String::with_capacity(rails.len() * (SUMMARY_BAR_WIDTH + 9));
+        for (i, (name, value)) in rails.into_iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            let filled = (clamp01(value) * SUMMARY_BAR_WIDTH as f32).round() as usize;
+            out.push_str(name);
+            out.push_str(" [");
+            out.extend((0..SUMMARY_BAR_WIDTH).map(|c| if c < filled { '#' } else { '.' }));
+            out.push(']');
+        }
+        out
+    }
+}
+
+/// Blend coefficients for the composite TREE rails. Defaults are the
+/// reference calibration; labs can load device-specific values from ALN/JSON,
+/// where omitted fields fall back to the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NeuroPrintWeights {
+    /// SMART = smart_brain * BRAIN + smart_evolve * EVOLVE
+    pub smart_brain: f32,
+    pub smart_evolve: f32,
+    /// POWER = power_hr * hr_norm + power_eeg * eeg_wave_norm
+    pub power_hr: f32,
+    pub power_eeg: f32,
+    /// TECH = tech_brain * BRAIN + tech_power * POWER
+    pub tech_brain: f32,
+    pub tech_power: f32,
+    /// FEAR = fear_eda * eda_norm + fear_hr * hr_norm
+    pub fear_eda: f32,
+    pub fear_hr: f32,
+    /// PAIN = pain_motion * motion_norm + pain_eda * eda_norm
+    pub pain_motion: f32,
+    pub pain_eda: f32,
+    /// Session length (in epochs) the TIME rail is scaled against.
+    pub time_scale_epochs: f32,
+    /// How epochs map onto the TIME rail.
+    pub time_mapping: TimeMapping,
+}
+
+/// TIME rail mapping from `epoch_index`, with `s = time_scale_epochs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeMapping {
+    /// `epoch / s`, saturating at 1.0 once the session reaches `s` epochs.
+    #[default]
+    Linear,
+    /// `x / (1 + x)` with `x = ln(1 + epoch / s)`: never saturates, so long
+    /// sessions keep gradation (≈0.41 at `s`, ≈0.71 at `10·s`).
+    Log,
+}
+
+impl TimeMapping {
+    fn map(self, epoch_index: u64, scale_epochs: f32) -> f32 {
+        if scale_epochs.is_nan() || scale_epochs <= 0.0 {
+            return 0.0;
+        }
+        let ratio = epoch_index as f32 / scale_epochs;
+        match self {
+            TimeMapping::Linear => clamp01(ratio),
+            TimeMapping::Log => {
+                let x = ratio.ln_1p();
+                clamp01(x / (1.0 + x))
+            }
+        }
+    }
+}
+
+impl Default for NeuroPrintWeights {
+    fn default() -> Self {
+        Self {
+            smart_brain: 0.5,
+            smart_evolve: 0.5,
+            power_hr: 0.5,
+            power_eeg: 0.5,
+            tech_brain: 0.5,
+            tech_power: 0.5,
+            fear_eda: 0.6,
+            fear_hr: 0.4,
+            pain_motion: 0.5,
+            pain_eda: 0.5,
+            time_scale_epochs: 10_000.0,
+            time_mapping: TimeMapping::Linear,
+        }
+    }
 }
 
 fn clamp01(x: f32) -> f32 {
@@ -64,6 +372,15 @@ fn clamp01(x: f32) -> f32 {
 /// Map governed inputs + biofield 1D geometry into a TREE/NATURE view.
 /// Pure function: NO side effects, NO capability writes.
 pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
+    neuroprint_from_snapshot_with_weights(input, &NeuroPrintWeights::default())
+}
+
+/// Same as `neuroprint_from_snapshot`, with composite-rail blend weights
+/// taken from `weights` instead of the reference calibration.
+pub fn neuroprint_from_snapshot_with_weights(
+    input: &NeuroPrintInput,
+    weights: &NeuroPrintWeights,
+) -> NeuroPrintView {
     // RoH-based rails
     let roh_norm = if input.roh_ceiling > 0.0 {
         clamp01(input.roh_after / input.roh_ceiling)
@@ -81,15 +398,15 @@ pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
     // Capability / evolution rails
     let brain = clamp01(input.capability_tier);
     let evolve = clamp01(input.evolve_index);
-    let smart = clamp01(0.5 * brain + 0.5 * evolve);
+    let smart = clamp01(weights.smart_brain * brain + weights.smart_evolve * evolve);
 
     // Power / tech (simplified weighted loads)
-    let power = clamp01(0.5 * input.hr_norm + 0.5 * input.eeg_wave_norm);
-    let tech = clamp01(0.5 * brain + 0.5 * power);
+    let power = clamp01(weights.power_hr * input.hr_norm + weights.power_eeg * input.eeg_wave_norm);
+    let tech = clamp01(weights.tech_brain * brain + weights.tech_power * power);
 
     // Distress rails from EDA + motion
-    let fear = clamp01(0.6 * input.eda_norm + 0.4 * input.hr_norm);
-    let pain = clamp01(0.5 * input.motion_norm + 0.5 * input.eda_norm);
+    let fear = clamp01(weights.fear_eda * input.eda_norm + weights.fear_hr * input.hr_norm);
+    let pain = clamp01(weights.pain_motion * input.motion_norm + weights.pain_eda * input.eda_norm);
 
     // Nano rail: reuse evolve for now (you can specialize later)
     let nano = evolve;
@@ -101,14 +418,14 @@ pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
     // Simple NATURE labelling (diagnostic only)
     let mut nature_labels = Vec::new();
     if lifeforce > 0.7 && fear < 0.3 && pain < 0.3 && decay < 0.3 {
-        nature_labels.push("CALM_STABLE".to_string());
+        nature_labels.push(NatureLabel::CalmStable);
     }
     if decay > 0.7 || fear > 0.7 || pain > 0.7 {
-        nature_labels.push("OVERLOADED".to_string());
+        nature_labels.push(NatureLabel::Overloaded);
     }
     // Example fairness hint using 1D geometry (still advisory)
     if biofield_load > 0.8 && lifeforce < 0.4 {
-        nature_labels.push("LOCAL_1D_OVERLOAD".to_string());
+        nature_labels.push(NatureLabel::Local1dOverload);
     }
 
     NeuroPrintView {
@@ -117,7 +434,9 @@ pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
         blood,
         oxygen,
         wave,
-        time: clamp01(input.epoch_index as f32 / 10_000.0),
+        time: weights
+            .time_mapping
+            .map(input.epoch_index, weights.time_scale_epochs),
         decay,
         lifeforce,
         brain,
@@ -134,6 +453,111 @@ pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
     }
 }
 
+/// Approximate inverse of `neuroprint_from_snapshot_with_weights`, for
+/// "what-if" tooling that starts from a target view.
+///
+/// - Exactly invertible (one rail, one input): `epoch_index`, `bio_1d_coord`
+///   (BIO_COORD_1D), `biofield_intensity` (BIOFIELD_LOAD), and `roh_after`
+///   from DECAY/LIFEFORCE, rescaled onto the global `ROH_CEILING` (a view
+///   does not record the ceiling it was built under).
+/// - Coupled rails: `hr_norm`, `hrv_norm`, `eeg_wave_norm`, `eda_norm`,
+///   `motion_norm`, `capability_tier` and `evolve_index` are the least-squares
+///   solution over BLOOD, OXYGEN, WAVE, BRAIN, EVOLVE, NANO, SMART, POWER,
+///   TECH, FEAR and PAIN under `weights`. On a view the forward map produced
+///   without clamping this recovers the inputs; on an edited view it is the
+///   closest consistent input. An input no rail depends on (e.g. `eda_norm`
+///   with both EDA weights at 0) comes back as 0.
+/// - TIME and the NATURE labels are derived, not inverted.
+///
+/// Recovered inputs are clamped to [0, 1] like the forward map's rails.
+pub fn approximate_inputs(view: &NeuroPrintView, weights: &NeuroPrintWeights) -> NeuroPrintInput {
+    // DECAY and 1 - LIFEFORCE both observe roh_after / roh_ceiling.
+    let roh_norm = clamp01((view.decay + (1.0 - view.lifeforce)) * 0.5);
+
+    // Unknowns: [hr, hrv, eeg, eda, motion, tier, evolve].
+    let w = |x: f32| x as f64;
+    let (ph, pe) = (w(weights.power_hr), w(weights.power_eeg));
+    let tp = w(weights.tech_power);
+    let rows: [([f64; 7], f32); 11] = [
+        ([1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], view.blood),
+        ([0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0], view.oxygen),
+        ([0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0], view.wave),
+        ([0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0], view.brain),
+        ([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0], view.evolve),
+        ([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0], view.nano),
+        (
+            [0.0, 0.0, 0.0, 0.0, 0.0, w(weights.smart_brain), w(weights.smart_evolve)],
+            view.smart,
+        ),
+        ([ph, 0.0, pe, 0.0, 0.0, 0.0, 0.0], view.power),
+        ([tp * ph, 0.0, tp * pe, 0.0, 0.0, w(weights.tech_brain), 0.0], view.tech),
+        ([w(weights.fear_hr), 0.0, 0.0, w(weights.fear_eda), 0.0, 0.0, 0.0], view.fear),
+        ([0.0, 0.0, 0.0, w(weights.pain_eda), w(weights.pain_motion), 0.0, 0.0], view.pain),
+    ];
+    let x = least_squares(&rows);
+    let solved = |i: usize| clamp01(x[i] as f32);
+
+    NeuroPrintInput {
+        subject_id: view.subject_id.clone(),
+        epoch_index: view.epoch_index,
+        roh_after: roh_norm * ROH_CEILING,
+        roh_ceiling: ROH_CEILING,
+        hr_norm: solved(0),
+        hrv_norm: solved(1),
+        eeg_wave_norm: solved(2),
+        eda_norm: solved(3),
+        motion_norm: solved(4),
+        capability_tier: solved(5),
+        evolve_index: solved(6),
+        bio_1d_coord: clamp01(view.bio_coord_1d),
+        biofield_intensity: clamp01(view.biofield_load),
+    }
+}
+
+/// Solve `min |A x - b|` via the normal equations with a tiny ridge term, so
+/// unobservable unknowns settle at 0 instead of making the system singular.
+fn least_squares<const N: usize>(rows: &[([f64; N], f32)]) -> [f64; N] {
+    const RIDGE: f64 = 1e-12;
+    let mut m = [[0.0f64; N]; N];
+    let mut rhs = [0.0f64; N];
+    for (a, b) in rows {
+        let b = if b.is_nan() { 0.0 } else { *b as f64 };
+        for i in 0..N {
+            rhs[i] += a[i] * b;
+            for j in 0..N {
+                m[i][j] += a[i] * a[j];
+            }
+        }
+    }
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] += RIDGE;
+    }
+
+    // Gaussian elimination with partial pivoting; AᵀA + ridge is SPD.
+    for col in 0..N {
+        let pivot = (col..N)
+            .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+            .unwrap_or(col);
+        m.swap(col, pivot);
+        rhs.swap(col, pivot);
+        let (upper, lower) = m.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let f = row[col] / pivot_row[col];
+            for (dst, src) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *dst -= f * src;
+            }
+            rhs[col + 1 + offset] -= f * rhs[col];
+        }
+    }
+    let mut x = [0.0f64; N];
+    for i in (0..N).rev() {
+        let tail: f64 = (i + 1..N).map(|k| m[i][k] * x[k]).sum();
+        x[i] = (rhs[i] - tail) / m[i][i];
+    }
+    x
+}
+
 /// JSONL-friendly wrapper: turn a slice of inputs into newline-delimited views.
 pub fn render_jsonl(inputs: &[NeuroPrintInput]) -> String {
     let mut out = String::new();
@@ -146,3 +570,263 @@ pub fn render_jsonl(inputs: &[NeuroPrintInput]) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> NeuroPrintInput {
+        NeuroPrintInput {
+            subject_id: "subject-a".to_string(),
+            epoch_index: 42,
+            roh_after: 0.12,
+            roh_ceiling: 0.30,
+            hr_norm: 0.4,
+            hrv_norm: 0.6,
+            eeg_wave_norm: 0.5,
+            eda_norm: 0.3,
+            motion_norm: 0.2,
+            capability_tier: 0.5,
+            evolve_index: 0.25,
+            bio_1d_coord: 0.5,
+            biofield_intensity: 0.3,
+        }
+    }
+
+    fn builder() -> NeuroPrintInputBuilder {
+        NeuroPrintInput::builder()
+            .subject_id("subject-a")
+            .epoch_index(42)
+            .roh_after(0.12)
+            .roh_ceiling(0.30)
+            .hr_norm(0.4)
+            .hrv_norm(0.6)
+            .eeg_wave_norm(0.5)
+            .eda_norm(0.3)
+            .motion_norm(0.2)
+            .capability_tier(0.5)
+            .evolve_index(0.25)
+    }
+
+    #[test]
+    fn test_builder_matches_literal_and_defaults_geometry() {
+        let built = builder().bio_1d_coord(0.5).biofield_intensity(0.3).build().unwrap();
+        assert_eq!(
+            serde_json::to_string(&built).unwrap(),
+            serde_json::to_string(&input()).unwrap()
+        );
+
+        let bare = builder().build().unwrap();
+        assert_eq!((bare.bio_1d_coord, bare.biofield_intensity), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_builder_rejects_out_of_range_rail() {
+        // HR given in bpm instead of normalized.
+        assert_eq!(
+            builder().hr_norm(72.0).build().unwrap_err(),
+            NeuroPrintInputError::OutOfRange { field: "hr_norm", value: 72.0 }
+        );
+        assert!(matches!(
+            builder().biofield_intensity(f32::NAN).build(),
+            Err(NeuroPrintInputError::OutOfRange { field: "biofield_intensity", .. })
+        ));
+        assert_eq!(
+            builder().roh_ceiling(0.0).build().unwrap_err(),
+            NeuroPrintInputError::InvalidRohCeiling { value: 0.0 }
+        );
+    }
+
+    #[test]
+    fn test_builder_requires_subject_and_rails() {
+        let no_subject = NeuroPrintInputBuilder {
+            subject_id: None,
+            ..builder()
+        };
+        assert_eq!(
+            no_subject.build().unwrap_err(),
+            NeuroPrintInputError::Missing { field: "subject_id" }
+        );
+        let no_eda = NeuroPrintInputBuilder {
+            eda_norm: None,
+            ..builder()
+        };
+        assert_eq!(
+            no_eda.build().unwrap_err(),
+            NeuroPrintInputError::Missing { field: "eda_norm" }
+        );
+    }
+
+    #[test]
+    fn test_default_weights_match_reference_mapping() {
+        let view = neuroprint_from_snapshot(&input());
+        assert!((view.fear - (0.6 * 0.3 + 0.4 * 0.4)).abs() < 1e-6);
+        assert!((view.power - 0.45).abs() < 1e-6);
+        assert_eq!(
+            serde_json::from_str::<NeuroPrintWeights>("{}").unwrap(),
+            NeuroPrintWeights::default()
+        );
+    }
+
+    fn time_at(epoch_index: u64, time_mapping: TimeMapping) -> f32 {
+        let weights = NeuroPrintWeights {
+            time_mapping,
+            ..NeuroPrintWeights::default()
+        };
+        let input = NeuroPrintInput {
+            epoch_index,
+            ..input()
+        };
+        neuroprint_from_snapshot_with_weights(&input, &weights).time
+    }
+
+    #[test]
+    fn test_time_rail_linear_and_log_mappings() {
+        // Linear (default) is unchanged below the scale and saturates at it.
+        assert_eq!(time_at(5_000, TimeMapping::Linear), 0.5);
+        assert_eq!(time_at(10_000, TimeMapping::Linear), 1.0);
+        assert_eq!(time_at(100_000, TimeMapping::Linear), 1.0);
+
+        // Log keeps resolution past the scale.
+        let log_5k = time_at(5_000, TimeMapping::Log);
+        let log_10k = time_at(10_000, TimeMapping::Log);
+        let log_100k = time_at(100_000, TimeMapping::Log);
+        assert!((log_10k - 2f32.ln() / (1.0 + 2f32.ln())).abs() < 1e-6);
+        assert!((log_100k - 11f32.ln() / (1.0 + 11f32.ln())).abs() < 1e-6);
+        assert!(0.0 < log_5k && log_5k < log_10k && log_10k < log_100k && log_100k < 1.0);
+    }
+
+    #[test]
+    fn test_nature_labels_serialize_as_uppercase_strings() {
+        let view = neuroprint_from_snapshot(&NeuroPrintInput {
+            roh_after: 0.0,
+            eda_norm: 0.0,
+            motion_norm: 0.0,
+            hr_norm: 0.0,
+            ..input()
+        });
+        assert_eq!(view.nature_labels, vec![NatureLabel::CalmStable]);
+
+        let json = serde_json::to_value(&view).unwrap();
+        assert_eq!(json["nature_labels"], serde_json::json!(["CALM_STABLE"]));
+        let back: NeuroPrintView = serde_json::from_value(json).unwrap();
+        assert_eq!(back.nature_labels, view.nature_labels);
+
+        assert_eq!("LOCAL_1D_OVERLOAD".parse(), Ok(NatureLabel::Local1dOverload));
+        assert_eq!(NatureLabel::Overloaded.to_string(), "OVERLOADED");
+
+        let mut json = serde_json::to_value(&view).unwrap();
+        json["nature_labels"] = serde_json::json!(["ROH_VOLATILE", "DEEP_SLEEP"]);
+        let newer: NeuroPrintView = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            newer.nature_labels,
+            vec![NatureLabel::RohVolatile, NatureLabel::Unknown]
+        );
+    }
+
+    #[test]
+    fn test_diff_against_self_is_zero_and_rising_decay_is_positive() {
+        let view = neuroprint_from_snapshot(&input());
+        let same = view.diff(&view).unwrap();
+        let zeros = serde_json::to_value(&same).unwrap();
+        for (key, value) in zeros.as_object().unwrap() {
+            if let Some(delta) = value.as_f64().filter(|_| !key.ends_with("_epoch")) {
+                assert_eq!(delta, 0.0, "{} should not change", key);
+            }
+        }
+        assert!(same.labels_added.is_empty() && same.labels_removed.is_empty());
+
+        let later = neuroprint_from_snapshot(&NeuroPrintInput {
+            epoch_index: 43,
+            roh_after: 0.27,
+            ..input()
+        });
+        let rising = later.diff(&view).unwrap();
+        assert!((rising.decay - 0.5).abs() < 1e-6);
+        assert!((rising.lifeforce + 0.5).abs() < 1e-6);
+        assert_eq!((rising.from_epoch, rising.to_epoch), (42, 43));
+        assert_eq!(rising.labels_added, vec![NatureLabel::Overloaded]);
+
+        let other = NeuroPrintView {
+            subject_id: "subject-b".to_string(),
+            ..view.clone()
+        };
+        assert_eq!(other.diff(&view), None);
+    }
+
+    #[test]
+    fn test_approximate_inputs_round_trip() {
+        let weights = NeuroPrintWeights::default();
+        let original = input();
+        let view = neuroprint_from_snapshot_with_weights(&original, &weights);
+        let recovered = approximate_inputs(&view, &weights);
+
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+        assert_eq!(recovered.epoch_index, original.epoch_index);
+        assert!(close(recovered.roh_after, original.roh_after));
+        assert!(close(recovered.bio_1d_coord, original.bio_1d_coord));
+        assert!(close(recovered.biofield_intensity, original.biofield_intensity));
+        // Consistent (unclamped) view: the coupled rails come back too.
+        assert!(close(recovered.hr_norm, original.hr_norm));
+        assert!(close(recovered.eda_norm, original.eda_norm));
+        assert!(close(recovered.motion_norm, original.motion_norm));
+        assert!(close(recovered.capability_tier, original.capability_tier));
+
+        // Raising only FEAR in a what-if view: EDA absorbs the rise and
+        // MOTION drops (to its floor here) so PAIN stays where it was.
+        let what_if = NeuroPrintView {
+            fear: view.fear + 0.2,
+            ..view.clone()
+        };
+        let estimate = approximate_inputs(&what_if, &weights);
+        assert!(close(estimate.eda_norm, original.eda_norm + 0.2 / weights.fear_eda));
+        assert_eq!(estimate.motion_norm, 0.0);
+        assert!(close(estimate.hr_norm, original.hr_norm));
+
+        // EDA drives no rail at all: it is unobservable and comes back 0.
+        let blind = NeuroPrintWeights {
+            fear_eda: 0.0,
+            pain_eda: 0.0,
+            ..NeuroPrintWeights::default()
+        };
+        let blind_view = neuroprint_from_snapshot_with_weights(&original, &blind);
+        assert_eq!(approximate_inputs(&blind_view, &blind).eda_norm, 0.0);
+    }
+
+    #[test]
+    fn test_display_and_summary_bar() {
+        let mut view = neuroprint_from_snapshot(&input());
+        view.nature_labels = vec![NatureLabel::CalmStable, NatureLabel::Local1dOverload];
+        let line = view.to_string();
+        assert!(line.starts_with("subj=subject-a epoch=42 "));
+        assert!(line.contains("DECAY=0.40"));
+        assert!(line.ends_with("[CALM_STABLE LOCAL_1D_OVERLOAD]"));
+
+        view.nature_labels.clear();
+        assert!(view.to_string().ends_with(" []"));
+
+        // DECAY 0.40, FEAR 0.34, PAIN 0.25 (rounds half away from zero).
+        assert_eq!(
+            view.summary_bar(),
+            "DECAY [####......] FEAR [###.......] PAIN [###.......]"
+        );
+    }
+
+    #[test]
+    fn test_fear_weights_change_only_fear_rail() {
+        let base = neuroprint_from_snapshot(&input());
+        let weights = NeuroPrintWeights {
+            fear_eda: 0.2,
+            fear_hr: 0.1,
+            ..NeuroPrintWeights::default()
+        };
+        let tuned = neuroprint_from_snapshot_with_weights(&input(), &weights);
+
+        assert!((tuned.fear - (0.2 * 0.3 + 0.1 * 0.4)).abs() < 1e-6);
+        assert_ne!(tuned.fear, base.fear);
+
+        let mut expected = serde_json::to_value(&base).unwrap();
+        expected["fear"] = serde_json::to_value(tuned.fear).unwrap();
+        assert_eq!(serde_json::to_value(&tuned).unwrap(), expected);
+    }
+}
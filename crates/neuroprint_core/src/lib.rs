@@ -1,8 +1,20 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use capability_core::CapabilityState;
 use envelope_core::BiophysicalEnvelopeSnapshot;
 use roh_model::RoHProjection;
 
+#[cfg(feature = "std")]
+pub mod log;
+pub mod nature;
+
+use crate::nature::NatureLabel;
+
 /// View-only input for a single neuromorphic snapshot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuroPrintInput {
@@ -13,6 +25,11 @@ pub struct NeuroPrintInput {
     pub evolve_index: Option<u64>,
     /// Optional epoch index within session.
     pub epoch_index: Option<u64>,
+    /// Optional per-axis breakdown of `roh.after` as `(axis, contribution)`,
+    /// e.g. weight * axis value from the envelope projection. Empty when the
+    /// caller only has the scalar RoH.
+    #[serde(default)]
+    pub roh_axis_contributions: Vec<(String, f32)>,
 }
 
 /// Human-binary compatible TREE view, normalized to [0.0, 1.0].
@@ -33,9 +50,35 @@ pub struct NeuroPrintView {
     pub fear: f32,
     pub pain: f32,
     pub nano: f32,
-    /// Optional advisory labels (e.g., NATURE tokens as strings).
-    pub labels: Vec<String>,
+    /// Optional advisory NATURE labels.
+    pub labels: Vec<NatureLabel>,
+    /// Per-axis RoH contributions carried over from the input; empty if none
+    /// were supplied.
+    #[serde(default)]
+    pub roh_axis_contributions: Vec<(String, f32)>,
+}
+
+impl NeuroPrintView {
+    /// Axis with the largest RoH contribution, i.e. what is driving DECAY.
+    /// `None` without a breakdown; NaN contributions are ignored.
+    pub fn dominant_axis(&self) -> Option<&str> {
+        self.roh_axis_contributions
+            .iter()
+            .filter(|(_, c)| !c.is_nan())
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(axis, _)| axis.as_str())
+    }
+}
+/// Clamp to [0.0, 1.0]; NaN maps to 0.0 so it never reaches NATURE labels
+/// or cohort statistics.
+fn clamp01(x: f32) -> f32 {
+    if x.is_nan() {
+        0.0
+    } else {
+        x.clamp(0.0, 1.0)
+    }
 }
+
 /// Pure, non-actuating projection from governed state to NeuroPrintView.
 pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
     // Internal helpers use only envelope + RoH + capability, never mutate them.
@@ -45,9 +88,13 @@ pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
     let time = clamp01(map_time(&input.envelope));
 
     // RoH-based assets; RoHProjection enforces roh_after <= roh_ceiling <= 0.3.
-    let roh_norm = clamp01(input.roh.after / input.roh.ceiling);
+    let roh_norm = if input.roh.ceiling > 0.0 {
+        clamp01(input.roh.after / input.roh.ceiling)
+    } else {
+        0.0
+    };
     let decay = roh_norm;
-    let lifeforce = 1.0 - roh_norm;
+    let lifeforce = clamp01(1.0 - roh_norm);
 
     let brain = clamp01(map_brain(&input.capability_state));
     let smart = clamp01(map_smart(&input.capability_state));
@@ -76,6 +123,7 @@ pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
         pain,
         nano,
         labels,
+        roh_axis_contributions: input.roh_axis_contributions.clone(),
     }
 }
 
@@ -85,3 +133,68 @@ macro_rules! neuroprint {
         $crate::neuroprint_from_snapshot(&$input)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rails(v: &NeuroPrintView) -> [f32; 14] {
+        [
+            v.blood, v.oxygen, v.wave, v.time, v.decay, v.lifeforce, v.brain, v.smart, v.evolve,
+            v.power, v.tech, v.fear, v.pain, v.nano,
+        ]
+    }
+
+    fn input(after: f32, ceiling: f32) -> NeuroPrintInput {
+        NeuroPrintInput {
+            capability_state: CapabilityState::CapControlledHuman,
+            roh: RoHProjection {
+                before: after,
+                after,
+                ceiling,
+            },
+            envelope: BiophysicalEnvelopeSnapshot::default(),
+            evolve_index: Some(3),
+            epoch_index: Some(1),
+            roh_axis_contributions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_clamp01_maps_nan_to_zero() {
+        // Every envelope-derived rail passes through clamp01.
+        assert_eq!(clamp01(f32::NAN), 0.0);
+        assert_eq!(clamp01(f32::INFINITY), 1.0);
+        assert_eq!(clamp01(f32::NEG_INFINITY), 0.0);
+        assert_eq!(clamp01(0.4), 0.4);
+    }
+
+    #[test]
+    fn test_zero_ceiling_and_nan_roh_keep_rails_in_range() {
+        for (after, ceiling) in [(0.1, 0.0), (0.0, 0.0), (f32::NAN, 0.3), (0.1, f32::NAN)] {
+            let view = neuroprint_from_snapshot(&input(after, ceiling));
+            for rail in rails(&view) {
+                assert!((0.0..=1.0).contains(&rail), "{} out of range", rail);
+            }
+            assert_eq!(view.decay, 0.0);
+            assert_eq!(view.lifeforce, 1.0);
+        }
+    }
+
+    #[test]
+    fn test_dominant_axis_reports_thermal_driver() {
+        let plain = neuroprint_from_snapshot(&input(0.15, 0.3));
+        assert_eq!(plain.dominant_axis(), None);
+
+        let mut with_axes = input(0.15, 0.3);
+        with_axes.roh_axis_contributions = vec![
+            ("thermal".to_string(), 0.09),
+            ("arousal".to_string(), 0.04),
+            ("motion".to_string(), f32::NAN),
+            ("cognitive".to_string(), 0.02),
+        ];
+        let view = neuroprint_from_snapshot(&with_axes);
+        assert_eq!(view.decay, plain.decay);
+        assert_eq!(view.dominant_axis(), Some("thermal"));
+    }
+}
@@ -1,9 +1,21 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use crate::{NeuroPrintView};
 use crate::nature::NatureLabels;
 use capability_core::CapabilityState;
 use roh_model::RoHProjection;
 
+/// Where a record came from. Rows written before this field existed were
+/// produced by live sessions, so it defaults to `Live`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProvenanceMode {
+    Simulation,
+    LabBench,
+    #[default]
+    Live,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuroPrintLogEntry {
     pub timestamp_ms: u64,
@@ -13,4 +25,393 @@ pub struct NeuroPrintLogEntry {
     pub roh: RoHProjection,
     pub neuroprint: NeuroPrintView,
     pub nature: Option<NatureLabels>,
+    #[serde(default)]
+    pub provenance_mode: ProvenanceMode,
+}
+
+/// Entries recorded in the given provenance mode, in log order.
+pub fn entries_with_mode(
+    entries: &[NeuroPrintLogEntry],
+    mode: ProvenanceMode,
+) -> Vec<&NeuroPrintLogEntry> {
+    entries.iter().filter(|e| e.provenance_mode == mode).collect()
+}
+
+/// Live-session entries only; use before computing live statistics.
+pub fn live_only(entries: &[NeuroPrintLogEntry]) -> Vec<&NeuroPrintLogEntry> {
+    entries_with_mode(entries, ProvenanceMode::Live)
+}
+
+/// Simulation entries only.
+pub fn simulation_only(entries: &[NeuroPrintLogEntry]) -> Vec<&NeuroPrintLogEntry> {
+    entries_with_mode(entries, ProvenanceMode::Simulation)
+}
+
+/// First per-subject ordering violation found in a log; `index` is the
+/// position of the offending entry in the input slice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderingError {
+    /// `epoch_index` went backwards for this subject.
+    OutOfOrder {
+        index: usize,
+        subject_id: String,
+        previous_epoch: u64,
+        epoch_index: u64,
+    },
+    /// `epoch_index` repeated for this subject.
+    DuplicateEpoch {
+        index: usize,
+        subject_id: String,
+        epoch_index: u64,
+    },
+}
+
+/// Check that `epoch_index` is strictly increasing within each subject.
+/// Interleaving between subjects is not constrained.
+pub fn validate_log_ordering(entries: &[NeuroPrintLogEntry]) -> Result<(), OrderingError> {
+    let mut last: HashMap<&str, u64> = HashMap::new();
+    for (index, e) in entries.iter().enumerate() {
+        if let Some(&previous_epoch) = last.get(e.subject_id.as_str()) {
+            if e.epoch_index == previous_epoch {
+                return Err(OrderingError::DuplicateEpoch {
+                    index,
+                    subject_id: e.subject_id.clone(),
+                    epoch_index: e.epoch_index,
+                });
+            }
+            if e.epoch_index < previous_epoch {
+                return Err(OrderingError::OutOfOrder {
+                    index,
+                    subject_id: e.subject_id.clone(),
+                    previous_epoch,
+                    epoch_index: e.epoch_index,
+                });
+            }
+        }
+        last.insert(e.subject_id.as_str(), e.epoch_index);
+    }
+    Ok(())
+}
+
+/// What `sort_and_dedup` changed, for the audit trail.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderingRepair {
+    /// Entries whose position changed after sorting.
+    pub moved: usize,
+    /// `(subject_id, epoch_index)` of each dropped duplicate.
+    pub dropped: Vec<(String, u64)>,
+}
+
+impl OrderingRepair {
+    pub fn is_noop(&self) -> bool {
+        self.moved == 0 && self.dropped.is_empty()
+    }
+}
+
+/// Repair variant of `validate_log_ordering`: stable-sorts by `epoch_index`
+/// and keeps only the first recorded entry for each `(subject, epoch)`.
+/// Afterwards `validate_log_ordering` always succeeds.
+pub fn sort_and_dedup(entries: &mut Vec<NeuroPrintLogEntry>) -> OrderingRepair {
+    let mut repair = OrderingRepair::default();
+
+    let mut seen: HashSet<(String, u64)> = HashSet::new();
+    entries.retain(|e| {
+        let key = (e.subject_id.clone(), e.epoch_index);
+        if seen.contains(&key) {
+            repair.dropped.push(key);
+            false
+        } else {
+            seen.insert(key);
+            true
+        }
+    });
+
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|&i| entries[i].epoch_index);
+    repair.moved = order.iter().enumerate().filter(|(pos, i)| pos != *i).count();
+    if repair.moved > 0 {
+        entries.sort_by_key(|e| e.epoch_index);
+    }
+    repair
+}
+
+/// Session rollup for one subject, computed from recorded log entries only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubjectSessionSummary {
+    pub subject_id: String,
+    pub epoch_count: usize,
+    pub first_epoch: u64,
+    pub last_epoch: u64,
+    /// Highest `roh.after` seen in the session.
+    pub peak_roh: f32,
+    /// Epochs that carried NATURE labels; the fractions below use this denominator.
+    pub labelled_epochs: usize,
+    pub overloaded_fraction: f32,
+    pub calm_stable_fraction: f32,
+    /// Number of times RECOVERY switched on (rising edges in epoch order).
+    pub recovery_events: usize,
+    /// Mean of every TREE asset over the session; `labels` is left empty.
+    pub mean_assets: NeuroPrintView,
+}
+
+/// Whole-session report, one summary per subject, sorted by subject_id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeuroPrintSessionReport {
+    pub subjects: Vec<SubjectSessionSummary>,
+}
+
+/// Pure rollup over recorded entries; multi-subject logs are grouped by subject.
+/// Entries are ordered by epoch_index within each subject before counting
+/// recovery transitions.
+pub fn summarize_session(entries: &[NeuroPrintLogEntry]) -> NeuroPrintSessionReport {
+    let mut by_subject: BTreeMap<&str, Vec<&NeuroPrintLogEntry>> = BTreeMap::new();
+    for e in entries {
+        by_subject.entry(e.subject_id.as_str()).or_default().push(e);
+    }
+
+    let subjects = by_subject
+        .into_iter()
+        .map(|(subject_id, mut series)| {
+            series.sort_by_key(|e| e.epoch_index);
+            summarize_subject(subject_id, &series)
+        })
+        .collect();
+
+    NeuroPrintSessionReport { subjects }
+}
+
+fn summarize_subject(subject_id: &str, series: &[&NeuroPrintLogEntry]) -> SubjectSessionSummary {
+    let n = series.len();
+    let mut peak_roh = 0f32;
+    let mut labelled = 0usize;
+    let mut overloaded = 0usize;
+    let mut calm = 0usize;
+    let mut recovery_events = 0usize;
+    let mut prev_recovery = false;
+    let mut sum = [0f32; 14];
+
+    for e in series {
+        if e.roh.after > peak_roh {
+            peak_roh = e.roh.after;
+        }
+        if let Some(nature) = &e.nature {
+            labelled += 1;
+            overloaded += nature.overloaded as usize;
+            calm += nature.calm_stable as usize;
+            if nature.recovery && !prev_recovery {
+                recovery_events += 1;
+            }
+            prev_recovery = nature.recovery;
+        }
+        let v = &e.neuroprint;
+        let rails = [
+            v.blood, v.oxygen, v.wave, v.time, v.decay, v.lifeforce, v.brain, v.smart,
+            v.evolve, v.power, v.tech, v.fear, v.pain, v.nano,
+        ];
+        for (acc, x) in sum.iter_mut().zip(rails) {
+            *acc += x;
+        }
+    }
+
+    let frac = |count: usize| if labelled == 0 { 0.0 } else { count as f32 / labelled as f32 };
+    let m = sum.map(|x| if n == 0 { 0.0 } else { x / n as f32 });
+
+    SubjectSessionSummary {
+        subject_id: subject_id.to_string(),
+        epoch_count: n,
+        first_epoch: series.first().map(|e| e.epoch_index).unwrap_or(0),
+        last_epoch: series.last().map(|e| e.epoch_index).unwrap_or(0),
+        peak_roh,
+        labelled_epochs: labelled,
+        overloaded_fraction: frac(overloaded),
+        calm_stable_fraction: frac(calm),
+        recovery_events,
+        mean_assets: NeuroPrintView {
+            blood: m[0],
+            oxygen: m[1],
+            wave: m[2],
+            time: m[3],
+            decay: m[4],
+            lifeforce: m[5],
+            brain: m[6],
+            smart: m[7],
+            evolve: m[8],
+            power: m[9],
+            tech: m[10],
+            fear: m[11],
+            pain: m[12],
+            nano: m[13],
+            labels: Vec::new(),
+            roh_axis_contributions: Vec::new(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(decay: f32) -> NeuroPrintView {
+        NeuroPrintView {
+            blood: 0.5,
+            oxygen: 0.5,
+            wave: 0.5,
+            time: 0.0,
+            decay,
+            lifeforce: 1.0 - decay,
+            brain: 0.5,
+            smart: 0.5,
+            evolve: 0.5,
+            power: 0.5,
+            tech: 0.5,
+            fear: 0.2,
+            pain: 0.2,
+            nano: 0.5,
+            labels: Vec::new(),
+            roh_axis_contributions: Vec::new(),
+        }
+    }
+
+    fn entry(subject: &str, epoch: u64, roh_after: f32, overloaded: bool, recovery: bool) -> NeuroPrintLogEntry {
+        NeuroPrintLogEntry {
+            timestamp_ms: epoch * 1_000,
+            subject_id: subject.to_string(),
+            epoch_index: epoch,
+            capability_state: CapabilityState::CapControlledHuman,
+            roh: RoHProjection {
+                before: roh_after,
+                after: roh_after,
+                ceiling: 0.30,
+            },
+            neuroprint: view(roh_after / 0.30),
+            nature: Some(NatureLabels {
+                calm_stable: !overloaded && !recovery,
+                overloaded,
+                recovery,
+                unfair_drain: false,
+                roh_volatile: false,
+                conflicts: Vec::new(),
+            }),
+            provenance_mode: ProvenanceMode::Live,
+        }
+    }
+
+    #[test]
+    fn test_validate_log_ordering_in_order() {
+        let entries = vec![
+            entry("s1", 1, 0.10, false, false),
+            entry("s2", 1, 0.10, false, false),
+            entry("s1", 2, 0.10, false, false),
+            entry("s2", 5, 0.10, false, false),
+        ];
+        assert_eq!(validate_log_ordering(&entries), Ok(()));
+        let mut repaired = entries.clone();
+        assert!(sort_and_dedup(&mut repaired).is_noop());
+        assert_eq!(repaired.len(), 4);
+    }
+
+    #[test]
+    fn test_validate_log_ordering_out_of_order() {
+        let mut entries = vec![
+            entry("s1", 1, 0.10, false, false),
+            entry("s1", 3, 0.10, false, false),
+            entry("s2", 1, 0.10, false, false),
+            entry("s1", 2, 0.10, false, false),
+        ];
+        assert_eq!(
+            validate_log_ordering(&entries),
+            Err(OrderingError::OutOfOrder {
+                index: 3,
+                subject_id: "s1".to_string(),
+                previous_epoch: 3,
+                epoch_index: 2,
+            })
+        );
+
+        let repair = sort_and_dedup(&mut entries);
+        assert!(repair.dropped.is_empty());
+        assert_eq!(repair.moved, 3);
+        assert_eq!(validate_log_ordering(&entries), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_log_ordering_duplicate_epoch() {
+        let mut first = entry("s1", 2, 0.10, false, false);
+        first.timestamp_ms = 1;
+        let mut entries = vec![
+            entry("s1", 1, 0.10, false, false),
+            first,
+            entry("s1", 2, 0.20, false, false),
+        ];
+        assert_eq!(
+            validate_log_ordering(&entries),
+            Err(OrderingError::DuplicateEpoch {
+                index: 2,
+                subject_id: "s1".to_string(),
+                epoch_index: 2,
+            })
+        );
+
+        let repair = sort_and_dedup(&mut entries);
+        assert_eq!(repair.dropped, vec![("s1".to_string(), 2)]);
+        assert_eq!(entries.len(), 2);
+        // The first recorded entry for the epoch is the one kept.
+        assert_eq!(entries[1].timestamp_ms, 1);
+        assert_eq!(validate_log_ordering(&entries), Ok(()));
+    }
+
+    #[test]
+    fn test_mixed_log_filters_by_provenance_mode() {
+        let mut sim = entry("s1", 2, 0.20, false, false);
+        sim.provenance_mode = ProvenanceMode::Simulation;
+        let mut bench = entry("s1", 3, 0.20, false, false);
+        bench.provenance_mode = ProvenanceMode::LabBench;
+        let entries = vec![
+            entry("s1", 1, 0.10, false, false),
+            sim,
+            bench,
+            entry("s2", 1, 0.05, false, false),
+        ];
+
+        let live: Vec<u64> = live_only(&entries).iter().map(|e| e.epoch_index).collect();
+        assert_eq!(live, vec![1, 1]);
+        assert!(live_only(&entries).iter().all(|e| e.provenance_mode == ProvenanceMode::Live));
+
+        let simulated = simulation_only(&entries);
+        assert_eq!(simulated.len(), 1);
+        assert_eq!(simulated[0].epoch_index, 2);
+        assert_eq!(entries_with_mode(&entries, ProvenanceMode::LabBench).len(), 1);
+
+        // Rows written before the field existed deserialize as Live.
+        let mut legacy = serde_json::to_value(&entries[0]).unwrap();
+        legacy.as_object_mut().unwrap().remove("provenance_mode");
+        let legacy: NeuroPrintLogEntry = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.provenance_mode, ProvenanceMode::Live);
+    }
+
+    #[test]
+    fn test_summarize_session_peak_and_overloaded_fraction() {
+        let entries = vec![
+            entry("s1", 3, 0.27, true, false),
+            entry("s1", 1, 0.10, false, false),
+            entry("s2", 1, 0.05, false, false),
+            entry("s1", 2, 0.24, true, false),
+            entry("s1", 4, 0.15, false, true),
+        ];
+        let report = summarize_session(&entries);
+
+        assert_eq!(report.subjects.len(), 2);
+        let s1 = &report.subjects[0];
+        assert_eq!(s1.subject_id, "s1");
+        assert_eq!(s1.epoch_count, 4);
+        assert_eq!((s1.first_epoch, s1.last_epoch), (1, 4));
+        assert!((s1.peak_roh - 0.27).abs() < 1e-6);
+        assert!((s1.overloaded_fraction - 0.5).abs() < 1e-6);
+        assert_eq!(s1.recovery_events, 1);
+
+        let s2 = &report.subjects[1];
+        assert_eq!(s2.epoch_count, 1);
+        assert_eq!(s2.overloaded_fraction, 0.0);
+        assert!((s2.calm_stable_fraction - 1.0).abs() < 1e-6);
+    }
 }
@@ -1,3 +1,8 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 use crate::NeuroPrintView;
 
@@ -8,6 +13,16 @@ pub struct NatureConfig {
     pub overloaded: OverloadedConfig,
     pub recovery: RecoveryConfig,
     pub unfair_drain: UnfairDrainConfig,
+    /// Enforce that CALM_STABLE and OVERLOADED never both hold; OVERLOADED wins.
+    #[serde(default = "default_exclusive_calm_overloaded")]
+    pub exclusive_calm_overloaded: bool,
+    /// ROH_VOLATILE label; disabled when absent.
+    #[serde(default)]
+    pub roh_volatile: Option<RohVolatileConfig>,
+}
+
+fn default_exclusive_calm_overloaded() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +32,10 @@ pub struct CalmStableConfig {
     pub fear_max: f32,
     pub pain_max: f32,
     pub decay_max: f32,
+    /// Turn-on thresholds are tightened, and turn-off thresholds relaxed, by
+    /// this much when evaluated with a `NatureLabelState`.
+    #[serde(default)]
+    pub hysteresis_margin: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,9 +46,126 @@ pub struct OverloadedConfig {
     pub lifeforce_max: f32,
     pub fear_min: f32,
     pub pain_min: f32,
+    /// See `CalmStableConfig::hysteresis_margin`.
+    #[serde(default)]
+    pub hysteresis_margin: f32,
+}
+
+/// ROH_VOLATILE: epoch-to-epoch RoH swings, even within the ceiling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RohVolatileConfig {
+    pub window_epochs: u64,
+    /// Standard deviation of normalized RoH (DECAY) above which the label fires.
+    pub stddev_max: f32,
 }
 
-// Similar structs for RecoveryConfig and UnfairDrainConfig ...
+/// RECOVERY: an overloaded past window followed, after a gap, by a recent
+/// window with improved TREE assets. Mirrors `nature_recovery::is_recovery`.
+/// Per-epoch overload uses the OVERLOADED thresholds of the same config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveryConfig {
+    /// Length of the past (overloaded) window.
+    pub window_len_epochs: u64,
+    /// Epochs skipped between the past and recent windows.
+    pub recovery_gap_epochs: u64,
+    /// Length of the recent (recovered) window, ending at the newest epoch.
+    pub recovery_window_epochs: u64,
+    /// Fraction of past-window epochs that must be OVERLOADED.
+    pub min_overloaded_fraction: f32,
+    /// Minimum past-minus-recent drop in DECAY, FEAR and PAIN, and
+    /// recent-minus-past gain in LIFEFORCE.
+    pub delta_decay_min: f32,
+    pub delta_lifeforce_min: f32,
+    pub delta_fear_min: f32,
+    pub delta_pain_min: f32,
+    /// See `CalmStableConfig::hysteresis_margin`; applies to the overloaded
+    /// fraction and the deltas.
+    #[serde(default)]
+    pub hysteresis_margin: f32,
+}
+
+/// UNFAIR_DRAIN for a single subject: the windowed budget
+/// `0.5 * (LIFEFORCE + OXYGEN)` sits at least `delta_unfair` below
+/// `reference_budget` while enough epochs are OVERLOADED. The cohort-relative
+/// form (peer median instead of a reference) lives in the fairness crate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnfairDrainConfig {
+    pub window_epochs: u64,
+    /// Expected budget for comparable subjects, e.g. a calibrated peer median.
+    pub reference_budget: f32,
+    /// Max allowed deficit below `reference_budget`.
+    pub delta_unfair: f32,
+    /// Minimum OVERLOADED fraction over the window.
+    pub overload_frac_min: f32,
+}
+
+/// NATURE token attached to a view. Serializes to the uppercase strings
+/// already present in logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NatureLabel {
+    #[serde(rename = "CALM_STABLE")]
+    CalmStable,
+    #[serde(rename = "OVERLOADED")]
+    Overloaded,
+    #[serde(rename = "RECOVERY")]
+    Recovery,
+    #[serde(rename = "UNFAIR_DRAIN")]
+    UnfairDrain,
+    #[serde(rename = "LOCAL_1D_OVERLOAD")]
+    Local1dOverload,
+    #[serde(rename = "ROH_VOLATILE")]
+    RohVolatile,
+    /// Any label this build does not know, e.g. from a newer or retired
+    /// predicate in an older log. Never produced by the evaluators.
+    #[serde(rename = "UNKNOWN", other)]
+    Unknown,
+}
+
+impl NatureLabel {
+    /// Log token, identical to the serde form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NatureLabel::CalmStable => "CALM_STABLE",
+            NatureLabel::Overloaded => "OVERLOADED",
+            NatureLabel::Recovery => "RECOVERY",
+            NatureLabel::UnfairDrain => "UNFAIR_DRAIN",
+            NatureLabel::Local1dOverload => "LOCAL_1D_OVERLOAD",
+            NatureLabel::RohVolatile => "ROH_VOLATILE",
+            NatureLabel::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl fmt::Display for NatureLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NatureLabel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CALM_STABLE" => Ok(NatureLabel::CalmStable),
+            "OVERLOADED" => Ok(NatureLabel::Overloaded),
+            "RECOVERY" => Ok(NatureLabel::Recovery),
+            "UNFAIR_DRAIN" => Ok(NatureLabel::UnfairDrain),
+            "LOCAL_1D_OVERLOAD" => Ok(NatureLabel::Local1dOverload),
+            "ROH_VOLATILE" => Ok(NatureLabel::RohVolatile),
+            "UNKNOWN" => Ok(NatureLabel::Unknown),
+            other => Err(format!("unknown NATURE label `{}`", other)),
+        }
+    }
+}
+
+/// Incoherent predicate combinations detected while evaluating a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NatureConflict {
+    /// CALM_STABLE and OVERLOADED both fired on the same window.
+    CalmStableAndOverloaded,
+}
 
 /// Evaluated NATURE tokens for a given epoch.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,16 +174,496 @@ pub struct NatureLabels {
     pub overloaded: bool,
     pub recovery: bool,
     pub unfair_drain: bool,
+    #[serde(default)]
+    pub roh_volatile: bool,
+    /// Conflicts found (and, if configured, resolved) during evaluation.
+    #[serde(default)]
+    pub conflicts: Vec<NatureConflict>,
+}
+
+/// Active labels as `[CALM_STABLE RECOVERY]`, in declaration order; `[]` when
+/// none is set. Conflicts are not rendered.
+impl fmt::Display for NatureLabels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let active = [
+            (self.calm_stable, NatureLabel::CalmStable),
+            (self.overloaded, NatureLabel::Overloaded),
+            (self.recovery, NatureLabel::Recovery),
+            (self.unfair_drain, NatureLabel::UnfairDrain),
+            (self.roh_volatile, NatureLabel::RohVolatile),
+        ];
+        f.write_str("[")?;
+        let mut first = true;
+        for (_, label) in active.iter().filter(|(on, _)| *on) {
+            if !first {
+                f.write_str(" ")?;
+            }
+            first = false;
+            f.write_str(label.as_str())?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Previously reported CALM_STABLE / OVERLOADED / RECOVERY labels, threaded
+/// between epochs so hysteresis can tell turn-on from turn-off.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NatureLabelState {
+    pub calm_stable: bool,
+    pub overloaded: bool,
+    pub recovery: bool,
+}
+
+/// Threshold shift for a hysteretic label: tighten by `margin` to turn on,
+/// relax by `margin` to stay on.
+fn hysteresis_shift(was_on: bool, margin: f32) -> f32 {
+    if was_on {
+        -margin
+    } else {
+        margin
+    }
 }
 
 pub fn eval_nature_labels(
     history: &[NeuroPrintView],
     cfg: &NatureConfig,
 ) -> NatureLabels {
-    NatureLabels {
-        calm_stable: eval_calm_stable(history, &cfg.calm_stable),
-        overloaded: eval_overloaded(history, &cfg.overloaded),
-        recovery: eval_recovery(history, &cfg.recovery),
-        unfair_drain: eval_unfair_drain(history, &cfg.unfair_drain),
+    labels_with_shifts(history, cfg, &NatureLabelState::default(), 0.0)
+}
+
+/// Hysteretic variant of `eval_nature_labels`: CALM_STABLE, OVERLOADED and
+/// RECOVERY must cross `threshold + hysteresis_margin` to turn on and fall
+/// past `threshold - hysteresis_margin` to turn off, relative to `state`.
+/// `state` is updated to the reported labels; nothing else is mutated.
+pub fn eval_nature_labels_with_state(
+    history: &[NeuroPrintView],
+    cfg: &NatureConfig,
+    state: &mut NatureLabelState,
+) -> NatureLabels {
+    let labels = labels_with_shifts(history, cfg, state, 1.0);
+    *state = NatureLabelState {
+        calm_stable: labels.calm_stable,
+        overloaded: labels.overloaded,
+        recovery: labels.recovery,
+    };
+    labels
+}
+
+/// `margin_scale` of 0.0 disables hysteresis (plain thresholds).
+fn labels_with_shifts(
+    history: &[NeuroPrintView],
+    cfg: &NatureConfig,
+    state: &NatureLabelState,
+    margin_scale: f32,
+) -> NatureLabels {
+    let shift = |was_on: bool, margin: f32| hysteresis_shift(was_on, margin * margin_scale);
+    let mut labels = NatureLabels {
+        calm_stable: eval_calm_stable(
+            history,
+            &cfg.calm_stable,
+            shift(state.calm_stable, cfg.calm_stable.hysteresis_margin),
+        ),
+        overloaded: eval_overloaded(
+            history,
+            &cfg.overloaded,
+            shift(state.overloaded, cfg.overloaded.hysteresis_margin),
+        ),
+        recovery: eval_recovery(
+            history,
+            &cfg.recovery,
+            &cfg.overloaded,
+            shift(state.recovery, cfg.recovery.hysteresis_margin),
+        ),
+        unfair_drain: eval_unfair_drain(history, &cfg.unfair_drain, &cfg.overloaded),
+        roh_volatile: cfg
+            .roh_volatile
+            .as_ref()
+            .is_some_and(|c| eval_roh_volatile(history, c)),
+        conflicts: Vec::new(),
+    };
+    reconcile_calm_overloaded(&mut labels, cfg.exclusive_calm_overloaded);
+    labels
+}
+
+/// Post-pass for CALM_STABLE / OVERLOADED coherence.
+///
+/// When both fire the conflict is always recorded. If `enforce` is set,
+/// OVERLOADED takes priority and CALM_STABLE is cleared: erring towards the
+/// more cautious label is the safer reading of a borderline window.
+pub fn reconcile_calm_overloaded(labels: &mut NatureLabels, enforce: bool) {
+    if labels.calm_stable && labels.overloaded {
+        labels.conflicts.push(NatureConflict::CalmStableAndOverloaded);
+        if enforce {
+            labels.calm_stable = false;
+        }
+    }
+}
+
+/// Most recent `window_epochs` views, or `None` if the history is too short.
+fn window(history: &[NeuroPrintView], window_epochs: u64) -> Option<&[NeuroPrintView]> {
+    let w = window_epochs.max(1) as usize;
+    if history.len() < w {
+        return None;
+    }
+    Some(&history[history.len() - w..])
+}
+
+fn avg<F>(views: &[NeuroPrintView], f: F) -> f32
+where
+    F: Fn(&NeuroPrintView) -> f32,
+{
+    if views.is_empty() {
+        return 0.0;
+    }
+    views.iter().map(f).sum::<f32>() / views.len() as f32
+}
+
+/// CALM_STABLE: window averages show high LIFEFORCE and low FEAR/PAIN/DECAY.
+/// A positive `shift` raises every minimum and lowers every maximum.
+fn eval_calm_stable(history: &[NeuroPrintView], cfg: &CalmStableConfig, shift: f32) -> bool {
+    let Some(w) = window(history, cfg.window_epochs) else {
+        return false;
+    };
+    avg(w, |v| v.lifeforce) >= cfg.lifeforce_min + shift
+        && avg(w, |v| v.fear) <= cfg.fear_max - shift
+        && avg(w, |v| v.pain) <= cfg.pain_max - shift
+        && avg(w, |v| v.decay) <= cfg.decay_max - shift
+}
+
+/// OVERLOADED: sustained load (high DECAY and POWER with low LIFEFORCE) or
+/// sustained distress (high FEAR and PAIN) over the window.
+fn eval_overloaded(history: &[NeuroPrintView], cfg: &OverloadedConfig, shift: f32) -> bool {
+    let Some(w) = window(history, cfg.window_epochs) else {
+        return false;
+    };
+    overloaded_over(w, cfg, shift)
+}
+
+/// OVERLOADED thresholds applied to the averages of `views`, shifted as in
+/// `eval_calm_stable`.
+fn overloaded_over(views: &[NeuroPrintView], cfg: &OverloadedConfig, shift: f32) -> bool {
+    let load = avg(views, |v| v.decay) >= cfg.decay_min + shift
+        && avg(views, |v| v.power) >= cfg.power_min + shift
+        && avg(views, |v| v.lifeforce) <= cfg.lifeforce_max - shift;
+    let distress = avg(views, |v| v.fear) >= cfg.fear_min + shift
+        && avg(views, |v| v.pain) >= cfg.pain_min + shift;
+    load || distress
+}
+
+/// Fraction of `views` that are individually OVERLOADED.
+fn overloaded_fraction(views: &[NeuroPrintView], cfg: &OverloadedConfig) -> f32 {
+    if views.is_empty() {
+        return 0.0;
+    }
+    let overloaded = views
+        .iter()
+        .filter(|v| overloaded_over(core::slice::from_ref(*v), cfg, 0.0))
+        .count();
+    overloaded as f32 / views.len() as f32
+}
+
+/// RECOVERY: `[past window][gap][recent window]` ending at the newest epoch;
+/// the past window was mostly OVERLOADED and every TREE delta improved.
+fn eval_recovery(
+    history: &[NeuroPrintView],
+    cfg: &RecoveryConfig,
+    overloaded: &OverloadedConfig,
+    shift: f32,
+) -> bool {
+    let w = cfg.window_len_epochs.max(1) as usize;
+    let g = cfg.recovery_gap_epochs as usize;
+    let wr = cfg.recovery_window_epochs.max(1) as usize;
+    let len = history.len();
+    if len < w + g + wr {
+        return false; // not enough history to evaluate
+    }
+
+    let recent_start = len - wr;
+    let past_start = recent_start - g - w;
+    let past = &history[past_start..past_start + w];
+    let recent = &history[recent_start..];
+
+    if overloaded_fraction(past, overloaded) < cfg.min_overloaded_fraction + shift {
+        return false;
+    }
+
+    avg(past, |v| v.decay) - avg(recent, |v| v.decay) >= cfg.delta_decay_min + shift
+        && avg(recent, |v| v.lifeforce) - avg(past, |v| v.lifeforce)
+            >= cfg.delta_lifeforce_min + shift
+        && avg(past, |v| v.fear) - avg(recent, |v| v.fear) >= cfg.delta_fear_min + shift
+        && avg(past, |v| v.pain) - avg(recent, |v| v.pain) >= cfg.delta_pain_min + shift
+}
+
+/// UNFAIR_DRAIN: windowed budget below the reference by `delta_unfair` while
+/// the window is sufficiently OVERLOADED.
+fn eval_unfair_drain(
+    history: &[NeuroPrintView],
+    cfg: &UnfairDrainConfig,
+    overloaded: &OverloadedConfig,
+) -> bool {
+    let Some(w) = window(history, cfg.window_epochs) else {
+        return false;
+    };
+    let budget = avg(w, |v| 0.5 * (v.lifeforce + v.oxygen));
+    cfg.reference_budget - budget >= cfg.delta_unfair
+        && overloaded_fraction(w, overloaded) >= cfg.overload_frac_min
+}
+
+/// Population standard deviation of the last `window` RoH samples.
+///
+/// Pure diagnostic: 0.0 when fewer than two samples are available; NaN
+/// samples are ignored.
+pub fn roh_volatility(history: &[f32], window: usize) -> f32 {
+    let start = history.len().saturating_sub(window);
+    let samples: Vec<f32> = history[start..].iter().copied().filter(|r| !r.is_nan()).collect();
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    let n = samples.len() as f32;
+    let mean = samples.iter().sum::<f32>() / n;
+    // libm rather than f32::sqrt so the predicate also builds without std.
+    libm::sqrtf(samples.iter().map(|r| (r - mean) * (r - mean)).sum::<f32>() / n)
+}
+
+/// ROH_VOLATILE: volatility of DECAY (RoH / ceiling) over the window exceeds the limit.
+fn eval_roh_volatile(history: &[NeuroPrintView], cfg: &RohVolatileConfig) -> bool {
+    let Some(w) = window(history, cfg.window_epochs) else {
+        return false;
+    };
+    let decay: Vec<f32> = w.iter().map(|v| v.decay).collect();
+    roh_volatility(&decay, decay.len()) > cfg.stddev_max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(decay: f32, power: f32, fear: f32, pain: f32) -> NeuroPrintView {
+        NeuroPrintView {
+            blood: 0.5,
+            oxygen: 0.5,
+            wave: 0.5,
+            time: 0.0,
+            decay,
+            lifeforce: 1.0 - decay,
+            brain: 0.5,
+            smart: 0.5,
+            evolve: 0.5,
+            power,
+            tech: 0.5,
+            fear,
+            pain,
+            nano: 0.5,
+            labels: Vec::new(),
+            roh_axis_contributions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_calm_and_overloaded_reconciled_to_overloaded() {
+        // Deliberately overlapping bands so a borderline window trips both predicates.
+        let calm_stable = CalmStableConfig {
+            window_epochs: 2,
+            lifeforce_min: 0.40,
+            fear_max: 0.20,
+            pain_max: 0.20,
+            decay_max: 0.60,
+            hysteresis_margin: 0.0,
+        };
+        let overloaded = OverloadedConfig {
+            window_epochs: 2,
+            decay_min: 0.50,
+            power_min: 0.30,
+            lifeforce_max: 0.50,
+            fear_min: 0.90,
+            pain_min: 0.90,
+            hysteresis_margin: 0.0,
+        };
+        let history = vec![view(0.55, 0.5, 0.1, 0.1), view(0.55, 0.5, 0.1, 0.1)];
+
+        assert!(eval_calm_stable(&history, &calm_stable, 0.0));
+        assert!(eval_overloaded(&history, &overloaded, 0.0));
+
+        let mut labels = NatureLabels {
+            calm_stable: true,
+            overloaded: true,
+            recovery: false,
+            unfair_drain: false,
+            roh_volatile: false,
+            conflicts: Vec::new(),
+        };
+        reconcile_calm_overloaded(&mut labels, true);
+        assert!(labels.overloaded);
+        assert!(!labels.calm_stable);
+        assert_eq!(labels.conflicts, vec![NatureConflict::CalmStableAndOverloaded]);
+        assert_eq!(labels.to_string(), "[OVERLOADED]");
+
+        labels.roh_volatile = true;
+        assert_eq!(labels.to_string(), "[OVERLOADED ROH_VOLATILE]");
+        labels.overloaded = false;
+        labels.roh_volatile = false;
+        assert_eq!(labels.to_string(), "[]");
+    }
+
+    fn overloaded_cfg() -> OverloadedConfig {
+        OverloadedConfig {
+            window_epochs: 3,
+            decay_min: 0.60,
+            power_min: 0.50,
+            lifeforce_max: 0.40,
+            fear_min: 0.90,
+            pain_min: 0.90,
+            hysteresis_margin: 0.0,
+        }
+    }
+
+    fn recovery_cfg() -> RecoveryConfig {
+        RecoveryConfig {
+            window_len_epochs: 4,
+            recovery_gap_epochs: 2,
+            recovery_window_epochs: 3,
+            min_overloaded_fraction: 0.75,
+            delta_decay_min: 0.20,
+            delta_lifeforce_min: 0.20,
+            delta_fear_min: 0.10,
+            delta_pain_min: 0.10,
+            hysteresis_margin: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_recovery_after_overloaded_window() {
+        let mut history = vec![view(0.8, 0.7, 0.5, 0.5); 4];
+        history.extend(vec![view(0.5, 0.5, 0.3, 0.3); 2]);
+        history.extend(vec![view(0.2, 0.3, 0.1, 0.1); 3]);
+
+        assert!(eval_recovery(&history, &recovery_cfg(), &overloaded_cfg(), 0.0));
+        // One epoch short of past + gap + recent.
+        assert!(!eval_recovery(&history[1..], &recovery_cfg(), &overloaded_cfg(), 0.0));
+    }
+
+    #[test]
+    fn test_sustained_overload_is_not_recovery() {
+        let history = vec![view(0.8, 0.7, 0.5, 0.5); 9];
+        assert!(eval_overloaded(&history, &overloaded_cfg(), 0.0));
+        assert!(!eval_recovery(&history, &recovery_cfg(), &overloaded_cfg(), 0.0));
+
+        let cfg = UnfairDrainConfig {
+            window_epochs: 3,
+            reference_budget: 0.6,
+            delta_unfair: 0.15,
+            overload_frac_min: 0.5,
+        };
+        // Budget 0.5 * (0.2 + 0.5) = 0.35, 0.25 below the reference.
+        assert!(eval_unfair_drain(&history, &cfg, &overloaded_cfg()));
+        let calm = vec![view(0.2, 0.3, 0.1, 0.1); 3];
+        assert!(!eval_unfair_drain(&calm, &cfg, &overloaded_cfg()));
+    }
+
+    #[test]
+    fn test_hysteresis_keeps_calm_stable_through_sawtooth() {
+        let cfg = NatureConfig {
+            calm_stable: CalmStableConfig {
+                window_epochs: 1,
+                lifeforce_min: 0.50,
+                fear_max: 0.20,
+                pain_max: 0.20,
+                decay_max: 0.30,
+                hysteresis_margin: 0.05,
+            },
+            overloaded: overloaded_cfg(),
+            recovery: recovery_cfg(),
+            unfair_drain: UnfairDrainConfig {
+                window_epochs: 1,
+                reference_budget: 0.5,
+                delta_unfair: 0.2,
+                overload_frac_min: 0.5,
+            },
+            exclusive_calm_overloaded: true,
+            roh_volatile: None,
+        };
+
+        // Settle clearly inside the band, then saw across decay_max = 0.30.
+        let mut series = vec![view(0.20, 0.3, 0.1, 0.1)];
+        series.extend((0..20).map(|i| view(if i % 2 == 0 { 0.32 } else { 0.28 }, 0.3, 0.1, 0.1)));
+
+        let mut state = NatureLabelState::default();
+        let mut stateless = Vec::new();
+        let mut hysteretic = Vec::new();
+        for end in 1..=series.len() {
+            let history = &series[..end];
+            stateless.push(eval_nature_labels(history, &cfg).calm_stable);
+            hysteretic.push(eval_nature_labels_with_state(history, &cfg, &mut state).calm_stable);
+        }
+
+        assert!(stateless[1..].windows(2).all(|w| w[0] != w[1]));
+        assert!(hysteretic.iter().all(|on| *on));
+        assert!(state.calm_stable);
+
+        // Falling past decay_max + margin turns the label off.
+        series.push(view(0.36, 0.3, 0.1, 0.1));
+        assert!(!eval_nature_labels_with_state(&series, &cfg, &mut state).calm_stable);
+        assert!(!state.calm_stable);
+    }
+
+    #[test]
+    fn test_view_labels_round_trip_as_uppercase_strings() {
+        let mut v = view(0.2, 0.3, 0.1, 0.1);
+        v.labels = vec![NatureLabel::CalmStable, NatureLabel::Local1dOverload];
+
+        let json = serde_json::to_value(&v).unwrap();
+        assert_eq!(json["labels"], serde_json::json!(["CALM_STABLE", "LOCAL_1D_OVERLOAD"]));
+        let back: NeuroPrintView = serde_json::from_value(json).unwrap();
+        assert_eq!(back.labels, v.labels);
+
+        for label in [NatureLabel::Recovery, NatureLabel::UnfairDrain, NatureLabel::RohVolatile] {
+            assert_eq!(label.to_string().parse::<NatureLabel>(), Ok(label));
+            assert_eq!(
+                serde_json::to_string(&label).unwrap(),
+                format!("\"{}\"", label)
+            );
+        }
+        assert!("CALM-STABLE".parse::<NatureLabel>().is_err());
+
+        // Logs carrying labels this build does not know still load.
+        let legacy: Vec<NatureLabel> =
+            serde_json::from_str(r#"["CALM_STABLE", "DEEP_SLEEP"]"#).unwrap();
+        assert_eq!(legacy, [NatureLabel::CalmStable, NatureLabel::Unknown]);
+    }
+
+    #[test]
+    fn test_roh_volatility_stable_vs_volatile() {
+        let stable = [0.12, 0.13, 0.12, 0.13, 0.12, 0.13];
+        let volatile = [0.02, 0.28, 0.05, 0.27, 0.03, 0.29];
+        assert!(roh_volatility(&stable, 6) < 0.01);
+        assert!(roh_volatility(&volatile, 6) > 0.1);
+        // Only the recent window counts.
+        assert!(roh_volatility(&[0.02, 0.28, 0.12, 0.12, 0.12], 3) < 1e-6);
+        assert_eq!(roh_volatility(&[0.2], 4), 0.0);
+
+        let cfg = RohVolatileConfig {
+            window_epochs: 6,
+            stddev_max: 0.05,
+        };
+        let to_views = |series: &[f32]| -> Vec<NeuroPrintView> {
+            series.iter().map(|r| view(r / 0.30, 0.5, 0.1, 0.1)).collect()
+        };
+        assert!(!eval_roh_volatile(&to_views(&stable), &cfg));
+        assert!(eval_roh_volatile(&to_views(&volatile), &cfg));
+    }
+
+    #[test]
+    fn test_reconcile_records_conflict_without_enforcing() {
+        let mut labels = NatureLabels {
+            calm_stable: true,
+            overloaded: true,
+            recovery: false,
+            unfair_drain: false,
+            roh_volatile: false,
+            conflicts: Vec::new(),
+        };
+        reconcile_calm_overloaded(&mut labels, false);
+        assert!(labels.calm_stable && labels.overloaded);
+        assert_eq!(labels.conflicts.len(), 1);
     }
 }
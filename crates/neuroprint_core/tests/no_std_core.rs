@@ -0,0 +1,40 @@
+//! Exercises the `no_std + alloc` surface of the crate.
+//!
+//! - Run as `cargo test -p neuroprint_core --no-default-features` to build the
+//!   library without `std`; the default run covers the same calls with `std`.
+//! - Only `core`/`alloc` items are used here, so anything that slips behind
+//!   the `std` feature fails to compile in the no-default-features run.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+use capability_core::CapabilityState;
+use envelope_core::BiophysicalEnvelopeSnapshot;
+use neuroprint_core::nature::{roh_volatility, NatureLabel};
+use neuroprint_core::{neuroprint_from_snapshot, NeuroPrintInput};
+use roh_model::RoHProjection;
+
+#[test]
+fn test_neuroprint_from_snapshot_without_std() {
+    let input = NeuroPrintInput {
+        capability_state: CapabilityState::CapControlledHuman,
+        roh: RoHProjection {
+            before: 0.15,
+            after: 0.15,
+            ceiling: 0.3,
+        },
+        envelope: BiophysicalEnvelopeSnapshot::default(),
+        evolve_index: Some(3),
+        epoch_index: Some(1),
+        roh_axis_contributions: vec![("thermal".into(), 0.09), ("arousal".into(), 0.06)],
+    };
+    let view = neuroprint_from_snapshot(&input);
+    assert!((view.decay - 0.5).abs() < 1e-6);
+    assert!((view.lifeforce - 0.5).abs() < 1e-6);
+    assert_eq!(view.dominant_axis(), Some("thermal"));
+
+    assert!((roh_volatility(&[0.2, 0.4], 2) - 0.1).abs() < 1e-6);
+    assert_eq!("ROH_VOLATILE".parse::<NatureLabel>(), Ok(NatureLabel::RohVolatile));
+}
@@ -0,0 +1,545 @@
+//! Standalone analyzer for the NewRow-Print! taint specification.
+//!
+//! Consumes the `cargo check --message-format json` stream for a crate,
+//! takes every `compiler-artifact` whose manifest lives under the crate root,
+//! re-reads the `nr_taint_*` marker attributes from that target's sources,
+//! and checks them against the tables in `policyengine::taint_spec`, read
+//! from that file itself.
+//!
+//! Like the macros themselves, the rules are syntactic:
+//! - `nr_taint_critical(path = "...")` must name a policy-critical type.
+//! - Only `#[nr_taint_trusted_writer]` functions may take `&mut` to a critical type.
+//! - Diagnostic source types may only reach `#[nr_taint_diag_join]`; any other
+//!   function that takes one while being a sink, a trusted writer, or returning
+//!   a critical type is reported.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde_json::Value;
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{Attribute, Expr, ExprLit, Lit, Meta, ReturnType, Signature, Type};
+
+/// `policyengine::taint_spec`, the single source of truth for which types
+/// are critical and which are diagnostic sources. Embedded at build time and
+/// parsed once, so the analyzer cannot drift from the spec it enforces.
+const TAINT_SPEC: &str = include_str!("../../policyengine/src/taint_spec.rs");
+
+/// Policy tables read from `TAINT_SPEC`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaintSpec {
+    /// `(type ident, canonical path)` for every `"<path>" => true` arm of
+    /// `TaintPolicy::is_critical_type`.
+    pub critical_types: Vec<(String, String)>,
+    /// Variants of `DiagnosticSource`.
+    pub diagnostic_sources: Vec<String>,
+}
+
+impl TaintSpec {
+    /// The embedded taint spec. Panics only if the spec in this repository no
+    /// longer has the shape this parser reads, which the tests catch.
+    pub fn embedded() -> &'static TaintSpec {
+        static SPEC: OnceLock<TaintSpec> = OnceLock::new();
+        SPEC.get_or_init(|| {
+            TaintSpec::parse(TAINT_SPEC).expect("policyengine taint_spec.rs is parseable")
+        })
+    }
+
+    /// Read the policy tables from the source of a taint spec module.
+    pub fn parse(source: &str) -> Result<TaintSpec, syn::Error> {
+        let file = syn::parse_file(source)?;
+        let mut spec = TaintSpec {
+            critical_types: Vec::new(),
+            diagnostic_sources: Vec::new(),
+        };
+        for item in &file.items {
+            match item {
+                syn::Item::Enum(e) if e.ident == "DiagnosticSource" => {
+                    spec.diagnostic_sources =
+                        e.variants.iter().map(|v| v.ident.to_string()).collect();
+                }
+                syn::Item::Impl(i) => {
+                    for impl_item in &i.items {
+                        match impl_item {
+                            syn::ImplItem::Fn(f) if f.sig.ident == "is_critical_type" => {
+                                spec.critical_types = true_arm_paths(&f.block)
+                                    .into_iter()
+                                    .map(|path| {
+                                        let ident = path.rsplit("::").next().unwrap_or(&path);
+                                        (ident.to_string(), path.clone())
+                                    })
+                                    .collect();
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if spec.critical_types.is_empty() || spec.diagnostic_sources.is_empty() {
+            return Err(syn::Error::new(
+                file.span(),
+                "taint spec lacks `TaintPolicy::is_critical_type` or `DiagnosticSource`",
+            ));
+        }
+        Ok(spec)
+    }
+}
+
+/// String-literal patterns of every `"<lit>" => true` match arm in `block`.
+fn true_arm_paths(block: &syn::Block) -> Vec<String> {
+    struct Arms(Vec<String>);
+    impl<'ast> Visit<'ast> for Arms {
+        fn visit_arm(&mut self, arm: &'ast syn::Arm) {
+            let is_true = matches!(
+                &*arm.body,
+                Expr::Lit(ExprLit { lit: Lit::Bool(b), .. }) if b.value
+            );
+            if let (syn::Pat::Lit(ExprLit { lit: Lit::Str(s), .. }), true) = (&arm.pat, is_true) {
+                self.0.push(s.value());
+            }
+            syn::visit::visit_arm(self, arm);
+        }
+    }
+    let mut arms = Arms(Vec::new());
+    arms.visit_block(block);
+    arms.0
+}
+
+/// A single policy violation, located by source file and item name.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Violation {
+    /// `nr_taint_critical(path = ...)` names a type the policy does not list.
+    UnknownCriticalPath {
+        file: PathBuf,
+        item: String,
+        path: String,
+    },
+    /// A function outside the trusted writers takes `&mut` to a critical type.
+    UntrustedWriter {
+        file: PathBuf,
+        function: String,
+        critical_type: String,
+    },
+    /// A diagnostic source reaches a non-join function that can affect decisions.
+    TaintedFlow {
+        file: PathBuf,
+        function: String,
+        source: String,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::UnknownCriticalPath { file, item, path } => write!(
+                f,
+                "{}: `{}` is marked critical as `{}`, which TAINT_POLICY does not list",
+                file.display(),
+                item,
+                path
+            ),
+            Violation::UntrustedWriter {
+                file,
+                function,
+                critical_type,
+            } => write!(
+                f,
+                "{}: fn `{}` takes `&mut {}` but is not #[nr_taint_trusted_writer]",
+                file.display(),
+                function,
+                critical_type
+            ),
+            Violation::TaintedFlow {
+                file,
+                function,
+                source,
+            } => write!(
+                f,
+                "{}: diagnostic source `{}` reaches fn `{}`, which is not \
+                 #[nr_taint_diag_join]",
+                file.display(),
+                source,
+                function
+            ),
+        }
+    }
+}
+
+/// Result of one analyzer run.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub artifacts: usize,
+    pub files_scanned: usize,
+    pub critical_markers: usize,
+    pub trusted_writers: usize,
+    pub sinks: usize,
+    pub diag_joins: usize,
+    pub violations: Vec<Violation>,
+}
+
+impl Report {
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "nr_taint_analyzer: {} artifact(s), {} file(s); markers: {} critical, \
+             {} trusted writer(s), {} sink(s), {} diag join(s)",
+            self.artifacts,
+            self.files_scanned,
+            self.critical_markers,
+            self.trusted_writers,
+            self.sinks,
+            self.diag_joins
+        )?;
+        for v in &self.violations {
+            writeln!(f, "violation: {}", v)?;
+        }
+        if self.is_clean() {
+            writeln!(f, "result: clean")
+        } else {
+            writeln!(f, "result: {} violation(s)", self.violations.len())
+        }
+    }
+}
+
+/// Reasons the analyzer could not produce a report.
+#[derive(Debug)]
+pub enum AnalyzeError {
+    /// A stdin line was not valid JSON.
+    InvalidJson {
+        line: usize,
+    },
+    /// The build itself failed; markers from a broken build are not trusted.
+    BuildFailed {
+        messages: Vec<String>,
+    },
+    /// No `compiler-artifact` belonged to the crate root.
+    NoArtifacts {
+        root: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: syn::Error,
+    },
+}
+
+impl fmt::Display for AnalyzeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnalyzeError::InvalidJson { line } => {
+                write!(f, "line {} of the cargo message stream is not JSON", line)
+            }
+            AnalyzeError::BuildFailed { messages } => {
+                write!(f, "cargo check reported errors:")?;
+                for m in messages {
+                    write!(f, "\n  {}", m)?;
+                }
+                Ok(())
+            }
+            AnalyzeError::NoArtifacts { root } => {
+                write!(f, "no compiler-artifact found under {}", root.display())
+            }
+            AnalyzeError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            AnalyzeError::Parse { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for AnalyzeError {}
+
+/// Analyze the crate under `root` given its `cargo check --message-format json` output.
+pub fn analyze(stream: &str, root: &Path) -> Result<Report, AnalyzeError> {
+    // Cargo reports canonical absolute paths; accept `.` and friends too.
+    let root = &root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let src_roots = artifact_src_paths(stream, root)?;
+    if src_roots.is_empty() {
+        return Err(AnalyzeError::NoArtifacts {
+            root: root.to_path_buf(),
+        });
+    }
+
+    let mut files = BTreeSet::new();
+    for src in &src_roots {
+        if let Some(dir) = src.parent() {
+            collect_rs_files(dir, &mut files)?;
+        }
+    }
+
+    let mut report = Report {
+        artifacts: src_roots.len(),
+        ..Report::default()
+    };
+    for file in &files {
+        let source = fs::read_to_string(file).map_err(|error| AnalyzeError::Io {
+            path: file.clone(),
+            error,
+        })?;
+        let parsed = syn::parse_file(&source).map_err(|error| AnalyzeError::Parse {
+            path: file.clone(),
+            error,
+        })?;
+        let rel = file.strip_prefix(root).unwrap_or(file).to_path_buf();
+        let mut visitor = MarkerVisitor {
+            file: rel,
+            report: &mut report,
+        };
+        visitor.visit_file(&parsed);
+    }
+    report.files_scanned = files.len();
+    report.violations.sort();
+    Ok(report)
+}
+
+/// `target.src_path` of every artifact whose manifest is under `root`.
+fn artifact_src_paths(stream: &str, root: &Path) -> Result<BTreeSet<PathBuf>, AnalyzeError> {
+    let mut src_paths = BTreeSet::new();
+    let mut errors = Vec::new();
+
+    for (idx, line) in stream.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let msg: Value =
+            serde_json::from_str(line).map_err(|_| AnalyzeError::InvalidJson { line: idx + 1 })?;
+        match msg["reason"].as_str() {
+            Some("compiler-artifact") => {
+                let manifest = msg["manifest_path"].as_str().map(Path::new);
+                let src = msg["target"]["src_path"].as_str().map(PathBuf::from);
+                if let (Some(manifest), Some(src)) = (manifest, src) {
+                    if manifest.starts_with(root) {
+                        src_paths.insert(src);
+                    }
+                }
+            }
+            Some("compiler-message") if msg["message"]["level"] == "error" => {
+                let rendered = msg["message"]["message"].as_str().unwrap_or("<no message>");
+                errors.push(rendered.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(src_paths)
+    } else {
+        Err(AnalyzeError::BuildFailed { messages: errors })
+    }
+}
+
+fn collect_rs_files(dir: &Path, out: &mut BTreeSet<PathBuf>) -> Result<(), AnalyzeError> {
+    let entries = fs::read_dir(dir).map_err(|error| AnalyzeError::Io {
+        path: dir.to_path_buf(),
+        error,
+    })?;
+    for entry in entries {
+        let path = entry
+            .map_err(|error| AnalyzeError::Io {
+                path: dir.to_path_buf(),
+                error,
+            })?
+            .path();
+        if path.is_dir() {
+            collect_rs_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            out.insert(path);
+        }
+    }
+    Ok(())
+}
+
+/// Marker kinds recognised on an item, by attribute name.
+#[derive(Debug, Default)]
+struct Markers {
+    critical_path: Option<String>,
+    critical: bool,
+    trusted_writer: bool,
+    sink: bool,
+    diag_join: bool,
+}
+
+fn markers(attrs: &[Attribute]) -> Markers {
+    let mut m = Markers::default();
+    for attr in attrs {
+        let Some(name) = attr.path().segments.last().map(|s| s.ident.to_string()) else {
+            continue;
+        };
+        match name.as_str() {
+            "nr_taint_critical" => {
+                m.critical = true;
+                m.critical_path = critical_path_arg(attr);
+            }
+            "nr_taint_trusted_writer" => m.trusted_writer = true,
+            "nr_taint_sink" => m.sink = true,
+            "nr_taint_diag_join" => m.diag_join = true,
+            _ => {}
+        }
+    }
+    m
+}
+
+fn critical_path_arg(attr: &Attribute) -> Option<String> {
+    let Meta::List(list) = &attr.meta else {
+        return None;
+    };
+    let nv: syn::MetaNameValue = list.parse_args().ok()?;
+    match nv.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) if nv.path.is_ident("path") => Some(s.value()),
+        _ => None,
+    }
+}
+
+/// Every path-segment ident mentioned anywhere in `ty`.
+fn type_idents(ty: &Type, out: &mut Vec<String>) {
+    struct Idents<'a>(&'a mut Vec<String>);
+    impl<'ast> Visit<'ast> for Idents<'_> {
+        fn visit_path_segment(&mut self, seg: &'ast syn::PathSegment) {
+            self.0.push(seg.ident.to_string());
+            syn::visit::visit_path_segment(self, seg);
+        }
+    }
+    Idents(out).visit_type(ty);
+}
+
+fn critical_ident(name: &str) -> bool {
+    TaintSpec::embedded()
+        .critical_types
+        .iter()
+        .any(|(ident, _)| ident == name)
+}
+
+fn diagnostic_source(name: &str) -> bool {
+    TaintSpec::embedded()
+        .diagnostic_sources
+        .iter()
+        .any(|source| source == name)
+}
+
+struct MarkerVisitor<'r> {
+    file: PathBuf,
+    report: &'r mut Report,
+}
+
+impl MarkerVisitor<'_> {
+    fn check_critical(&mut self, attrs: &[Attribute], item: &syn::Ident) {
+        let m = markers(attrs);
+        if !m.critical {
+            return;
+        }
+        self.report.critical_markers += 1;
+        if let Some(path) = m.critical_path {
+            let known = TaintSpec::embedded()
+                .critical_types
+                .iter()
+                .any(|(_, p)| *p == path);
+            if !known {
+                self.report.violations.push(Violation::UnknownCriticalPath {
+                    file: self.file.clone(),
+                    item: item.to_string(),
+                    path,
+                });
+            }
+        }
+    }
+
+    fn check_fn(&mut self, attrs: &[Attribute], sig: &Signature) {
+        let m = markers(attrs);
+        self.report.trusted_writers += m.trusted_writer as usize;
+        self.report.sinks += m.sink as usize;
+        self.report.diag_joins += m.diag_join as usize;
+        let function = sig.ident.to_string();
+
+        let mut diag_sources = Vec::new();
+        for input in &sig.inputs {
+            let syn::FnArg::Typed(arg) = input else {
+                continue;
+            };
+            if let Type::Reference(r) = &*arg.ty {
+                if r.mutability.is_some() && !m.trusted_writer {
+                    let mut idents = Vec::new();
+                    type_idents(&r.elem, &mut idents);
+                    if let Some(critical) = idents.last().filter(|i| critical_ident(i)) {
+                        self.report.violations.push(Violation::UntrustedWriter {
+                            file: self.file.clone(),
+                            function: function.clone(),
+                            critical_type: critical.clone(),
+                        });
+                    }
+                }
+            }
+            let mut idents = Vec::new();
+            type_idents(&arg.ty, &mut idents);
+            diag_sources.extend(
+                idents
+                    .into_iter()
+                    .filter(|i| diagnostic_source(i)),
+            );
+        }
+
+        if m.diag_join || diag_sources.is_empty() {
+            return;
+        }
+        let returns_critical = match &sig.output {
+            ReturnType::Type(_, ty) => {
+                let mut idents = Vec::new();
+                type_idents(ty, &mut idents);
+                idents.iter().any(|i| critical_ident(i))
+            }
+            ReturnType::Default => false,
+        };
+        if m.sink || m.trusted_writer || returns_critical {
+            diag_sources.sort();
+            diag_sources.dedup();
+            for source in diag_sources {
+                self.report.violations.push(Violation::TaintedFlow {
+                    file: self.file.clone(),
+                    function: function.clone(),
+                    source,
+                });
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for MarkerVisitor<'_> {
+    fn visit_item_type(&mut self, i: &'ast syn::ItemType) {
+        self.check_critical(&i.attrs, &i.ident);
+        syn::visit::visit_item_type(self, i);
+    }
+
+    fn visit_item_struct(&mut self, i: &'ast syn::ItemStruct) {
+        self.check_critical(&i.attrs, &i.ident);
+        syn::visit::visit_item_struct(self, i);
+    }
+
+    fn visit_item_enum(&mut self, i: &'ast syn::ItemEnum) {
+        self.check_critical(&i.attrs, &i.ident);
+        syn::visit::visit_item_enum(self, i);
+    }
+
+    fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
+        self.check_fn(&i.attrs, &i.sig);
+        syn::visit::visit_item_fn(self, i);
+    }
+
+    fn visit_impl_item_fn(&mut self, i: &'ast syn::ImplItemFn) {
+        self.check_fn(&i.attrs, &i.sig);
+        syn::visit::visit_impl_item_fn(self, i);
+    }
+}
@@ -0,0 +1,35 @@
+//! `nr_taint_analyzer <crate-root>` — reads `cargo check --message-format json`
+//! on stdin, prints a taint report, and exits 0 (clean), 1 (violations),
+//! or 2 (bad input / failed build).
+
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let Some(root) = std::env::args_os().nth(1).map(PathBuf::from) else {
+        eprintln!("usage: cargo check --message-format json | nr_taint_analyzer <crate-root>");
+        return ExitCode::from(2);
+    };
+
+    let mut stream = String::new();
+    if let Err(err) = std::io::stdin().read_to_string(&mut stream) {
+        eprintln!("nr_taint_analyzer: failed to read stdin: {}", err);
+        return ExitCode::from(2);
+    }
+
+    match nr_taint_analyzer::analyze(&stream, &root) {
+        Ok(report) => {
+            print!("{}", report);
+            if report.is_clean() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(1)
+            }
+        }
+        Err(err) => {
+            eprintln!("nr_taint_analyzer: {}", err);
+            ExitCode::from(2)
+        }
+    }
+}
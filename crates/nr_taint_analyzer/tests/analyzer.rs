@@ -0,0 +1,122 @@
+//! End-to-end runs of the analyzer binary over the fixture crates.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+use serde_json::json;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// Minimal `cargo check --message-format json` stream for a fixture crate,
+/// including an unrelated dependency artifact that must be ignored.
+fn check_stream(root: &Path, extra: &[serde_json::Value]) -> String {
+    let mut lines = vec![
+        json!({
+            "reason": "compiler-artifact",
+            "manifest_path": "/registry/quote-1.0.0/Cargo.toml",
+            "target": { "kind": ["lib"], "src_path": "/registry/quote-1.0.0/src/lib.rs" }
+        }),
+        json!({
+            "reason": "compiler-artifact",
+            "manifest_path": root.join("Cargo.toml"),
+            "target": { "kind": ["lib"], "src_path": root.join("src/lib.rs") }
+        }),
+    ];
+    lines.extend_from_slice(extra);
+    lines.push(json!({ "reason": "build-finished", "success": extra.is_empty() }));
+    lines.iter().map(|l| l.to_string() + "\n").collect()
+}
+
+fn run(root: &Path, stream: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nr_taint_analyzer"))
+        .arg(root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stream.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn clean_fixture_passes() {
+    let root = fixture("clean");
+    let out = run(&root, &check_stream(&root, &[]));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(0), "{}", stdout);
+    assert!(stdout.contains("1 critical, 1 trusted writer(s), 1 sink(s), 1 diag join(s)"));
+    assert!(stdout.contains("result: clean"));
+}
+
+#[test]
+fn violating_fixture_reports_untrusted_writer() {
+    let root = fixture("violating");
+    let out = run(&root, &check_stream(&root, &[]));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert_eq!(out.status.code(), Some(1), "{}", stdout);
+    assert!(stdout.contains(
+        "violation: src/lib.rs: fn `bump_capability` takes `&mut CapabilityState` \
+         but is not #[nr_taint_trusted_writer]"
+    ));
+    assert!(stdout.contains("result: 1 violation(s)"));
+}
+
+#[test]
+fn failed_build_is_not_analyzed() {
+    let root = fixture("clean");
+    let error = json!({
+        "reason": "compiler-message",
+        "message": { "level": "error", "message": "cannot find type `Foo` in this scope" }
+    });
+    let out = run(&root, &check_stream(&root, &[error]));
+    assert_eq!(out.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("cannot find type `Foo`"));
+}
+
+#[test]
+fn policy_tables_are_read_from_taint_spec() {
+    use nr_taint_analyzer::TaintSpec;
+
+    let spec = TaintSpec::embedded();
+    assert_eq!(spec.critical_types.len(), 9);
+    assert!(spec.critical_types.contains(&(
+        "ReversalContext".to_string(),
+        "crate::policyengine::reversalconditions::ReversalContext".to_string()
+    )));
+    assert_eq!(spec.diagnostic_sources.len(), 5);
+    assert!(spec.diagnostic_sources.iter().any(|s| s == "EnvelopeContextView"));
+
+    // A spec whose match arm is `false` does not make the type critical.
+    let edited = r#"
+        pub enum DiagnosticSource { TreeOfLifeView }
+        impl TaintPolicy {
+            pub fn is_critical_type(&self, fq_type: &str) -> bool {
+                match fq_type {
+                    "crate::alncore::CapabilityState" => true,
+                    "crate::alncore::Decision" => false,
+                    _ => false,
+                }
+            }
+        }
+    "#;
+    let parsed = TaintSpec::parse(edited).unwrap();
+    assert_eq!(
+        parsed.critical_types,
+        vec![(
+            "CapabilityState".to_string(),
+            "crate::alncore::CapabilityState".to_string()
+        )]
+    );
+    assert!(TaintSpec::parse("pub struct Unrelated;").is_err());
+}
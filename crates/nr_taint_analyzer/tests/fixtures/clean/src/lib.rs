@@ -0,0 +1,33 @@
+//! Taint fixture that follows every rule of the policy.
+
+use nr_taint_macros::{
+    nr_taint_critical, nr_taint_diag_join, nr_taint_sink, nr_taint_trusted_writer,
+};
+
+#[nr_taint_critical(path = "crate::alncore::CapabilityState")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapabilityState {
+    CapModelOnly,
+    CapLabBench,
+}
+
+pub struct EnvelopeContextView {
+    pub request_capability_downgrade: bool,
+}
+
+#[nr_taint_diag_join]
+pub fn compute_no_safer_alternative(envelope_ctx: &EnvelopeContextView) -> bool {
+    envelope_ctx.request_capability_downgrade
+}
+
+#[nr_taint_trusted_writer]
+pub fn apply_transition(state: &mut CapabilityState, nosaferalternative: bool) {
+    if nosaferalternative {
+        *state = CapabilityState::CapModelOnly;
+    }
+}
+
+#[nr_taint_sink]
+pub fn evaluate(state: CapabilityState) -> bool {
+    state == CapabilityState::CapLabBench
+}
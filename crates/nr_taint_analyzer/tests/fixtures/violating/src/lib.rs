@@ -0,0 +1,20 @@
+//! Taint fixture with a single violation: `bump_capability` writes a
+//! critical type without being a declared trusted writer.
+
+use nr_taint_macros::{nr_taint_critical, nr_taint_trusted_writer};
+
+#[nr_taint_critical(path = "crate::alncore::CapabilityState")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapabilityState {
+    CapModelOnly,
+    CapLabBench,
+}
+
+#[nr_taint_trusted_writer]
+pub fn apply_transition(state: &mut CapabilityState) {
+    *state = CapabilityState::CapModelOnly;
+}
+
+pub fn bump_capability(state: &mut CapabilityState) {
+    *state = CapabilityState::CapLabBench;
+}
@@ -8,64 +8,155 @@
 //! A separate static analyzer can consume the marker metadata
 //! via `cargo check --message-format json` if deeper analysis
 //! is needed.
+//!
+//! `#[derive(WCycle)]` also lives here, as the crate's one derive: it renders
+//! What / SoWhat / NowWhat advisory views from templated struct fields.
 
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
-    parse_macro_input, AttributeArgs, Item, ItemFn, ItemMod, ItemType, Meta, NestedMeta,
+    parse_macro_input, punctuated::Punctuated, Attribute, Data, DeriveInput, Expr, ExprLit, FnArg,
+    Fields, GenericArgument, Ident, Item, ItemFn, ItemMod, Lit, LitStr, Meta, PathArguments,
+    ReturnType, Token, Type, Visibility,
 };
 
+/// Attribute arguments; only `nr_taint_critical` currently interprets them.
+type MarkerArgs = Punctuated<Meta, Token![,]>;
+
 /// #[nr_taint_critical]
 ///
-/// Marks a type alias or item as policy-critical.
-/// For now this is a pure marker; deeper checks are done
-/// by the analyzer that reads the compiled metadata.
+/// Marks a type alias, struct, or enum as policy-critical.
+///
+/// Besides `#[doc(hidden)]`, the item gets a sibling hidden const
+/// `__NR_TAINT_CRITICAL_<Ident>` whose value is `"<module_path>::<Ident>|critical"`,
+/// so the analyzer can enumerate marked items from compiled metadata
+/// without re-parsing source. Visibility and generics are preserved.
+///
+/// `#[nr_taint_critical(path = "crate::alncore::CapabilityState")]` records
+/// the given canonical path instead, so markers can be cross-checked against
+/// `TAINT_POLICY`. Any other argument is a compile error.
 #[proc_macro_attribute]
 pub fn nr_taint_critical(args: TokenStream, input: TokenStream) -> TokenStream {
-    let _ = parse_macro_input!(args as AttributeArgs);
-    let item = parse_macro_input!(input as Item);
+    let args = parse_macro_input!(args with MarkerArgs::parse_terminated);
+    let path = match critical_path_arg(&args) {
+        Ok(path) => path,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let mut item = parse_macro_input!(input as Item);
 
-    // Inject a doc flag so the analyzer can discover this easily.
-    let expanded = match item {
-        Item::Type(ItemType { attrs, vis, type_token, ident, generics, eq_token, ty, semi_token }) => {
-            let mut attrs = attrs;
-            attrs.push(syn::parse_quote!(#[doc(hidden)]));
-            quote! {
-                #(#attrs)*
-                #vis #type_token #ident #generics #eq_token #ty #semi_token
-            }
-        }
-        other => {
+    let hidden: Attribute = syn::parse_quote!(#[doc(hidden)]);
+    let marked = match &mut item {
+        Item::Type(i) => Some((&mut i.attrs, i.vis.clone(), i.ident.clone())),
+        Item::Struct(i) => Some((&mut i.attrs, i.vis.clone(), i.ident.clone())),
+        Item::Enum(i) => Some((&mut i.attrs, i.vis.clone(), i.ident.clone())),
+        _ => None,
+    };
+
+    let expanded = match marked {
+        Some((attrs, vis, ident)) => {
+            attrs.push(hidden);
+            let marker = critical_marker_const(&vis, &ident, path.as_ref());
             quote! {
-                #[doc(hidden)]
-                #other
+                #item
+                #marker
             }
         }
+        // Other items keep the plain doc flag; the analyzer falls back to
+        // matching the attribute path for them.
+        None => quote! {
+            #hidden
+            #item
+        },
     };
 
     expanded.into()
 }
 
+/// Parse the optional `path = "..."` argument of `nr_taint_critical`.
+fn critical_path_arg(args: &MarkerArgs) -> syn::Result<Option<LitStr>> {
+    let mut path = None;
+    for meta in args {
+        match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("path") => {
+                if path.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        nv,
+                        "nr_taint_critical: duplicate `path` argument",
+                    ));
+                }
+                match &nv.value {
+                    Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => path = Some(lit.clone()),
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "nr_taint_critical: `path` must be a string literal",
+                        ))
+                    }
+                }
+            }
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    format!(
+                        "nr_taint_critical: unknown argument `{}`; expected `path = \"...\"`",
+                        other.path().to_token_stream()
+                    ),
+                ))
+            }
+        }
+    }
+    Ok(path)
+}
+
+/// Hidden `&str` const recording the fully-qualified path of a critical item.
+fn critical_marker_const(
+    vis: &Visibility,
+    ident: &Ident,
+    path: Option<&LitStr>,
+) -> proc_macro2::TokenStream {
+    marker_const("CRITICAL", "critical", vis, ident, path)
+}
+
+/// Hidden `__NR_TAINT_<PREFIX>_<Ident>` const with value `"<fq_path>|<kind>"`,
+/// where `fq_path` is `path` if given, else `<module_path>::<Ident>`.
+fn marker_const(
+    prefix: &str,
+    kind: &str,
+    vis: &Visibility,
+    ident: &Ident,
+    path: Option<&LitStr>,
+) -> proc_macro2::TokenStream {
+    let const_ident = format_ident!("__NR_TAINT_{}_{}", prefix, ident);
+    let value = match path {
+        Some(path) => quote! { concat!(#path, "|", #kind) },
+        None => {
+            let name = ident.to_string();
+            quote! { concat!(module_path!(), "::", #name, "|", #kind) }
+        }
+    };
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals, dead_code)]
+        #vis const #const_ident: &str = #value;
+    }
+}
+
 /// #[nr_taint_trusted_writer]
 ///
 /// Marks a function as an allowed writer of critical types.
-/// Enforces a small syntactic rule: the function itself cannot be `unsafe`.
+/// Syntactic guards:
+/// - Must not be `unsafe`.
+/// - Must not declare an ABI (`extern "C"` etc.); FFI-exposed writers are rejected.
+/// - No parameter may be or contain a raw pointer (`*const T` / `*mut T`).
 #[proc_macro_attribute]
 pub fn nr_taint_trusted_writer(args: TokenStream, input: TokenStream) -> TokenStream {
-    let _ = parse_macro_input!(args as AttributeArgs);
+    let _ = parse_macro_input!(args with MarkerArgs::parse_terminated);
     let item = parse_macro_input!(input as Item);
 
     match item {
         Item::Fn(ref fn_item) => {
-            if fn_item.sig.unsafety.is_some() {
-                let ident = &fn_item.sig.ident;
-                let err = syn::Error::new_spanned(
-                    &fn_item.sig,
-                    format!(
-                        "nr_taint_trusted_writer: trusted writer `{}` must not be `unsafe`",
-                        ident
-                    ),
-                );
+            if let Some(err) = fn_guard_error("nr_taint_trusted_writer", "trusted writer", fn_item)
+            {
                 return err.to_compile_error().into();
             }
         }
@@ -84,13 +175,64 @@ pub fn nr_taint_trusted_writer(args: TokenStream, input: TokenStream) -> TokenSt
     tokens.into()
 }
 
+/// Shared syntactic guards for function markers: no `unsafe`, no ABI,
+/// and no raw-pointer parameters.
+fn fn_guard_error(macro_name: &str, role: &str, fn_item: &ItemFn) -> Option<syn::Error> {
+    let ident = &fn_item.sig.ident;
+    if fn_item.sig.unsafety.is_some() {
+        return Some(syn::Error::new_spanned(
+            &fn_item.sig,
+            format!("{}: {} `{}` must not be `unsafe`", macro_name, role, ident),
+        ));
+    }
+    if let Some(abi) = &fn_item.sig.abi {
+        return Some(syn::Error::new_spanned(
+            abi,
+            format!("{}: {} `{}` must not be FFI-exposed", macro_name, role, ident),
+        ));
+    }
+    fn_item.sig.inputs.iter().find_map(|input| match input {
+        FnArg::Typed(arg) if contains_raw_ptr(&arg.ty) => Some(syn::Error::new_spanned(
+            arg,
+            format!(
+                "{}: argument `{}` of {} `{}` must not be a raw pointer",
+                macro_name,
+                arg.pat.to_token_stream(),
+                role,
+                ident
+            ),
+        )),
+        _ => None,
+    })
+}
+
+/// True if `ty` is, or syntactically wraps, a `*const`/`*mut` pointer.
+fn contains_raw_ptr(ty: &Type) -> bool {
+    match ty {
+        Type::Ptr(_) => true,
+        Type::Reference(r) => contains_raw_ptr(&r.elem),
+        Type::Slice(s) => contains_raw_ptr(&s.elem),
+        Type::Array(a) => contains_raw_ptr(&a.elem),
+        Type::Paren(p) => contains_raw_ptr(&p.elem),
+        Type::Group(g) => contains_raw_ptr(&g.elem),
+        Type::Tuple(t) => t.elems.iter().any(contains_raw_ptr),
+        Type::Path(p) => p.path.segments.iter().any(|seg| match &seg.arguments {
+            PathArguments::AngleBracketed(generic) => generic.args.iter().any(|arg| {
+                matches!(arg, GenericArgument::Type(inner) if contains_raw_ptr(inner))
+            }),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
 /// #[nr_taint_trusted_reader]
 ///
 /// Marks a module as a read-only consumer of critical types.
 /// Syntactic guard: must be used on modules, not functions.
 #[proc_macro_attribute]
 pub fn nr_taint_trusted_reader(args: TokenStream, input: TokenStream) -> TokenStream {
-    let _ = parse_macro_input!(args as AttributeArgs);
+    let _ = parse_macro_input!(args with MarkerArgs::parse_terminated);
     let item = parse_macro_input!(input as Item);
 
     match item {
@@ -117,9 +259,11 @@ pub fn nr_taint_trusted_reader(args: TokenStream, input: TokenStream) -> TokenSt
 /// Syntactic guards:
 /// - Must be applied to a function.
 /// - Must not be `unsafe`.
+/// - Must return `bool`, `Result<bool, _>`, or `Option<NoSaferAlternativeProof>`
+///   (the sealed proof token standing in for the bool).
 #[proc_macro_attribute]
 pub fn nr_taint_diag_join(args: TokenStream, input: TokenStream) -> TokenStream {
-    let _ = parse_macro_input!(args as AttributeArgs);
+    let _ = parse_macro_input!(args with MarkerArgs::parse_terminated);
     let item = parse_macro_input!(input as Item);
 
     match item {
@@ -135,8 +279,19 @@ pub fn nr_taint_diag_join(args: TokenStream, input: TokenStream) -> TokenStream
                 );
                 return err.to_compile_error().into();
             }
-            // Could add further syntactic checks here (e.g., return type),
-            // but deeper semantic checks should live in the analyzer.
+            if !is_diag_join_output(&fn_item.sig.output) {
+                let ident = &fn_item.sig.ident;
+                let err = syn::Error::new_spanned(
+                    &fn_item.sig,
+                    format!(
+                        "nr_taint_diag_join: diagnostic join point `{}` must return `bool`, \
+                         `Result<bool, _>`, or `Option<NoSaferAlternativeProof>`",
+                        ident
+                    ),
+                );
+                return err.to_compile_error().into();
+            }
+            // Deeper semantic checks should live in the analyzer.
             let tokens = quote! { #fn_item };
             tokens.into()
         }
@@ -149,3 +304,265 @@ pub fn nr_taint_diag_join(args: TokenStream, input: TokenStream) -> TokenStream
         }
     }
 }
+
+/// True if a join point's return type can only express `nosaferalternative`.
+fn is_diag_join_output(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let Some((name, inner)) = last_segment_with_first_arg(ty) else {
+        return false;
+    };
+    match name.as_str() {
+        "bool" => inner.is_none(),
+        "Result" => inner.is_some_and(|t| last_segment_is(t, "bool")),
+        "Option" => inner.is_some_and(|t| last_segment_is(t, "NoSaferAlternativeProof")),
+        _ => false,
+    }
+}
+
+/// Last path segment of `ty` and its first generic type argument, if any.
+fn last_segment_with_first_arg(ty: &Type) -> Option<(String, Option<&Type>)> {
+    let Type::Path(p) = ty else {
+        return None;
+    };
+    let seg = p.path.segments.last()?;
+    let first = match &seg.arguments {
+        PathArguments::AngleBracketed(generic) => generic.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(t) => Some(t),
+            _ => None,
+        }),
+        _ => None,
+    };
+    Some((seg.ident.to_string(), first))
+}
+
+fn last_segment_is(ty: &Type, name: &str) -> bool {
+    matches!(last_segment_with_first_arg(ty), Some((n, None)) if n == name)
+}
+
+/// #[nr_taint_sink]
+///
+/// Marks a function that must never receive tainted diagnostic values
+/// (see `DiagnosticSource` in the taint spec) except via the diagnostic
+/// join point. Emits a hidden `__NR_TAINT_SINK_<ident>` const so the
+/// analyzer can enumerate declared sinks.
+///
+/// Syntactic guards (same as trusted writers):
+/// - Must be applied to a function.
+/// - Must not be `unsafe`, FFI-exposed, or take raw-pointer arguments.
+#[proc_macro_attribute]
+pub fn nr_taint_sink(args: TokenStream, input: TokenStream) -> TokenStream {
+    let _ = parse_macro_input!(args with MarkerArgs::parse_terminated);
+    let item = parse_macro_input!(input as Item);
+
+    match item {
+        Item::Fn(ref fn_item) => {
+            if let Some(err) = fn_guard_error("nr_taint_sink", "taint sink", fn_item) {
+                return err.to_compile_error().into();
+            }
+            let marker = marker_const("SINK", "sink", &fn_item.vis, &fn_item.sig.ident, None);
+            let tokens = quote! {
+                #fn_item
+                #marker
+            };
+            tokens.into()
+        }
+        _ => {
+            let err = syn::Error::new_spanned(
+                item.to_token_stream(),
+                "#[nr_taint_sink] may only be applied to functions",
+            );
+            err.to_compile_error().into()
+        }
+    }
+}
+
+/// #[derive(WCycle)]
+///
+/// Generates `pub fn w_cycle_view(&self) -> WCycleView` from three templates:
+///
+/// ```ignore
+/// #[derive(WCycle)]
+/// #[w_cycle(
+///     what = "Tick {unit.tick}: {unit.kind:?} by actor {unit.actor_id}",
+///     so_what = "Fairness verdict: negative={verdict.fairness_negative}",
+///     now_what = "Log this micro-unit to the moral ledger."
+/// )]
+/// struct DeedReflection<'a> {
+///     unit: &'a MicroUnit,
+///     verdict: &'a FairnessVerdict,
+/// }
+/// ```
+///
+/// `{field}` / `{field.path}` placeholders must start with a named field of
+/// the struct and accept a `format!` spec after `:` (e.g. `{kind:?}`);
+/// `{{` and `}}` are literal braces. `WCycleView { what, so_what, now_what }`
+/// must be in scope where the derive is used. All three templates are
+/// required.
+#[proc_macro_derive(WCycle, attributes(w_cycle))]
+pub fn derive_w_cycle(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_w_cycle(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+const W_CYCLE_KEYS: [&str; 3] = ["what", "so_what", "now_what"];
+
+fn expand_w_cycle(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields: Vec<&Ident> = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => named.named.iter().filter_map(|f| f.ident.as_ref()).collect(),
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    };
+    if fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(WCycle)] requires a struct with named fields",
+        ));
+    }
+
+    let mut templates: [Option<LitStr>; 3] = [None, None, None];
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("w_cycle")) {
+        for meta in attr.parse_args_with(MarkerArgs::parse_terminated)? {
+            let Meta::NameValue(nv) = &meta else {
+                return Err(syn::Error::new_spanned(
+                    &meta,
+                    "w_cycle: expected `what`, `so_what`, or `now_what = \"...\"`",
+                ));
+            };
+            let key = nv.path.to_token_stream().to_string();
+            let Some(slot) = W_CYCLE_KEYS.iter().position(|k| *k == key) else {
+                return Err(syn::Error::new_spanned(
+                    &nv.path,
+                    format!(
+                        "w_cycle: unknown key `{}`; expected `what`, `so_what`, or `now_what`",
+                        key
+                    ),
+                ));
+            };
+            if templates[slot].is_some() {
+                return Err(syn::Error::new_spanned(
+                    nv,
+                    format!("w_cycle: duplicate `{}` template", key),
+                ));
+            }
+            match &nv.value {
+                Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => templates[slot] = Some(lit.clone()),
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        format!("w_cycle: `{}` must be a string literal", key),
+                    ))
+                }
+            }
+        }
+    }
+
+    let mut rendered = Vec::with_capacity(3);
+    for (key, template) in W_CYCLE_KEYS.iter().zip(&templates) {
+        let Some(template) = template else {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "#[derive(WCycle)]: missing `{} = \"...\"` in #[w_cycle(...)]",
+                    key
+                ),
+            ));
+        };
+        let (format, args) = w_cycle_template(template, &fields)?;
+        rendered.push(quote! { ::std::format!(#format #(, #args)*) });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (what, so_what, now_what) = (&rendered[0], &rendered[1], &rendered[2]);
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// What / SoWhat / NowWhat advisory view rendered from `#[w_cycle]` templates.
+            pub fn w_cycle_view(&self) -> WCycleView {
+                WCycleView {
+                    what: #what,
+                    so_what: #so_what,
+                    now_what: #now_what,
+                }
+            }
+        }
+    })
+}
+
+/// Rewrite `{field.path:spec}` placeholders into positional `{:spec}` and
+/// collect the matching `&self.field.path` arguments.
+fn w_cycle_template(
+    template: &LitStr,
+    fields: &[&Ident],
+) -> syn::Result<(LitStr, Vec<proc_macro2::TokenStream>)> {
+    let source = template.value();
+    let mut format = String::with_capacity(source.len());
+    let mut args = Vec::new();
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                format.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                format.push_str("}}");
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(c);
+                }
+                if !closed {
+                    return Err(syn::Error::new_spanned(template, "w_cycle: unclosed `{` in template"));
+                }
+                let (path, spec) = match placeholder.split_once(':') {
+                    Some((path, spec)) => (path.trim(), Some(spec)),
+                    None => (placeholder.trim(), None),
+                };
+                let segments: Vec<&str> = path.split('.').collect();
+                if !fields.iter().any(|f| *f == segments[0]) {
+                    return Err(syn::Error::new_spanned(
+                        template,
+                        format!("w_cycle: `{{{}}}` does not name a field of this struct", path),
+                    ));
+                }
+                let mut access = Vec::with_capacity(segments.len());
+                for segment in &segments {
+                    access.push(syn::parse_str::<syn::Member>(segment).map_err(|_| {
+                        syn::Error::new_spanned(
+                            template,
+                            format!("w_cycle: `{}` is not a valid field path", path),
+                        )
+                    })?);
+                }
+                args.push(quote! { &self.#(#access).* });
+                format.push('{');
+                if let Some(spec) = spec {
+                    format.push(':');
+                    format.push_str(spec);
+                }
+                format.push('}');
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(template, "w_cycle: unmatched `}` in template"));
+            }
+            c => format.push(c),
+        }
+    }
+
+    Ok((LitStr::new(&format, template.span()), args))
+}
@@ -0,0 +1,74 @@
+use nr_taint_macros::nr_taint_critical;
+
+mod kernel {
+    use super::nr_taint_critical;
+
+    pub struct Inner<T>(pub T);
+
+    #[nr_taint_critical]
+    pub type CriticalInner<T> = Inner<T>;
+
+    #[nr_taint_critical]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct RoHScore {
+        pub value: f32,
+    }
+
+    #[nr_taint_critical]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CapabilityState {
+        CapModelOnly,
+        CapLabBench,
+    }
+
+    #[nr_taint_critical(path = "crate::alncore::PolicyStack")]
+    pub type CriticalPolicyStack = Inner<u32>;
+}
+
+use kernel::*;
+
+#[test]
+fn test_generic_type_alias_emits_marker_const() {
+    assert_eq!(
+        __NR_TAINT_CRITICAL_CriticalInner,
+        "critical_marker::kernel::CriticalInner|critical"
+    );
+    let alias: CriticalInner<u8> = Inner(3);
+    assert_eq!(alias.0, 3);
+}
+
+#[test]
+fn test_struct_emits_marker_const() {
+    assert_eq!(
+        __NR_TAINT_CRITICAL_RoHScore,
+        "critical_marker::kernel::RoHScore|critical"
+    );
+    let score = RoHScore { value: 0.2 };
+    assert_eq!(score, RoHScore { value: 0.2 });
+}
+
+#[test]
+fn test_enum_emits_marker_const() {
+    assert_eq!(
+        __NR_TAINT_CRITICAL_CapabilityState,
+        "critical_marker::kernel::CapabilityState|critical"
+    );
+    assert_ne!(CapabilityState::CapModelOnly, CapabilityState::CapLabBench);
+}
+
+#[test]
+fn test_path_argument_overrides_module_path() {
+    assert_eq!(
+        __NR_TAINT_CRITICAL_CriticalPolicyStack,
+        "crate::alncore::PolicyStack|critical"
+    );
+    let stack: CriticalPolicyStack = Inner(7);
+    assert_eq!(stack.0, 7);
+}
+
+#[test]
+fn test_unknown_or_malformed_arguments_are_rejected() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/critical_unknown_arg.rs");
+    t.compile_fail("tests/ui/critical_path_not_str.rs");
+}
@@ -0,0 +1,19 @@
+//! Compile-time checks for the `#[nr_taint_diag_join]` return-type guard.
+
+#[test]
+fn diag_join_return_type_guard() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/diag_join_bool.rs");
+    t.compile_fail("tests/ui/diag_join_unit.rs");
+    t.compile_fail("tests/ui/diag_join_on_struct.rs");
+}
+
+/// `nosaferalternative` only accepts the sealed proof from the join point:
+/// neither a forged proof nor a raw bool compiles.
+#[test]
+fn diag_join_proof_is_sealed() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/diag_join_proof.rs");
+    t.compile_fail("tests/ui/diag_join_proof_forged.rs");
+    t.compile_fail("tests/ui/diag_join_proof_raw_bool.rs");
+}
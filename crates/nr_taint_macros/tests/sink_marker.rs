@@ -0,0 +1,39 @@
+use nr_taint_macros::nr_taint_sink;
+
+pub struct CapabilityTransitionRequest {
+    pub from: u8,
+    pub to: u8,
+}
+
+#[nr_taint_sink]
+pub fn evaluate(req: &CapabilityTransitionRequest) -> bool {
+    req.to <= req.from
+}
+
+impl CapabilityTransitionRequest {
+    #[nr_taint_sink]
+    pub fn is_downgrade(&self) -> bool {
+        self.to < self.from
+    }
+}
+
+#[test]
+fn test_sink_on_fn_compiles_and_emits_marker_const() {
+    assert!(evaluate(&CapabilityTransitionRequest { from: 2, to: 1 }));
+    assert_eq!(__NR_TAINT_SINK_evaluate, "sink_marker::evaluate|sink");
+}
+
+#[test]
+fn test_sink_on_method_emits_associated_marker_const() {
+    assert!(CapabilityTransitionRequest { from: 2, to: 1 }.is_downgrade());
+    assert_eq!(
+        CapabilityTransitionRequest::__NR_TAINT_SINK_is_downgrade,
+        "sink_marker::is_downgrade|sink"
+    );
+}
+
+#[test]
+fn test_sink_rejects_non_fn_items() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/sink_on_struct.rs");
+}
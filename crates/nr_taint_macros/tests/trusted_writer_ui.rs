@@ -0,0 +1,9 @@
+//! Compile-time checks for the `#[nr_taint_trusted_writer]` syntactic guards.
+
+#[test]
+fn trusted_writer_guards() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/trusted_writer_clean.rs");
+    t.compile_fail("tests/ui/trusted_writer_raw_ptr.rs");
+    t.compile_fail("tests/ui/trusted_writer_extern_c.rs");
+}
@@ -0,0 +1,6 @@
+use nr_taint_macros::nr_taint_critical;
+
+#[nr_taint_critical(path = 42)]
+pub struct CapabilityState;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nr_taint_macros::nr_taint_critical;
+
+#[nr_taint_critical(fq = "crate::alncore::CapabilityState")]
+pub struct CapabilityState;
+
+fn main() {}
@@ -0,0 +1,23 @@
+use nr_taint_macros::nr_taint_diag_join;
+
+pub struct EnvelopeContextView {
+    pub request_capability_downgrade: bool,
+}
+
+#[nr_taint_diag_join]
+pub fn compute_no_safer_alternative(envelope_ctx: &EnvelopeContextView) -> bool {
+    envelope_ctx.request_capability_downgrade
+}
+
+#[nr_taint_diag_join]
+pub fn try_compute_no_safer_alternative(
+    envelope_ctx: &EnvelopeContextView,
+) -> Result<bool, String> {
+    Ok(envelope_ctx.request_capability_downgrade)
+}
+
+fn main() {
+    let ctx = EnvelopeContextView { request_capability_downgrade: true };
+    assert!(compute_no_safer_alternative(&ctx));
+    assert_eq!(try_compute_no_safer_alternative(&ctx), Ok(true));
+}
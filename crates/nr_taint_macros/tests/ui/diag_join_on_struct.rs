@@ -0,0 +1,6 @@
+use nr_taint_macros::nr_taint_diag_join;
+
+#[nr_taint_diag_join]
+pub struct NoSaferAlternative(bool);
+
+fn main() {}
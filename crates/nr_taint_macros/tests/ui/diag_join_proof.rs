@@ -0,0 +1,40 @@
+use nr_taint_macros::nr_taint_diag_join;
+
+mod reversal_policy {
+    use super::nr_taint_diag_join;
+
+    pub struct EnvelopeContextView {
+        pub request_capability_downgrade: bool,
+    }
+
+    /// Sealed: only the join point below can construct one.
+    #[derive(Debug)]
+    pub struct NoSaferAlternativeProof {
+        _sealed: (),
+    }
+
+    #[nr_taint_diag_join]
+    pub fn compute_no_safer_alternative(
+        envelope_ctx: &EnvelopeContextView,
+    ) -> Option<NoSaferAlternativeProof> {
+        envelope_ctx
+            .request_capability_downgrade
+            .then_some(NoSaferAlternativeProof { _sealed: () })
+    }
+}
+
+use reversal_policy::{compute_no_safer_alternative, EnvelopeContextView, NoSaferAlternativeProof};
+
+pub struct ReversalContext {
+    pub nosaferalternative: Option<NoSaferAlternativeProof>,
+}
+
+fn main() {
+    let envelope_ctx = EnvelopeContextView {
+        request_capability_downgrade: true,
+    };
+    let ctx = ReversalContext {
+        nosaferalternative: compute_no_safer_alternative(&envelope_ctx),
+    };
+    assert!(ctx.nosaferalternative.is_some());
+}
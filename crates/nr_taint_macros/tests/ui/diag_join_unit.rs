@@ -0,0 +1,12 @@
+use nr_taint_macros::nr_taint_diag_join;
+
+pub struct EnvelopeContextView {
+    pub request_capability_downgrade: bool,
+}
+
+#[nr_taint_diag_join]
+pub fn compute_no_safer_alternative(envelope_ctx: &EnvelopeContextView) {
+    let _ = envelope_ctx.request_capability_downgrade;
+}
+
+fn main() {}
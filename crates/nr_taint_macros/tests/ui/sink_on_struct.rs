@@ -0,0 +1,9 @@
+use nr_taint_macros::nr_taint_sink;
+
+#[nr_taint_sink]
+pub struct CapabilityTransitionRequest {
+    pub from: u8,
+    pub to: u8,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use nr_taint_macros::nr_taint_trusted_writer;
+
+pub struct CapabilityState(u8);
+
+#[nr_taint_trusted_writer]
+pub fn apply_transition(state: &mut CapabilityState, next: Option<u8>) {
+    if let Some(next) = next {
+        state.0 = next;
+    }
+}
+
+fn main() {
+    let mut state = CapabilityState(0);
+    apply_transition(&mut state, Some(1));
+    assert_eq!(state.0, 1);
+}
@@ -0,0 +1,10 @@
+use nr_taint_macros::nr_taint_trusted_writer;
+
+pub struct CapabilityState(u8);
+
+#[nr_taint_trusted_writer]
+pub extern "C" fn apply_transition(state: &mut CapabilityState, next: u8) {
+    state.0 = next;
+}
+
+fn main() {}
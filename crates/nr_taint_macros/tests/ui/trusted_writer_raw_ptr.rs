@@ -0,0 +1,10 @@
+use nr_taint_macros::nr_taint_trusted_writer;
+
+pub struct CapabilityState(u8);
+
+#[nr_taint_trusted_writer]
+pub fn apply_transition(state: *mut CapabilityState, next: u8) {
+    let _ = (state, next);
+}
+
+fn main() {}
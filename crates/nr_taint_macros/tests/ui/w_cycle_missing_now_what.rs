@@ -0,0 +1,15 @@
+use nr_taint_macros::WCycle;
+
+pub struct WCycleView {
+    pub what: String,
+    pub so_what: String,
+    pub now_what: String,
+}
+
+#[derive(WCycle)]
+#[w_cycle(what = "Tick {tick}", so_what = "nothing to report")]
+struct Reflection {
+    tick: u64,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use nr_taint_macros::WCycle;
+
+pub struct WCycleView {
+    pub what: String,
+    pub so_what: String,
+    pub now_what: String,
+}
+
+#[derive(WCycle)]
+#[w_cycle(what = "Tick {tick}", so_what = "actor {actor_id}", now_what = "review")]
+struct Reflection {
+    tick: u64,
+}
+
+fn main() {}
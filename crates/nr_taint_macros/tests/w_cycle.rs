@@ -0,0 +1,63 @@
+use nr_taint_macros::WCycle;
+
+#[derive(Debug, PartialEq)]
+pub struct WCycleView {
+    pub what: String,
+    pub so_what: String,
+    pub now_what: String,
+}
+
+#[derive(Debug)]
+enum DeedKind {
+    Repair,
+}
+
+struct MicroUnit {
+    tick: u64,
+    actor_id: String,
+    kind: DeedKind,
+}
+
+struct Verdict {
+    fairness_negative: bool,
+    negative_weight: f32,
+}
+
+#[derive(WCycle)]
+#[w_cycle(
+    what = "Tick {unit.tick}: {unit.kind:?} by actor {unit.actor_id} on {site_count} site(s)",
+    so_what = "negative={verdict.fairness_negative}, weight {verdict.negative_weight:.2}",
+    now_what = "Log {{unit}} to the moral ledger; no actuation occurs here."
+)]
+struct DeedReflection<'a> {
+    unit: &'a MicroUnit,
+    verdict: &'a Verdict,
+    site_count: usize,
+}
+
+#[test]
+fn test_w_cycle_derive_renders_templates() {
+    let unit = MicroUnit {
+        tick: 7,
+        actor_id: "actor-1".into(),
+        kind: DeedKind::Repair,
+    };
+    let verdict = Verdict {
+        fairness_negative: true,
+        negative_weight: 0.5,
+    };
+    let reflection = DeedReflection {
+        unit: &unit,
+        verdict: &verdict,
+        site_count: 3,
+    };
+
+    assert_eq!(
+        reflection.w_cycle_view(),
+        WCycleView {
+            what: "Tick 7: Repair by actor actor-1 on 3 site(s)".into(),
+            so_what: "negative=true, weight 0.50".into(),
+            now_what: "Log {unit} to the moral ledger; no actuation occurs here.".into(),
+        }
+    );
+}
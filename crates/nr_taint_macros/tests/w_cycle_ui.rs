@@ -0,0 +1,8 @@
+//! Compile-time checks for `#[derive(WCycle)]` template attributes.
+
+#[test]
+fn w_cycle_template_guards() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/w_cycle_missing_now_what.rs");
+    t.compile_fail("tests/ui/w_cycle_unknown_field.rs");
+}
@@ -0,0 +1,444 @@
+//! WORM-chain verification for hivemind-fence-view rows.
+//!
+//! Each row must satisfy:
+//! - `hexstamp == HiveMindFence::compute_hexstamp(row)`, and
+//! - `prev_hexstamp` equals the previous row's `hexstamp` (or the genesis
+//!   hexstamp for the first row).
+//!
+//! Rotated logs are verified as ordered segments. Verification is read-only
+//! and never mutates capability, consent, envelope, or policy state.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hivemind_fence_log::{fence_log_segments, HiveMindFenceLogConfig, HiveMindFenceView};
+use crate::hivemind_fence_view::HiveMindFence;
+
+/// First break found in a fence chain. `index` is the 0-based row position
+/// across the whole chain (all segments concatenated).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FenceChainError {
+    /// `prev_hexstamp` does not link to the previous row (or genesis).
+    BrokenLink { index: usize },
+    /// `hexstamp` does not match the recomputed content hash.
+    HexstampMismatch { index: usize },
+    /// The log could not be read at this row (or opened, at index 0).
+    Io { index: usize, message: String },
+    /// The line at this row is not a valid `HiveMindFenceView`.
+    MalformedRow { index: usize, message: String },
+}
+
+impl FenceChainError {
+    pub fn index(&self) -> usize {
+        match self {
+            FenceChainError::BrokenLink { index }
+            | FenceChainError::HexstampMismatch { index }
+            | FenceChainError::Io { index, .. }
+            | FenceChainError::MalformedRow { index, .. } => *index,
+        }
+    }
+
+    /// Ordering key matching the serial walk: lower rows first, and on the
+    /// same row the link is checked before the hexstamp.
+    #[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+    fn order_key(&self) -> (usize, u8) {
+        match self {
+            FenceChainError::Io { index, .. } | FenceChainError::MalformedRow { index, .. } => {
+                (*index, 0)
+            }
+            FenceChainError::BrokenLink { index } => (*index, 1),
+            FenceChainError::HexstampMismatch { index } => (*index, 2),
+        }
+    }
+}
+
+/// Aggregate result of a successful segment verification.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FenceChainStats {
+    pub segments: usize,
+    pub rows: usize,
+    /// Hexstamp of the final row, i.e. the head the next append must link to.
+    pub head_hexstamp: Option<String>,
+}
+
+/// Verify a single in-memory chain, returning the number of verified rows.
+pub fn verify_fence_chain(
+    views: &[HiveMindFenceView],
+    genesis_hexstamp: &str,
+) -> Result<usize, FenceChainError> {
+    let mut prev = genesis_hexstamp;
+    for (index, view) in views.iter().enumerate() {
+        check_row(view, prev, index)?;
+        prev = &view.hexstamp;
+    }
+    Ok(views.len())
+}
+
+/// Replay a hivemind-fence-view JSONL log from disk and verify its chain,
+/// returning the number of verified rows. Rows are streamed, not buffered;
+/// blank lines are skipped and do not count as rows.
+pub fn verify_fence_log(path: &Path, genesis_hexstamp: &str) -> Result<usize, FenceChainError> {
+    let mut prev = genesis_hexstamp.to_string();
+    let mut index = 0;
+    replay_log(path, &mut prev, &mut index)?;
+    Ok(index)
+}
+
+/// Replay every segment of a (possibly rotated) log in rotation order as one
+/// chain starting at `config.genesis_hexstamp`. Rows are streamed like
+/// `verify_fence_log`, and a malformed row in any segment is a
+/// `MalformedRow` error rather than being skipped.
+pub fn verify_fence_log_segments(
+    config: &HiveMindFenceLogConfig,
+) -> Result<FenceChainStats, FenceChainError> {
+    let paths = fence_log_segments(config);
+    let mut prev = config.genesis_hexstamp.clone();
+    let mut index = 0;
+    for path in &paths {
+        replay_log(path, &mut prev, &mut index)?;
+    }
+    Ok(FenceChainStats {
+        segments: paths.len(),
+        rows: index,
+        head_hexstamp: (index > 0).then_some(prev),
+    })
+}
+
+/// Stream one log file, continuing the chain from `prev` at row `index`.
+fn replay_log(path: &Path, prev: &mut String, index: &mut usize) -> Result<(), FenceChainError> {
+    let file = File::open(path).map_err(|e| FenceChainError::Io {
+        index: *index,
+        message: e.to_string(),
+    })?;
+
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| FenceChainError::Io {
+            index: *index,
+            message: e.to_string(),
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let view: HiveMindFenceView =
+            serde_json::from_str(&line).map_err(|e| FenceChainError::MalformedRow {
+                index: *index,
+                message: e.to_string(),
+            })?;
+        check_row(&view, prev, *index)?;
+        *prev = view.hexstamp;
+        *index += 1;
+    }
+    Ok(())
+}
+
+/// Serial verifier over rotated segments, in rotation order.
+pub fn verify_segments(
+    segments: &[Vec<HiveMindFenceView>],
+    genesis_hexstamp: &str,
+) -> Result<FenceChainStats, FenceChainError> {
+    let mut prev = genesis_hexstamp;
+    let mut index = 0;
+    for view in segments.iter().flatten() {
+        check_row(view, prev, index)?;
+        prev = &view.hexstamp;
+        index += 1;
+    }
+    Ok(stats(segments, index))
+}
+
+/// Parallel verifier over rotated segments.
+///
+/// Each segment is verified internally (hexstamps and intra-segment links) on
+/// the rayon pool; the O(segments) boundary links are then checked serially.
+/// Every row gets exactly the checks `verify_segments` performs, so the
+/// correctness guarantee is identical, and the reported error is the same
+/// first break the serial walk would hit.
+#[cfg(feature = "rayon")]
+pub fn verify_segments_parallel(
+    segments: &[Vec<HiveMindFenceView>],
+    genesis_hexstamp: &str,
+) -> Result<FenceChainStats, FenceChainError> {
+    use rayon::prelude::*;
+
+    let offsets = segment_offsets(segments);
+    let internal = segments
+        .par_iter()
+        .zip(offsets.par_iter())
+        .filter_map(|(segment, offset)| verify_segment_internal(segment, *offset).err())
+        .min_by_key(FenceChainError::order_key);
+
+    let boundary = verify_boundaries(segments, &offsets, genesis_hexstamp).err();
+
+    match (internal, boundary) {
+        (None, None) => Ok(stats(segments, segments.iter().map(Vec::len).sum())),
+        (Some(e), None) | (None, Some(e)) => Err(e),
+        (Some(a), Some(b)) => Err(if b.order_key() <= a.order_key() { b } else { a }),
+    }
+}
+
+fn check_row(view: &HiveMindFenceView, prev: &str, index: usize) -> Result<(), FenceChainError> {
+    if view.prev_hexstamp != prev {
+        return Err(FenceChainError::BrokenLink { index });
+    }
+    if HiveMindFence::compute_hexstamp(view) != view.hexstamp {
+        return Err(FenceChainError::HexstampMismatch { index });
+    }
+    Ok(())
+}
+
+/// Everything except the first row's `prev_hexstamp`, which is a boundary link.
+#[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+fn verify_segment_internal(
+    segment: &[HiveMindFenceView],
+    offset: usize,
+) -> Result<(), FenceChainError> {
+    for (i, view) in segment.iter().enumerate() {
+        if i > 0 && view.prev_hexstamp != segment[i - 1].hexstamp {
+            return Err(FenceChainError::BrokenLink { index: offset + i });
+        }
+        if HiveMindFence::compute_hexstamp(view) != view.hexstamp {
+            return Err(FenceChainError::HexstampMismatch { index: offset + i });
+        }
+    }
+    Ok(())
+}
+
+/// First row of each non-empty segment must link to the last row before it.
+#[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+fn verify_boundaries(
+    segments: &[Vec<HiveMindFenceView>],
+    offsets: &[usize],
+    genesis_hexstamp: &str,
+) -> Result<(), FenceChainError> {
+    let mut prev = genesis_hexstamp;
+    for (segment, offset) in segments.iter().zip(offsets) {
+        if let (Some(first), Some(last)) = (segment.first(), segment.last()) {
+            if first.prev_hexstamp != prev {
+                return Err(FenceChainError::BrokenLink { index: *offset });
+            }
+            prev = &last.hexstamp;
+        }
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+fn segment_offsets(segments: &[Vec<HiveMindFenceView>]) -> Vec<usize> {
+    segments
+        .iter()
+        .scan(0, |acc, s| {
+            let offset = *acc;
+            *acc += s.len();
+            Some(offset)
+        })
+        .collect()
+}
+
+fn stats(segments: &[Vec<HiveMindFenceView>], rows: usize) -> FenceChainStats {
+    FenceChainStats {
+        segments: segments.len(),
+        rows,
+        head_hexstamp: segments.iter().flatten().last().map(|v| v.hexstamp.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hivemind_fence_log::ProvenanceMode;
+    use crate::hivemind_fence_view::{HiveMindFenceConfig, HiveMindFenceInput};
+
+    const GENESIS: &str = "0xHMFENCE-GENESIS";
+
+    fn build_chain(rows: usize) -> Vec<HiveMindFenceView> {
+        let cfg = HiveMindFenceConfig::default();
+        let mut prev = GENESIS.to_string();
+        (0..rows)
+            .map(|i| {
+                let input = HiveMindFenceInput {
+                    view_id: format!("view-{}", i),
+                    subject_id: "subject-a".to_string(),
+                    cohort_id: Some("cohort-1".to_string()),
+                    epoch_index: i as i64,
+                    roh_score: 0.01 * (i % 30) as f32,
+                    tol_fear: Some(0.3),
+                    tol_pain: Some(0.2),
+                    tol_decay: Some(0.4),
+                    tol_lifeforce: Some(0.6),
+                    cohort_mean_fear: Some(0.25),
+                    cohort_mean_pain: Some(0.25),
+                    cohort_decay_gini: Some(0.1),
+                    cohort_fear_gini: None,
+                    cohort_pain_gini: None,
+                    prev_hexstamp: prev.clone(),
+                    anchor_id: None,
+                    timestamp_utc: "2026-01-01T00:00:00Z".to_string(),
+                    provenance_mode: ProvenanceMode::Live,
+                };
+                let view = HiveMindFence::evaluate(&cfg, &input);
+                prev = view.hexstamp.clone();
+                view
+            })
+            .collect()
+    }
+
+    fn split(chain: &[HiveMindFenceView], size: usize) -> Vec<Vec<HiveMindFenceView>> {
+        chain.chunks(size).map(|c| c.to_vec()).collect()
+    }
+
+    #[test]
+    fn test_serial_segments_match_single_chain() {
+        let chain = build_chain(10);
+        assert_eq!(verify_fence_chain(&chain, GENESIS), Ok(10));
+
+        let stats = verify_segments(&split(&chain, 4), GENESIS).unwrap();
+        assert_eq!(stats.segments, 3);
+        assert_eq!(stats.rows, 10);
+        assert_eq!(stats.head_hexstamp.as_deref(), Some(chain[9].hexstamp.as_str()));
+
+        let mut tampered = chain.clone();
+        tampered[6].roh_score = 0.29;
+        assert_eq!(
+            verify_segments(&split(&tampered, 4), GENESIS),
+            Err(FenceChainError::HexstampMismatch { index: 6 })
+        );
+    }
+
+    fn write_log(name: &str, views: &[HiveMindFenceView]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "hivemind-fence-{}-{}.jsonl",
+            name,
+            std::process::id()
+        ));
+        let body: String = views
+            .iter()
+            .map(|v| serde_json::to_string(v).unwrap() + "\n")
+            .collect();
+        std::fs::write(&path, body).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_verify_fence_log_replays_jsonl() {
+        let chain = build_chain(6);
+        let valid = write_log("valid", &chain);
+        assert_eq!(verify_fence_log(&valid, GENESIS), Ok(6));
+        assert_eq!(
+            verify_fence_log(&valid, "0xHMFENCE-OTHER"),
+            Err(FenceChainError::BrokenLink { index: 0 })
+        );
+
+        let mut tampered = chain.clone();
+        tampered[3].unfairdrain_flag = !tampered[3].unfairdrain_flag;
+        let tampered_path = write_log("tampered", &tampered);
+        assert_eq!(
+            verify_fence_log(&tampered_path, GENESIS),
+            Err(FenceChainError::HexstampMismatch { index: 3 })
+        );
+
+        // Re-stamped row with a forged link: payload hash is valid, linkage is not.
+        let mut relinked = chain.clone();
+        relinked[4].prev_hexstamp = "0xHMFENCE-FORGED".to_string();
+        relinked[4].hexstamp = HiveMindFence::compute_hexstamp(&relinked[4]);
+        let relinked_path = write_log("relinked", &relinked);
+        assert_eq!(
+            verify_fence_log(&relinked_path, GENESIS),
+            Err(FenceChainError::BrokenLink { index: 4 })
+        );
+
+        for path in [valid, tampered_path, relinked_path] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_legacy_log_verifies() {
+        // Rows written before `provenance_mode` existed; the last one carries
+        // a caller-supplied `anchor_id`, which its hexstamp covers.
+        let legacy = include_str!("../tests/fixtures/hivemind-fence-view-legacy.jsonl");
+        let views: Vec<HiveMindFenceView> = legacy
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(views.iter().all(|v| v.provenance_mode == ProvenanceMode::Live));
+        assert_eq!(verify_fence_chain(&views, GENESIS), Ok(3));
+        assert!(views[2].anchor_id.is_some());
+
+        // Re-serializing a live row reproduces the legacy bytes.
+        assert_eq!(serde_json::to_string(&views[0]).unwrap(), legacy.lines().next().unwrap());
+
+        // Non-live rows carry the field and it is covered by the hexstamp.
+        let mut simulated = build_chain(1);
+        simulated[0].provenance_mode = ProvenanceMode::Simulation;
+        let row = serde_json::to_string(&simulated[0]).unwrap();
+        assert!(row.contains("\"provenance_mode\":\"SIMULATION\""));
+        assert_eq!(
+            verify_fence_chain(&simulated, GENESIS),
+            Err(FenceChainError::HexstampMismatch { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_rotated_log_verifies_end_to_end() {
+        use crate::hivemind_fence_log::{append_hivemind_fence_view, fence_log_segment_path};
+
+        let dir = std::env::temp_dir().join(format!("hivemind-fence-rotate-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let chain = build_chain(6);
+        let row_bytes = serde_json::to_string(&chain[0]).unwrap().len() as u64 + 1;
+        let config = HiveMindFenceLogConfig {
+            storage_path: dir.join("hivemind-fence-view.jsonl").to_string_lossy().into_owned(),
+            genesis_hexstamp: GENESIS.to_string(),
+            // Room for two rows per segment, not three.
+            max_bytes: Some(row_bytes * 2 + row_bytes / 2),
+        };
+        for view in &chain {
+            append_hivemind_fence_view(&config, view).unwrap();
+        }
+
+        let paths = fence_log_segments(&config);
+        assert_eq!(paths.len(), 3);
+        assert!(paths[2].ends_with("hivemind-fence-view.2.jsonl"));
+
+        let stats = verify_fence_log_segments(&config).unwrap();
+        assert_eq!((stats.segments, stats.rows), (3, 6));
+        assert_eq!(stats.head_hexstamp.as_deref(), Some(chain[5].hexstamp.as_str()));
+
+        // A corrupt row in the middle segment fails at its chain position.
+        let middle = fence_log_segment_path(Path::new(&config.storage_path), 1);
+        let text = std::fs::read_to_string(&middle).unwrap();
+        std::fs::write(&middle, text.replacen('{', "{{", 1)).unwrap();
+        assert!(matches!(
+            verify_fence_log_segments(&config),
+            Err(FenceChainError::MalformedRow { index: 2, .. })
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_serial_on_multi_segment_log() {
+        let chain = build_chain(64);
+        let segments = split(&chain, 8);
+        assert_eq!(
+            verify_segments_parallel(&segments, GENESIS),
+            verify_segments(&segments, GENESIS)
+        );
+        assert_eq!(verify_segments_parallel(&segments, GENESIS).unwrap().rows, 64);
+
+        // A tampered payload late in the log and a broken boundary link earlier:
+        // both verifiers must report the earlier break.
+        let mut tampered = chain.clone();
+        tampered[50].roh_score = 0.29;
+        tampered[24].prev_hexstamp = "0xHMFENCE-FORGED".to_string();
+        tampered[24].hexstamp = HiveMindFence::compute_hexstamp(&tampered[24]);
+        let segments = split(&tampered, 8);
+        let serial = verify_segments(&segments, GENESIS);
+        assert_eq!(serial, Err(FenceChainError::BrokenLink { index: 24 }));
+        assert_eq!(verify_segments_parallel(&segments, GENESIS), serial);
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use std::io::{BufWriter, Write};
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
 use std::fs::{File, OpenOptions};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HiveMindFenceView {
@@ -26,9 +26,47 @@ pub struct HiveMindFenceView {
     pub prev_hexstamp: String,
     pub hexstamp: String,
     pub anchor_id: Option<String>,
+    /// Omitted from the serialized row when `Live`, so live rows (and their
+    /// hexstamps) are byte-identical to rows written before this field existed.
+    #[serde(default, skip_serializing_if = "ProvenanceMode::is_live")]
+    pub provenance_mode: ProvenanceMode,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// Where a record came from; mirrors `neuroprint_core::log::ProvenanceMode`.
+/// Rows written before this field existed were produced by live sessions,
+/// so it defaults to `Live`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProvenanceMode {
+    Simulation,
+    LabBench,
+    #[default]
+    Live,
+}
+
+impl ProvenanceMode {
+    pub fn is_live(&self) -> bool {
+        *self == ProvenanceMode::Live
+    }
+}
+
+/// Live-session views only; use before computing live cohort statistics.
+pub fn live_only(views: &[HiveMindFenceView]) -> Vec<&HiveMindFenceView> {
+    views
+        .iter()
+        .filter(|v| v.provenance_mode == ProvenanceMode::Live)
+        .collect()
+}
+
+/// Simulation views only.
+pub fn simulation_only(views: &[HiveMindFenceView]) -> Vec<&HiveMindFenceView> {
+    views
+        .iter()
+        .filter(|v| v.provenance_mode == ProvenanceMode::Simulation)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum FenceState {
     Info,
@@ -43,6 +81,10 @@ pub struct HiveMindFenceLogConfig {
     pub storage_path: String,
     /// Genesis prev_hexstamp for the first row, e.g., "0xHMFENCE-GENESIS".
     pub genesis_hexstamp: String,
+    /// Rotate to a new segment when the active one would grow past this many
+    /// bytes. `None` (the default) never rotates.
+    #[serde(default)]
+    pub max_bytes: Option<u64>,
 }
 
 /// Result type for log append operations.
@@ -50,6 +92,70 @@ pub struct HiveMindFenceLogConfig {
 pub enum HiveMindFenceLogError {
     IoError(String),
     SerializationError(String),
+    Anchor(AnchorError),
+}
+
+/// Failure reported by an `Anchor` backend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AnchorError {
+    IoError(String),
+    Rejected(String),
+}
+
+/// External anchoring backend (e.g. a Googolswarm transaction) for fence
+/// view hexstamps. Returns the id recorded in a `FenceAnchorRecord`.
+pub trait Anchor {
+    fn anchor(&self, hexstamp: &str) -> Result<String, AnchorError>;
+}
+
+/// Anchor that posts nothing; the anchor id is the hexstamp itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullAnchor;
+
+impl Anchor for NullAnchor {
+    fn anchor(&self, hexstamp: &str) -> Result<String, AnchorError> {
+        Ok(hexstamp.to_string())
+    }
+}
+
+/// Anchor that appends each hexstamp as a line to a local file and returns
+/// `file:<path>@<byte offset>`. Intended for tests and offline runs.
+#[derive(Debug, Clone)]
+pub struct FileAnchor {
+    path: PathBuf,
+}
+
+impl FileAnchor {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Anchor for FileAnchor {
+    fn anchor(&self, hexstamp: &str) -> Result<String, AnchorError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| AnchorError::IoError(e.to_string()))?;
+        let offset = file
+            .metadata()
+            .map_err(|e| AnchorError::IoError(e.to_string()))?
+            .len();
+        writeln!(file, "{}", hexstamp).map_err(|e| AnchorError::IoError(e.to_string()))?;
+        Ok(format!("file:{}@{}", self.path.display(), offset))
+    }
+}
+
+/// Backend anchor for one appended fence row. Kept in a separate anchor log
+/// (`fence_anchor_log_path`) rather than on the row: the id is derived from
+/// the row's hexstamp, so it cannot be part of the hashed chain itself.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FenceAnchorRecord {
+    pub view_id: String,
+    /// Hexstamp of the anchored row; links the record back into the chain.
+    pub hexstamp: String,
+    pub anchor_id: String,
 }
 
 /// Append a single HIVEMIND-FENCE view to the WORM JSONL log.
@@ -59,13 +165,24 @@ pub enum HiveMindFenceLogError {
 /// - `view.hexstamp` has been computed as H(payload_without_hexes || prev_hexstamp).
 /// - `view.prev_hexstamp` is either the prior row's hexstamp or `config.genesis_hexstamp`.
 ///
+/// With `config.max_bytes` set, a row that would push the active segment past
+/// the limit opens the next segment instead (see `fence_log_segment_path`).
+/// Closed segments are never reopened, renamed, or rewritten; the first row of
+/// a new segment links to the last row of the previous one through its own
+/// `prev_hexstamp`, so the chain spans the rotation boundary. A single row
+/// larger than `max_bytes` still gets its own segment rather than being split.
+///
 /// This function never mutates capability, consent, envelope, or policy state.
 /// It only appends a serialized line to the hivemind-fence-view.jsonl log.
 pub fn append_hivemind_fence_view(
     config: &HiveMindFenceLogConfig,
     view: &HiveMindFenceView,
 ) -> Result<(), HiveMindFenceLogError> {
-    let path = Path::new(&config.storage_path);
+    let mut line = serde_json::to_string(view)
+        .map_err(|e| HiveMindFenceLogError::SerializationError(e.to_string()))?;
+    line.push('\n');
+
+    let path = active_segment(config, line.len() as u64)?;
 
     let file = OpenOptions::new()
         .create(true)
@@ -75,11 +192,264 @@ pub fn append_hivemind_fence_view(
 
     let mut writer = BufWriter::new(file);
 
-    let json = serde_json::to_string(view)
-        .map_err(|e| HiveMindFenceLogError::SerializationError(e.to_string()))?;
-
     writer
-        .write_all(json.as_bytes())
-        .and_then(|_| writer.write_all(b"\n"))
+        .write_all(line.as_bytes())
+        .and_then(|_| writer.flush())
+        .map_err(|e| HiveMindFenceLogError::IoError(e.to_string()))
+}
+
+/// Path of rotation segment `n`: segment 0 is `storage_path` itself, later
+/// segments insert `.n` before the extension, e.g.
+/// `hivemind-fence-view.jsonl` -> `hivemind-fence-view.2.jsonl`.
+pub fn fence_log_segment_path(storage_path: &Path, n: usize) -> PathBuf {
+    if n == 0 {
+        return storage_path.to_path_buf();
+    }
+    let stem = storage_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match storage_path.extension() {
+        Some(ext) => format!("{}.{}.{}", stem, n, ext.to_string_lossy()),
+        None => format!("{}.{}", stem, n),
+    };
+    storage_path.with_file_name(name)
+}
+
+/// Anchor log next to `storage_path`, e.g. `hivemind-fence-view.jsonl` ->
+/// `hivemind-fence-view.anchors.jsonl`. Never rotated.
+pub fn fence_anchor_log_path(storage_path: &Path) -> PathBuf {
+    let stem = storage_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match storage_path.extension() {
+        Some(ext) => format!("{}.anchors.{}", stem, ext.to_string_lossy()),
+        None => format!("{}.anchors", stem),
+    };
+    storage_path.with_file_name(name)
+}
+
+/// Append one `FenceAnchorRecord` to the anchor log (append-only, like the
+/// fence log itself).
+pub fn append_fence_anchor_record(
+    config: &HiveMindFenceLogConfig,
+    record: &FenceAnchorRecord,
+) -> Result<(), HiveMindFenceLogError> {
+    let mut line = serde_json::to_string(record)
+        .map_err(|e| HiveMindFenceLogError::SerializationError(e.to_string()))?;
+    line.push('\n');
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(fence_anchor_log_path(Path::new(&config.storage_path)))
+        .map_err(|e| HiveMindFenceLogError::IoError(e.to_string()))?;
+    file.write_all(line.as_bytes())
         .map_err(|e| HiveMindFenceLogError::IoError(e.to_string()))
 }
+
+/// Every anchor record for the log, in append order. A missing anchor log
+/// means nothing was anchored yet.
+pub fn read_fence_anchor_records(
+    config: &HiveMindFenceLogConfig,
+) -> Result<Vec<FenceAnchorRecord>, HiveMindFenceLogError> {
+    let path = fence_anchor_log_path(Path::new(&config.storage_path));
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(HiveMindFenceLogError::IoError(e.to_string())),
+    };
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line)
+                .map_err(|e| HiveMindFenceLogError::SerializationError(e.to_string()))
+        })
+        .collect()
+}
+
+/// Existing segments of the log in rotation order (chain order), stopping
+/// at the first missing index.
+pub fn fence_log_segments(config: &HiveMindFenceLogConfig) -> Vec<PathBuf> {
+    let base = Path::new(&config.storage_path);
+    (0..)
+        .map(|n| fence_log_segment_path(base, n))
+        .take_while(|p| p.exists())
+        .collect()
+}
+
+/// Segment the next `incoming` bytes should be appended to.
+fn active_segment(
+    config: &HiveMindFenceLogConfig,
+    incoming: u64,
+) -> Result<PathBuf, HiveMindFenceLogError> {
+    let base = Path::new(&config.storage_path);
+    let segments = fence_log_segments(config);
+    let Some(last) = segments.last() else {
+        return Ok(base.to_path_buf());
+    };
+    let Some(max_bytes) = config.max_bytes else {
+        return Ok(last.clone());
+    };
+    let size = std::fs::metadata(last)
+        .map_err(|e| HiveMindFenceLogError::IoError(e.to_string()))?
+        .len();
+    if size > 0 && size + incoming > max_bytes {
+        Ok(fence_log_segment_path(base, segments.len()))
+    } else {
+        Ok(last.clone())
+    }
+}
+
+/// A log line `FenceViewIter` skipped because it is not a valid view.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FenceViewWarning {
+    /// 1-based line number in the log.
+    pub line: usize,
+    pub message: String,
+}
+
+/// Streaming reader over a hivemind-fence-view JSONL log: one view per line,
+/// read lazily. Blank lines are ignored; malformed lines are skipped and
+/// recorded in `warnings()`. An I/O error is yielded once and ends iteration.
+pub struct FenceViewIter<R> {
+    lines: Lines<R>,
+    line: usize,
+    done: bool,
+    warnings: Vec<FenceViewWarning>,
+}
+
+impl FenceViewIter<BufReader<File>> {
+    pub fn open(path: &Path) -> Result<Self, HiveMindFenceLogError> {
+        let file = File::open(path).map_err(|e| HiveMindFenceLogError::IoError(e.to_string()))?;
+        Ok(Self::new(BufReader::new(file)))
+    }
+}
+
+impl<R: BufRead> FenceViewIter<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line: 0,
+            done: false,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Malformed lines skipped so far.
+    pub fn warnings(&self) -> &[FenceViewWarning] {
+        &self.warnings
+    }
+}
+
+impl<R: BufRead> Iterator for FenceViewIter<R> {
+    type Item = Result<HiveMindFenceView, HiveMindFenceLogError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line += 1;
+            let text = match self.lines.next()? {
+                Ok(text) => text,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(HiveMindFenceLogError::IoError(e.to_string())));
+                }
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&text) {
+                Ok(view) => return Some(Ok(view)),
+                Err(e) => self.warnings.push(FenceViewWarning {
+                    line: self.line,
+                    message: e.to_string(),
+                }),
+            }
+        }
+        None
+    }
+}
+
+/// Read every well-formed view from a hivemind-fence-view JSONL log.
+/// Malformed lines are skipped; use `FenceViewIter` to see which ones.
+pub fn read_fence_views(path: &Path) -> Result<Vec<HiveMindFenceView>, HiveMindFenceLogError> {
+    FenceViewIter::open(path)?.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(epoch_index: i64) -> HiveMindFenceView {
+        HiveMindFenceView {
+            view_id: format!("view-{}", epoch_index),
+            subject_id: "subject-a".to_string(),
+            cohort_id: None,
+            epoch_index,
+            roh_score: 0.1,
+            unfairdrain_index: None,
+            unfairfear_index: None,
+            unfairpain_index: None,
+            cohort_decay_gini: None,
+            cohort_fear_gini: None,
+            cohort_pain_gini: None,
+            subject_unfairdrain_state: None,
+            subject_unfairstress_state: None,
+            cohort_balance_state: None,
+            unfairdrain_flag: false,
+            collective_imbalance_flag: false,
+            cohort_cooldown_advised: false,
+            timestamp_utc: "2026-01-01T00:00:00Z".to_string(),
+            prev_hexstamp: "0xHMFENCE-GENESIS".to_string(),
+            hexstamp: format!("0xHMFENCE-{}", epoch_index),
+            anchor_id: None,
+            provenance_mode: ProvenanceMode::Live,
+        }
+    }
+
+    fn temp_log(name: &str, body: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "hivemind-fence-read-{}-{}.jsonl",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, body).unwrap();
+        path
+    }
+
+    fn row(epoch_index: i64) -> String {
+        serde_json::to_string(&view(epoch_index)).unwrap() + "\n"
+    }
+
+    #[test]
+    fn test_read_fence_views_multi_row_with_blank_trailing_line() {
+        let path = temp_log("rows", &format!("{}{}{}\n", row(0), row(1), row(2)));
+        let views = read_fence_views(&path).unwrap();
+        assert_eq!(
+            views.iter().map(|v| v.epoch_index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        std::fs::remove_file(path).unwrap();
+
+        assert!(matches!(
+            read_fence_views(Path::new("/nonexistent/hivemind-fence-view.jsonl")),
+            Err(HiveMindFenceLogError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn test_fence_view_iter_skips_malformed_row_with_warning() {
+        let path = temp_log(
+            "malformed",
+            &format!("{}{{\"view_id\": truncated\n{}", row(0), row(2)),
+        );
+        let mut iter = FenceViewIter::open(&path).unwrap();
+        let epochs: Vec<i64> = iter.by_ref().map(|v| v.unwrap().epoch_index).collect();
+        assert_eq!(epochs, vec![0, 2]);
+        assert_eq!(iter.warnings().len(), 1);
+        assert_eq!(iter.warnings()[0].line, 2);
+
+        assert_eq!(read_fence_views(&path).unwrap().len(), 2);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::hivemind_fence_log::{
-    append_hivemind_fence_view, FenceState, HiveMindFenceLogConfig, HiveMindFenceLogError,
-    HiveMindFenceView,
+    append_fence_anchor_record, append_hivemind_fence_view, Anchor, FenceAnchorRecord, FenceState,
+    HiveMindFenceLogConfig, HiveMindFenceLogError, HiveMindFenceView, ProvenanceMode,
 };
 
+/// Number of most recent epochs `classify_with_trend` measures the slope over.
+pub const FENCE_TREND_WINDOW: usize = 4;
+
 /// Minimal, readonly snapshot input for HIVEMIND-FENCE.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HiveMindFenceInput {
@@ -32,6 +35,9 @@ pub struct HiveMindFenceInput {
     pub anchor_id: Option<String>,
     /// ISO-8601 UTC timestamp, provided by caller.
     pub timestamp_utc: String,
+    /// Simulation / lab-bench / live origin, copied onto the view.
+    #[serde(default)]
+    pub provenance_mode: ProvenanceMode,
 }
 
 /// Advisory-only threshold configuration for HIVEMIND-FENCE indices.
@@ -60,6 +66,73 @@ impl Default for HiveMindFenceConfig {
     }
 }
 
+/// Smoothing configuration for cohort cooldown advice.
+///
+/// With both windows at 1 the advisor reproduces the stateless per-row
+/// `roh_score >= roh_cooldown_threshold` check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CooldownAdvisorConfig {
+    /// Consecutive epochs at or above threshold required before advising cooldown.
+    pub sustain_epochs: u32,
+    /// Consecutive epochs below threshold required before rescinding advice.
+    pub release_epochs: u32,
+}
+
+impl Default for CooldownAdvisorConfig {
+    fn default() -> Self {
+        Self {
+            sustain_epochs: 3,
+            release_epochs: 3,
+        }
+    }
+}
+
+/// Stateful, advisory-only debouncer for `cohort_cooldown_advised`.
+///
+/// Holds only its own streak counters; it never reads or writes capability,
+/// consent, or envelope state. One advisor should be kept per cohort stream.
+#[derive(Debug, Clone)]
+pub struct CooldownAdvisor {
+    cfg: CooldownAdvisorConfig,
+    advised: bool,
+    above_streak: u32,
+    below_streak: u32,
+}
+
+impl CooldownAdvisor {
+    pub fn new(cfg: CooldownAdvisorConfig) -> Self {
+        Self {
+            cfg,
+            advised: false,
+            above_streak: 0,
+            below_streak: 0,
+        }
+    }
+
+    /// Current smoothed advice without observing a new epoch.
+    pub fn advised(&self) -> bool {
+        self.advised
+    }
+
+    /// Feed one epoch's RoH score and return the smoothed advice.
+    pub fn observe(&mut self, roh_score: f32, threshold: f32) -> bool {
+        if roh_score >= threshold {
+            self.above_streak = self.above_streak.saturating_add(1);
+            self.below_streak = 0;
+            if !self.advised && self.above_streak >= self.cfg.sustain_epochs.max(1) {
+                self.advised = true;
+            }
+        } else {
+            self.below_streak = self.below_streak.saturating_add(1);
+            self.above_streak = 0;
+            if self.advised && self.below_streak >= self.cfg.release_epochs.max(1) {
+                self.advised = false;
+            }
+        }
+        self.advised
+    }
+}
+
 /// Pure evaluator namespace for HIVEMIND-FENCE.
 pub struct HiveMindFence;
 
@@ -67,14 +140,79 @@ impl HiveMindFence {
     /// Compute a HiveMindFenceView from a snapshot and thresholds,
     /// then append it to the hivemind-fence-view JSONL WORM log.
     ///
+    /// With `anchor`, the hexstamp is anchored once the row is appended, and
+    /// the returned id is written as a `FenceAnchorRecord` to the anchor log
+    /// (see `append_fence_anchor_record`). An anchor failure is returned as
+    /// `HiveMindFenceLogError::Anchor`; the row stays in the log unanchored
+    /// and can be anchored later.
+    ///
     /// Invariants:
     /// - No capability, consent, or envelope state is mutated.
     /// - hexstamp is a content hash over the view payload plus prev_hexstamp.
+    /// - The backend anchor id never enters the chain row, so anchoring does
+    ///   not change any hexstamp.
     pub fn evaluate_and_log(
         log_cfg: &HiveMindFenceLogConfig,
         cfg: &HiveMindFenceConfig,
         input: &HiveMindFenceInput,
+        anchor: Option<&dyn Anchor>,
+    ) -> Result<(), HiveMindFenceLogError> {
+        let view = Self::evaluate(cfg, input);
+        Self::anchor_and_append(log_cfg, view, anchor)
+    }
+
+    /// Same as `evaluate_and_log`, but `cohort_cooldown_advised` uses the
+    /// smoothed RoH advice from `advisor` instead of the per-row threshold.
+    /// Collective imbalance still advises cooldown immediately.
+    pub fn evaluate_and_log_smoothed(
+        log_cfg: &HiveMindFenceLogConfig,
+        cfg: &HiveMindFenceConfig,
+        input: &HiveMindFenceInput,
+        advisor: &mut CooldownAdvisor,
+        anchor: Option<&dyn Anchor>,
+    ) -> Result<(), HiveMindFenceLogError> {
+        let view = Self::evaluate_smoothed(cfg, input, advisor);
+        Self::anchor_and_append(log_cfg, view, anchor)
+    }
+
+    fn anchor_and_append(
+        log_cfg: &HiveMindFenceLogConfig,
+        view: HiveMindFenceView,
+        anchor: Option<&dyn Anchor>,
     ) -> Result<(), HiveMindFenceLogError> {
+        append_hivemind_fence_view(log_cfg, &view)?;
+        let Some(anchor) = anchor else {
+            return Ok(());
+        };
+        let anchor_id = anchor
+            .anchor(&view.hexstamp)
+            .map_err(HiveMindFenceLogError::Anchor)?;
+        append_fence_anchor_record(
+            log_cfg,
+            &FenceAnchorRecord {
+                view_id: view.view_id,
+                hexstamp: view.hexstamp,
+                anchor_id,
+            },
+        )
+    }
+
+    /// Smoothed variant of `evaluate`; see `evaluate_and_log_smoothed`.
+    pub fn evaluate_smoothed(
+        cfg: &HiveMindFenceConfig,
+        input: &HiveMindFenceInput,
+        advisor: &mut CooldownAdvisor,
+    ) -> HiveMindFenceView {
+        let mut view = Self::evaluate(cfg, input);
+        let roh_advised = advisor.observe(input.roh_score, cfg.roh_cooldown_threshold);
+        view.cohort_cooldown_advised = roh_advised || view.collective_imbalance_flag;
+        view.hexstamp = Self::compute_hexstamp(&view);
+        view
+    }
+
+    /// Compute a HiveMindFenceView from a snapshot and thresholds without logging.
+    /// Stateless: each row is evaluated on its own.
+    pub fn evaluate(cfg: &HiveMindFenceConfig, input: &HiveMindFenceInput) -> HiveMindFenceView {
         let unfairdrain_index =
             Self::compute_unfairdrain_index(input.tol_decay, input.tol_lifeforce);
         let (unfairfear_index, unfairpain_index) =
@@ -127,11 +265,11 @@ impl HiveMindFence {
             prev_hexstamp: input.prev_hexstamp.clone(),
             hexstamp: String::new(), // filled below
             anchor_id: input.anchor_id.clone(),
+            provenance_mode: input.provenance_mode,
         };
 
         view.hexstamp = Self::compute_hexstamp(&view);
-
-        append_hivemind_fence_view(log_cfg, &view)
+        view
     }
 
     /// Subject-level unfair drain index, normalized to 0.0..=1.0.
@@ -190,6 +328,34 @@ impl HiveMindFence {
         }
     }
 
+    /// Trend-aware variant of `classify_fence_state` over a per-epoch index
+    /// history (oldest first, latest last). The latest value is classified
+    /// against the static thresholds; if the mean per-epoch rise over the
+    /// last `FENCE_TREND_WINDOW` values exceeds `escalate_slope`, the state is
+    /// raised one level (Info -> Warn -> Risk). An empty history is Info.
+    pub fn classify_with_trend(
+        history: &[f32],
+        warn: f32,
+        risk: f32,
+        escalate_slope: f32,
+    ) -> FenceState {
+        let base = Self::classify_fence_state(history.last().copied(), warn, risk)
+            .unwrap_or(FenceState::Info);
+
+        let window = &history[history.len().saturating_sub(FENCE_TREND_WINDOW)..];
+        if window.len() < 2 {
+            return base;
+        }
+        let slope = (window[window.len() - 1] - window[0]) / (window.len() - 1) as f32;
+        if slope.is_nan() || slope <= escalate_slope {
+            return base;
+        }
+        match base {
+            FenceState::Info => FenceState::Warn,
+            FenceState::Warn | FenceState::Risk => FenceState::Risk,
+        }
+    }
+
     fn max_opt(a: Option<f32>, b: Option<f32>) -> Option<f32> {
         match (a, b) {
             (Some(x), Some(y)) => Some(x.max(y)),
@@ -208,18 +374,12 @@ impl HiveMindFence {
     }
 
     fn clamp01(x: f32) -> f32 {
-        if x < 0.0 {
-            0.0
-        } else if x > 1.0 {
-            1.0
-        } else {
-            x
-        }
+        x.clamp(0.0, 1.0)
     }
 
     /// Deterministic hexstamp over view content plus prev_hexstamp, with no I/O.
     /// Placeholder: wire to your existing hexstamp/H() utility in sovereignty core.
-    fn compute_hexstamp(view: &HiveMindFenceView) -> String {
+    pub(crate) fn compute_hexstamp(view: &HiveMindFenceView) -> String {
         use blake3::Hasher;
 
         let mut hasher = Hasher::new();
@@ -238,3 +398,205 @@ impl HiveMindFence {
         format!("0xHMFENCE{}", hash.to_hex())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_with_roh(epoch_index: i64, roh_score: f32) -> HiveMindFenceInput {
+        HiveMindFenceInput {
+            view_id: format!("view-{}", epoch_index),
+            subject_id: "subject-a".to_string(),
+            cohort_id: Some("cohort-1".to_string()),
+            epoch_index,
+            roh_score,
+            tol_fear: None,
+            tol_pain: None,
+            tol_decay: None,
+            tol_lifeforce: None,
+            cohort_mean_fear: None,
+            cohort_mean_pain: None,
+            cohort_decay_gini: None,
+            cohort_fear_gini: None,
+            cohort_pain_gini: None,
+            prev_hexstamp: "0xHMFENCE-GENESIS".to_string(),
+            anchor_id: None,
+            timestamp_utc: "2026-01-01T00:00:00Z".to_string(),
+            provenance_mode: ProvenanceMode::Live,
+        }
+    }
+
+    #[test]
+    fn test_cooldown_advisor_stable_under_oscillation() {
+        let cfg = HiveMindFenceConfig::default();
+        let mut advisor = CooldownAdvisor::new(CooldownAdvisorConfig {
+            sustain_epochs: 3,
+            release_epochs: 3,
+        });
+
+        // Alternates just above / just below the 0.25 threshold.
+        let series = [0.24, 0.26, 0.24, 0.26, 0.24, 0.26, 0.24, 0.26];
+        let mut stateless = Vec::new();
+        let mut smoothed = Vec::new();
+        for (i, roh) in series.iter().enumerate() {
+            let input = input_with_roh(i as i64, *roh);
+            stateless.push(HiveMindFence::evaluate(&cfg, &input).cohort_cooldown_advised);
+            smoothed.push(
+                HiveMindFence::evaluate_smoothed(&cfg, &input, &mut advisor)
+                    .cohort_cooldown_advised,
+            );
+        }
+
+        // The stateless per-row advice chatters on every epoch...
+        assert!(stateless.windows(2).all(|w| w[0] != w[1]));
+        // ...while the advisor never advises on a non-sustained excursion.
+        assert!(smoothed.iter().all(|a| !a));
+    }
+
+    #[test]
+    fn test_trend_flat_series_keeps_static_state() {
+        let flat = [0.10, 0.10, 0.10, 0.10, 0.10];
+        assert_eq!(HiveMindFence::classify_with_trend(&flat, 0.15, 0.30, 0.02), FenceState::Info);
+        assert_eq!(HiveMindFence::classify_with_trend(&[], 0.15, 0.30, 0.02), FenceState::Info);
+        assert_eq!(HiveMindFence::classify_with_trend(&[0.2], 0.15, 0.30, 0.02), FenceState::Warn);
+    }
+
+    #[test]
+    fn test_trend_sharp_rise_below_threshold_escalates() {
+        // Still below warn, but rising 0.04/epoch over the window.
+        let rising = [0.00, 0.02, 0.06, 0.10, 0.14];
+        assert_eq!(HiveMindFence::classify_with_trend(&rising, 0.15, 0.30, 0.02), FenceState::Warn);
+        // Already Warn and rising: one level up, to Risk.
+        let warn_rising = [0.10, 0.14, 0.18, 0.22];
+        assert_eq!(
+            HiveMindFence::classify_with_trend(&warn_rising, 0.15, 0.30, 0.02),
+            FenceState::Risk
+        );
+        // An old spike outside the window does not count as a trend.
+        let old_rise = [0.00, 0.14, 0.14, 0.14, 0.14];
+        assert_eq!(HiveMindFence::classify_with_trend(&old_rise, 0.15, 0.30, 0.02), FenceState::Info);
+    }
+
+    #[test]
+    fn test_trend_already_at_risk_stays_risk() {
+        let at_risk = [0.31, 0.30, 0.32, 0.35];
+        assert_eq!(HiveMindFence::classify_with_trend(&at_risk, 0.15, 0.30, 0.02), FenceState::Risk);
+        let falling = [0.40, 0.36, 0.33, 0.31];
+        assert_eq!(HiveMindFence::classify_with_trend(&falling, 0.15, 0.30, 0.02), FenceState::Risk);
+    }
+
+    #[test]
+    fn test_evaluate_and_log_records_anchor_id() {
+        use crate::hivemind_fence_chain::{verify_fence_chain, verify_fence_log};
+        use crate::hivemind_fence_log::{
+            fence_anchor_log_path, read_fence_anchor_records, read_fence_views, FileAnchor,
+            NullAnchor,
+        };
+
+        let dir = std::env::temp_dir();
+        let log_path = dir.join(format!("hivemind-fence-anchor-{}.jsonl", std::process::id()));
+        let anchor_path = dir.join(format!("hivemind-fence-anchor-{}.txt", std::process::id()));
+        let records_path = fence_anchor_log_path(&log_path);
+        for path in [&log_path, &anchor_path, &records_path] {
+            let _ = std::fs::remove_file(path);
+        }
+        let log_cfg = HiveMindFenceLogConfig {
+            storage_path: log_path.to_string_lossy().into_owned(),
+            genesis_hexstamp: "0xHMFENCE-GENESIS".to_string(),
+            max_bytes: None,
+        };
+        let cfg = HiveMindFenceConfig::default();
+        let file_anchor = FileAnchor::new(&anchor_path);
+
+        let first = input_with_roh(0, 0.1);
+        HiveMindFence::evaluate_and_log(&log_cfg, &cfg, &first, None).unwrap();
+        let mut second = input_with_roh(1, 0.1);
+        second.prev_hexstamp = HiveMindFence::evaluate(&cfg, &first).hexstamp;
+        HiveMindFence::evaluate_and_log(&log_cfg, &cfg, &second, Some(&file_anchor)).unwrap();
+        let mut third = input_with_roh(2, 0.1);
+        third.prev_hexstamp = HiveMindFence::evaluate(&cfg, &second).hexstamp;
+        third.anchor_id = Some("googolswarm-tx-3".to_string());
+        HiveMindFence::evaluate_and_log(&log_cfg, &cfg, &third, Some(&NullAnchor)).unwrap();
+
+        // Rows are exactly what `evaluate` produced; anchoring never rewrites them.
+        let views = read_fence_views(&log_path).unwrap();
+        assert_eq!(views[1].hexstamp, HiveMindFence::evaluate(&cfg, &second).hexstamp);
+        assert_eq!(views[1].anchor_id, None);
+        assert_eq!(views[2].anchor_id.as_deref(), Some("googolswarm-tx-3"));
+
+        let records = read_fence_anchor_records(&log_cfg).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].view_id, "view-1");
+        assert_eq!(records[0].hexstamp, views[1].hexstamp);
+        assert_eq!(records[0].anchor_id, format!("file:{}@0", anchor_path.display()));
+        assert_eq!(records[1].anchor_id, views[2].hexstamp);
+        assert_eq!(
+            std::fs::read_to_string(&anchor_path).unwrap(),
+            format!("{}\n", views[1].hexstamp)
+        );
+        assert_eq!(verify_fence_log(&log_path, "0xHMFENCE-GENESIS"), Ok(3));
+
+        // The caller-supplied anchor_id is covered by the hexstamp.
+        let mut forged = views.clone();
+        forged[2].anchor_id = Some("googolswarm-tx-forged".to_string());
+        assert!(verify_fence_chain(&forged, "0xHMFENCE-GENESIS").is_err());
+
+        for path in [log_path, anchor_path, records_path] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_failed_anchor_leaves_row_appended_without_record() {
+        use crate::hivemind_fence_chain::verify_fence_log;
+        use crate::hivemind_fence_log::{fence_anchor_log_path, AnchorError};
+
+        struct RejectingAnchor;
+        impl Anchor for RejectingAnchor {
+            fn anchor(&self, _hexstamp: &str) -> Result<String, AnchorError> {
+                Err(AnchorError::Rejected("backend offline".to_string()))
+            }
+        }
+
+        let log_path = std::env::temp_dir()
+            .join(format!("hivemind-fence-anchor-fail-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&log_path);
+        let log_cfg = HiveMindFenceLogConfig {
+            storage_path: log_path.to_string_lossy().into_owned(),
+            genesis_hexstamp: "0xHMFENCE-GENESIS".to_string(),
+            max_bytes: None,
+        };
+        let result = HiveMindFence::evaluate_and_log(
+            &log_cfg,
+            &HiveMindFenceConfig::default(),
+            &input_with_roh(0, 0.1),
+            Some(&RejectingAnchor),
+        );
+        assert!(matches!(
+            result,
+            Err(HiveMindFenceLogError::Anchor(AnchorError::Rejected(_)))
+        ));
+        assert_eq!(verify_fence_log(&log_path, "0xHMFENCE-GENESIS"), Ok(1));
+        assert!(!fence_anchor_log_path(&log_path).exists());
+
+        std::fs::remove_file(log_path).unwrap();
+    }
+
+    #[test]
+    fn test_cooldown_advisor_sustain_and_release() {
+        let mut advisor = CooldownAdvisor::new(CooldownAdvisorConfig {
+            sustain_epochs: 2,
+            release_epochs: 3,
+        });
+        let threshold = 0.25;
+
+        assert!(!advisor.observe(0.27, threshold));
+        assert!(advisor.observe(0.28, threshold));
+        // A single dip does not rescind advice.
+        assert!(advisor.observe(0.20, threshold));
+        assert!(advisor.observe(0.26, threshold));
+        assert!(advisor.observe(0.20, threshold));
+        assert!(advisor.observe(0.20, threshold));
+        assert!(!advisor.observe(0.20, threshold));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod hivemind_fence_chain;
+pub mod hivemind_fence_log;
+pub mod hivemind_fence_view;
@@ -11,5 +11,305 @@ pub enum DecisionReason {
     DeniedRoHViolation,
     // New, explicit code for permanently disabled reversals:
     DeniedNeuromorphReversalProhibited,
+    /// Requested drop spans more tiers than `max_downgrade_tiers` allows.
+    DeniedDowngradeTooLarge,
+    /// A nano-risk source is above the ceiling for its domain.
+    DeniedNanoRiskCeilingExceeded,
+    /// `from -> to` is not an edge of the capability graph (e.g. a skipped tier).
+    DeniedIllegalTransition,
+    /// ControlledHuman / GeneralUse target without regulator oversight.
+    DeniedMissingRole,
     DeniedUnknown,
 }
+
+/// Dashboard grouping for `DecisionReason`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReasonCategory {
+    Allowed,
+    Consent,
+    RoH,
+    Sovereignty,
+    Policy,
+    Unknown,
+}
+
+/// Audit severity for `DecisionReason`; ordered so denials sort worst-last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl DecisionReason {
+    pub fn category(&self) -> ReasonCategory {
+        match self {
+            DecisionReason::Allowed => ReasonCategory::Allowed,
+            DecisionReason::DeniedInsufficientConsent | DecisionReason::DeniedConsentRevoked => {
+                ReasonCategory::Consent
+            }
+            DecisionReason::DeniedRoHViolation | DecisionReason::DeniedNanoRiskCeilingExceeded => {
+                ReasonCategory::RoH
+            }
+            DecisionReason::DeniedIllegalDowngradeByNonRegulator
+            | DecisionReason::DeniedReversalNotAllowedInTier
+            | DecisionReason::DeniedNeuromorphReversalProhibited
+            | DecisionReason::DeniedDowngradeTooLarge => ReasonCategory::Sovereignty,
+            DecisionReason::DeniedPolicyStackFailure
+            | DecisionReason::DeniedMissingEvidence
+            | DecisionReason::DeniedNoSaferAlternativeNotProved
+            | DecisionReason::DeniedIllegalTransition
+            | DecisionReason::DeniedMissingRole => ReasonCategory::Policy,
+            DecisionReason::DeniedUnknown => ReasonCategory::Unknown,
+        }
+    }
+
+    /// `Info` only for `Allowed`. An unknown denial is treated as `High`
+    /// rather than guessed lower.
+    pub fn severity(&self) -> Severity {
+        match self {
+            DecisionReason::Allowed => Severity::Info,
+            DecisionReason::DeniedMissingEvidence
+            | DecisionReason::DeniedNoSaferAlternativeNotProved => Severity::Low,
+            DecisionReason::DeniedReversalNotAllowedInTier
+            | DecisionReason::DeniedDowngradeTooLarge => Severity::Medium,
+            DecisionReason::DeniedInsufficientConsent
+            | DecisionReason::DeniedPolicyStackFailure
+            | DecisionReason::DeniedIllegalDowngradeByNonRegulator
+            | DecisionReason::DeniedNanoRiskCeilingExceeded
+            | DecisionReason::DeniedIllegalTransition
+            | DecisionReason::DeniedMissingRole
+            | DecisionReason::DeniedUnknown => Severity::High,
+            DecisionReason::DeniedConsentRevoked
+            | DecisionReason::DeniedRoHViolation
+            | DecisionReason::DeniedNeuromorphReversalProhibited => Severity::Critical,
+        }
+    }
+}
+
+/// Everything `CapabilityTransitionRequest::evaluate` checks besides the
+/// `from -> to` edge itself, resolved to plain values up front.
+pub struct TransitionRequirements<'a> {
+    pub evidence: &'a [EvidenceRef],
+    pub consent: ConsentState,
+    /// At least one regulator signs off (needed for ControlledHuman / GeneralUse).
+    pub regulator_oversight: bool,
+    pub policystack_pass: bool,
+}
+
+impl<'a> TransitionRequirements<'a> {
+    pub fn resolve(
+        evidence: &'a [EvidenceRef],
+        consent: ConsentState,
+        roles: &RoleSet,
+        policystack: &PolicyStack,
+    ) -> Self {
+        Self {
+            evidence,
+            consent,
+            regulator_oversight: roles.neuromorph_god_satisfied(1),
+            policystack_pass: policystack.all_pass(),
+        }
+    }
+}
+
+impl CapabilityTransitionRequest {
+    /// Base transition check, in `aln_schema::CapabilityTransition::validate`
+    /// order: graph edge, evidence, consent, roles, policy stack. The first
+    /// failing check decides. Neuromorph downgrades go through the reversal
+    /// kernel first; everything else lands here directly.
+    ///
+    /// This is the clean sink of the taint spec: diagnostics may only reach
+    /// it through `nosaferalternative`.
+    #[crate::taint_spec::nr_taint_sink]
+    pub fn evaluate(&self, reqs: &TransitionRequirements) -> Decision {
+        match check_transition(self.from, self.to, reqs) {
+            Ok(()) => Decision::Allowed,
+            Err(reason) => Decision::denied(reason),
+        }
+    }
+}
+
+fn check_transition(
+    from: CapabilityState,
+    to: CapabilityState,
+    reqs: &TransitionRequirements,
+) -> Result<(), DecisionReason> {
+    // Mirrors `aln_schema::CapabilityTransition::check_graph_edge`: upgrades
+    // move one tier at a time; same-level and rollbacks are edges.
+    if capability_rank(to) > capability_rank(from) + 1 {
+        return Err(DecisionReason::DeniedIllegalTransition);
+    }
+    let live = to != CapabilityState::CapModelOnly;
+    if live && reqs.evidence.is_empty() {
+        return Err(DecisionReason::DeniedMissingEvidence);
+    }
+    if live {
+        match reqs.consent {
+            ConsentState::Revoked => return Err(DecisionReason::DeniedConsentRevoked),
+            ConsentState::None => return Err(DecisionReason::DeniedInsufficientConsent),
+            ConsentState::Minimal | ConsentState::Extended => {}
+        }
+    }
+    let human = matches!(
+        to,
+        CapabilityState::CapControlledHuman | CapabilityState::CapGeneralUse
+    );
+    if human && !reqs.regulator_oversight {
+        return Err(DecisionReason::DeniedMissingRole);
+    }
+    if !reqs.policystack_pass {
+        return Err(DecisionReason::DeniedPolicyStackFailure);
+    }
+    Ok(())
+}
+
+/// RoH ceiling no applied transition may end above. Lives here, with the
+/// lattice, so view-only crates can share it without the policy engine.
+pub const ROH_CEILING: f32 = 0.30;
+
+/// Position in the safety lattice (ModelOnly = 0 .. GeneralUse = 3). The one
+/// tier ordering shared by the transition check, the capability guard, the
+/// reversal kernel, and `aln_schema::CapabilityState::rank`.
+pub fn capability_rank(state: CapabilityState) -> u8 {
+    match state {
+        CapabilityState::CapModelOnly => 0,
+        CapabilityState::CapLabBench => 1,
+        CapabilityState::CapControlledHuman => 2,
+        CapabilityState::CapGeneralUse => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every variant, in declaration order. The match below stops compiling
+    /// when a variant is added, so this list cannot silently fall behind.
+    fn all_reasons() -> Vec<DecisionReason> {
+        let all = vec![
+            DecisionReason::Allowed,
+            DecisionReason::DeniedInsufficientConsent,
+            DecisionReason::DeniedConsentRevoked,
+            DecisionReason::DeniedPolicyStackFailure,
+            DecisionReason::DeniedMissingEvidence,
+            DecisionReason::DeniedIllegalDowngradeByNonRegulator,
+            DecisionReason::DeniedNoSaferAlternativeNotProved,
+            DecisionReason::DeniedReversalNotAllowedInTier,
+            DecisionReason::DeniedRoHViolation,
+            DecisionReason::DeniedNeuromorphReversalProhibited,
+            DecisionReason::DeniedDowngradeTooLarge,
+            DecisionReason::DeniedNanoRiskCeilingExceeded,
+            DecisionReason::DeniedIllegalTransition,
+            DecisionReason::DeniedMissingRole,
+            DecisionReason::DeniedUnknown,
+        ];
+        for reason in &all {
+            match reason {
+                DecisionReason::Allowed
+                | DecisionReason::DeniedInsufficientConsent
+                | DecisionReason::DeniedConsentRevoked
+                | DecisionReason::DeniedPolicyStackFailure
+                | DecisionReason::DeniedMissingEvidence
+                | DecisionReason::DeniedIllegalDowngradeByNonRegulator
+                | DecisionReason::DeniedNoSaferAlternativeNotProved
+                | DecisionReason::DeniedReversalNotAllowedInTier
+                | DecisionReason::DeniedRoHViolation
+                | DecisionReason::DeniedNeuromorphReversalProhibited
+                | DecisionReason::DeniedDowngradeTooLarge
+                | DecisionReason::DeniedNanoRiskCeilingExceeded
+                | DecisionReason::DeniedIllegalTransition
+                | DecisionReason::DeniedMissingRole
+                | DecisionReason::DeniedUnknown => {}
+            }
+        }
+        all
+    }
+
+    #[test]
+    fn test_every_reason_has_category_and_severity() {
+        for reason in all_reasons() {
+            let (category, severity) = (reason.category(), reason.severity());
+            let allowed = reason == DecisionReason::Allowed;
+            assert_eq!(category == ReasonCategory::Allowed, allowed, "{:?}", reason);
+            assert_eq!(severity == Severity::Info, allowed, "{:?}", reason);
+        }
+
+        let prohibited = DecisionReason::DeniedNeuromorphReversalProhibited;
+        assert_eq!(prohibited.category(), ReasonCategory::Sovereignty);
+        assert_eq!(prohibited.severity(), Severity::Critical);
+        assert_eq!(DecisionReason::DeniedConsentRevoked.category(), ReasonCategory::Consent);
+        assert_eq!(DecisionReason::DeniedRoHViolation.category(), ReasonCategory::RoH);
+        assert!(Severity::Info < Severity::Low && Severity::High < Severity::Critical);
+    }
+
+    fn request(from: CapabilityState, to: CapabilityState) -> CapabilityTransitionRequest {
+        CapabilityTransitionRequest {
+            from,
+            to,
+            roh_before: 0.2,
+            roh_after: 0.2,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_allows_stepwise_upgrade_with_evidence_consent_and_roles() {
+        let evidence = vec![EvidenceRef::new("cid:QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR")];
+        let reqs = |consent: ConsentState, regulator_oversight: bool| TransitionRequirements {
+            evidence: &evidence,
+            consent,
+            regulator_oversight,
+            policystack_pass: true,
+        };
+        let upgrade = request(CapabilityState::CapLabBench, CapabilityState::CapControlledHuman);
+        assert_eq!(upgrade.evaluate(&reqs(ConsentState::Minimal, true)), Decision::Allowed);
+        assert_eq!(
+            upgrade.evaluate(&reqs(ConsentState::Revoked, true)),
+            Decision::denied(DecisionReason::DeniedConsentRevoked)
+        );
+        assert_eq!(
+            upgrade.evaluate(&reqs(ConsentState::Extended, false)),
+            Decision::denied(DecisionReason::DeniedMissingRole)
+        );
+        let failing_stack = TransitionRequirements {
+            policystack_pass: false,
+            ..reqs(ConsentState::Extended, true)
+        };
+        assert_eq!(
+            upgrade.evaluate(&failing_stack),
+            Decision::denied(DecisionReason::DeniedPolicyStackFailure)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_denies_direct_jump_and_missing_evidence() {
+        let evidence = vec![EvidenceRef::new("cid:QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR")];
+        let reqs = TransitionRequirements {
+            evidence: &evidence,
+            consent: ConsentState::Extended,
+            regulator_oversight: true,
+            policystack_pass: true,
+        };
+        let jump = request(CapabilityState::CapModelOnly, CapabilityState::CapControlledHuman);
+        assert_eq!(
+            jump.evaluate(&reqs),
+            Decision::denied(DecisionReason::DeniedIllegalTransition)
+        );
+
+        let bench = request(CapabilityState::CapModelOnly, CapabilityState::CapLabBench);
+        assert_eq!(bench.evaluate(&reqs), Decision::Allowed);
+        let no_evidence = TransitionRequirements {
+            evidence: &[],
+            ..reqs
+        };
+        assert_eq!(
+            bench.evaluate(&no_evidence),
+            Decision::denied(DecisionReason::DeniedMissingEvidence)
+        );
+        // Staying in ModelOnly needs no evidence.
+        let model = request(CapabilityState::CapModelOnly, CapabilityState::CapModelOnly);
+        assert_eq!(model.evaluate(&no_evidence), Decision::Allowed);
+    }
+}
@@ -6,51 +6,14 @@
 //! - Pure functions only, suitable for use in Church-of-FEAR, Tree-of-Life, Jetson-Line logs.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
 
-/// Core scalar rails for a site, as seen through Tree-of-Life / NATURE.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub struct TreeOfLifeRails {
-    pub roh: f32,          // Risk-of-Harm, clamped [0, 0.3]
-    pub decay: f32,        // DECAY = roh / 0.3, clamped [0, 1]
-    pub lifeforce: f32,    // LIFEFORCE = 1 - DECAY
-    pub fear: f32,         // FEAR asset [0, 1]
-    pub pain: f32,         // PAIN asset [0, 1]
-    pub power: f32,        // POWER asset [0, 1]
-    pub church: f32,       // CHURCH asset [0, 1]
-    pub unfair_drain: bool,
-    pub calm_stable: bool,
-    pub overloaded: bool,
-    pub recovery: bool,
-}
-
-/// Minimal deed vocabulary for Jetson-Line justice/fairness.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub enum DeedKind {
-    Help,
-    Repair,
-    Support,
-    DeployCleanTech,
-    Colonize,
-    Conflict,
-    UseHabit,
-    EmitPollution,
-    Abstain,
-    Unknown,
-}
-
-/// Cause / context labels for the deed.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CauseContext {
-    pub rule_id: Option<String>,    // e.g., "JUST_CAUSE", "LAST_RESORT_WINDOW"
-    pub intent_tag: Option<String>, // e.g., "defensive", "restorative"
-}
-
-/// Snapshot of one site on the Jetson-Line.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SiteSnapshot {
-    pub index: u32,                // lattice index
-    pub rails: TreeOfLifeRails,    // Tree-of-Life / NATURE view at this tick
-}
+use crate::fairness_types::{check_positive, check_range, PolicyShard};
+pub use crate::fairness_types::{
+    join_reasons, CauseContext, ConfigError, DeedKind, FairnessReason, FairnessReasonCode,
+    SiteSnapshot, TreeOfLifeRails,
+};
 
 /// A Jetson-Line micro-unit / deed event, consensus-facing view.
 ///
@@ -79,12 +42,52 @@ pub struct FairnessVerdict {
     pub fairness_positive: bool,
     pub fairness_negative: bool,
     pub fairness_ambiguous: bool,
+    /// `"; "`-joined `reason_events` messages, kept for logs and older readers.
     pub reason: String,
+    /// Structured reasons in emission order; empty in records that predate it.
+    #[serde(default)]
+    pub reason_events: Vec<FairnessReason>,
+    /// Summed distance-weighted evidence behind the positive / negative flags.
+    #[serde(default)]
+    pub positive_weight: f32,
+    #[serde(default)]
+    pub negative_weight: f32,
+    /// Site indices that raised the negative flag, ascending.
+    #[serde(default)]
+    pub negative_sites: Vec<u32>,
+}
+
+impl FairnessVerdict {
+    /// Joined reason string, derived from `reason_events` when present.
+    pub fn reason(&self) -> String {
+        if self.reason_events.is_empty() {
+            self.reason.clone()
+        } else {
+            join_reasons(&self.reason_events)
+        }
+    }
+}
+
+/// Batch-level rollup of per-unit fairness verdicts (advisory only).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FairnessSummary {
+    pub units: usize,
+    /// Units judged positive-only, negative-only, or ambiguous; these sum to `units`.
+    pub positive: usize,
+    pub negative: usize,
+    pub ambiguous: usize,
+    /// Number of units in which each site index raised the negative flag.
+    pub negative_site_counts: BTreeMap<u32, usize>,
+    /// Sites flagged negative in at least two units, ascending.
+    pub recurring_negative_sites: Vec<u32>,
+    /// (positive - negative) / units, in [-1, 1]; 0.0 for an empty batch.
+    pub net_score: f32,
 }
 
 /// Simple W-cycle advisory view: What / SoWhat / NowWhat strings.
 ///
-/// In a later step, a proc-macro can auto-derive this from MicroUnit + FairnessVerdict.
+/// Template-driven views can be generated with `nr_taint_macros::WCycle`
+/// (`#[derive(WCycle)]` + `#[w_cycle(what = .., so_what = .., now_what = ..)]`).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WCycleView {
     pub what: String,
@@ -92,14 +95,95 @@ pub struct WCycleView {
     pub now_what: String,
 }
 
+/// How a peer's influence decays with lattice distance |actor.index - peer.index|.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DistanceDecay {
+    /// Every peer weighs 1.0 regardless of distance.
+    #[default]
+    Uniform,
+    /// 1 - d / radius, floored at 0.
+    Linear { radius: f32 },
+    /// exp(-d / length).
+    Exponential { length: f32 },
+    /// 1.0 within `radius`, 0.0 beyond.
+    Step { radius: u32 },
+}
+
+impl DistanceDecay {
+    /// Weight in [0, 1] for a peer at lattice distance `distance`.
+    /// A non-positive radius/length only keeps the actor's own site (d = 0).
+    pub fn weight(&self, distance: u32) -> f32 {
+        let d = distance as f32;
+        match *self {
+            DistanceDecay::Uniform => 1.0,
+            DistanceDecay::Linear { radius } => {
+                if radius <= 0.0 {
+                    return if distance == 0 { 1.0 } else { 0.0 };
+                }
+                (1.0 - d / radius).max(0.0)
+            }
+            DistanceDecay::Exponential { length } => {
+                if length <= 0.0 {
+                    return if distance == 0 { 1.0 } else { 0.0 };
+                }
+                (-d / length).exp()
+            }
+            DistanceDecay::Step { radius } => {
+                if distance <= radius {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// Per-term weights in [0, 1] for `vulnerability_score`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VulnerabilityWeights {
+    /// Scales the lifeforce deficit `1 - lifeforce`.
+    pub low_lifeforce: f32,
+    pub unfair_drain: f32,
+    pub overloaded: f32,
+}
+
+impl Default for VulnerabilityWeights {
+    fn default() -> Self {
+        Self {
+            low_lifeforce: 1.0,
+            unfair_drain: 0.6,
+            overloaded: 0.6,
+        }
+    }
+}
+
 /// Policy parameters for biophysical fairness checks.
-/// These are loaded from ALN/config in real deployments.
+/// These are loaded from ALN/config (`from_aln`) in real deployments.
+///
+/// JSON shards reject unknown keys, as ALN shards do.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct BiophysicalConsensusPolicy {
     pub roh_ceiling: f32,        // e.g., 0.30
     pub decay_max: f32,          // e.g., 1.0
     pub fear_safe_max: f32,      // e.g., 0.60
     pub power_church_k: f32,     // k in POWER <= k * CHURCH
+    /// Peer weighting by lattice distance; `Uniform` keeps every peer at full weight.
+    #[serde(default)]
+    pub distance_decay: DistanceDecay,
+    /// Minimum decayed weight for a single peer to raise a positive/negative flag.
+    #[serde(default = "default_min_peer_weight")]
+    pub min_peer_weight: f32,
+    /// Term weights for `vulnerability_score`.
+    #[serde(default)]
+    pub vulnerability_weights: VulnerabilityWeights,
+}
+
+fn default_min_peer_weight() -> f32 {
+    0.25
 }
 
 impl Default for BiophysicalConsensusPolicy {
@@ -109,6 +193,76 @@ impl Default for BiophysicalConsensusPolicy {
             decay_max: 1.0,
             fear_safe_max: 0.60,
             power_church_k: 2.0,
+            distance_decay: DistanceDecay::Uniform,
+            min_peer_weight: default_min_peer_weight(),
+            vulnerability_weights: VulnerabilityWeights::default(),
+        }
+    }
+}
+
+impl BiophysicalConsensusPolicy {
+    /// Load from a JSON or `key = value` ALN shard (vulnerability weights as
+    /// `vulnerability_weights.<term>`; `distance_decay` only via JSON). Keys
+    /// missing from an ALN shard keep their `Default` value. The result is
+    /// validated.
+    pub fn from_aln(path: &Path) -> Result<Self, ConfigError> {
+        Self::from_shard(PolicyShard::load(path)?)
+    }
+
+    /// `from_aln` on an in-memory shard body.
+    pub fn from_aln_str(text: &str) -> Result<Self, ConfigError> {
+        Self::from_shard(PolicyShard::parse(text)?)
+    }
+
+    fn from_shard(shard: PolicyShard<Self>) -> Result<Self, ConfigError> {
+        let policy = match shard {
+            PolicyShard::Json(policy) => policy,
+            PolicyShard::Fields(fields) => {
+                let mut policy = Self::default();
+                for (key, value) in fields {
+                    let weights = &mut policy.vulnerability_weights;
+                    match key.as_str() {
+                        "roh_ceiling" => policy.roh_ceiling = value,
+                        "decay_max" => policy.decay_max = value,
+                        "fear_safe_max" => policy.fear_safe_max = value,
+                        "power_church_k" => policy.power_church_k = value,
+                        "min_peer_weight" => policy.min_peer_weight = value,
+                        "vulnerability_weights.low_lifeforce" => weights.low_lifeforce = value,
+                        "vulnerability_weights.unfair_drain" => weights.unfair_drain = value,
+                        "vulnerability_weights.overloaded" => weights.overloaded = value,
+                        _ => return Err(ConfigError::UnknownKey { key }),
+                    }
+                }
+                policy
+            }
+        };
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    /// `roh_ceiling` within the global 0.30 ceiling, `decay_max` in (0, 1],
+    /// the other bands and weights in [0, 1], and a positive `power_church_k`
+    /// and `distance_decay` radius / length.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_range("roh_ceiling", self.roh_ceiling, 0.0..=0.30)?;
+        check_range("decay_max", self.decay_max, f32::MIN_POSITIVE..=1.0)?;
+        check_range("fear_safe_max", self.fear_safe_max, 0.0..=1.0)?;
+        check_positive("power_church_k", self.power_church_k)?;
+        check_range("min_peer_weight", self.min_peer_weight, 0.0..=1.0)?;
+        let w = &self.vulnerability_weights;
+        for (key, value) in [
+            ("vulnerability_weights.low_lifeforce", w.low_lifeforce),
+            ("vulnerability_weights.unfair_drain", w.unfair_drain),
+            ("vulnerability_weights.overloaded", w.overloaded),
+        ] {
+            check_range(key, value, 0.0..=1.0)?;
+        }
+        match self.distance_decay {
+            DistanceDecay::Linear { radius } => check_positive("distance_decay.radius", radius),
+            DistanceDecay::Exponential { length } => {
+                check_positive("distance_decay.length", length)
+            }
+            DistanceDecay::Uniform | DistanceDecay::Step { .. } => Ok(()),
         }
     }
 }
@@ -129,15 +283,112 @@ fn site_respects_core_rails(rails: &TreeOfLifeRails, policy: &BiophysicalConsens
         && power_within_church_cap(rails, policy.power_church_k)
 }
 
-/// Decide whether a site is "vulnerable" for fairness weighting.
+/// Decide whether a site is "vulnerable" for fairness weighting: its
+/// `vulnerability_score` reaches `decay_max * 0.5`, i.e. lifeforce at or
+/// below `1 - decay_max * 0.5`, or unfair drain / overload with a weight at
+/// least that threshold.
 fn is_vulnerable_site(rails: &TreeOfLifeRails, policy: &BiophysicalConsensusPolicy) -> bool {
-    rails.lifeforce <= (1.0 - policy.decay_max * 0.5) // e.g., low lifeforce
-        || rails.unfair_drain
-        || rails.overloaded
+    vulnerability_score(rails, policy) >= policy.decay_max * 0.5
+}
+
+fn ambiguous_verdict(message: String) -> FairnessVerdict {
+    FairnessVerdict {
+        fairness_positive: false,
+        fairness_negative: false,
+        fairness_ambiguous: true,
+        reason_events: vec![FairnessReason::new(
+            FairnessReasonCode::SitesUnavailable,
+            None,
+            message.clone(),
+        )],
+        reason: message,
+        positive_weight: 0.0,
+        negative_weight: 0.0,
+        negative_sites: Vec::new(),
+    }
+}
+
+/// Pair each pre site with the post site of the same `index`, in `pre_sites`
+/// order (actor first). Errs with a reason if the site counts or index sets
+/// differ or an index repeats, since positional pairing would compare
+/// unrelated sites or silently drop the unmatched ones.
+fn align_sites(unit: &MicroUnit) -> Result<Vec<(&SiteSnapshot, &SiteSnapshot)>, String> {
+    if unit.pre_sites.len() != unit.post_sites.len() {
+        let pre = sorted_indices(&unit.pre_sites);
+        let post = sorted_indices(&unit.post_sites);
+        let dropped_post: Vec<u32> = pre.iter().filter(|i| !post.contains(i)).copied().collect();
+        let dropped_pre: Vec<u32> = post.iter().filter(|i| !pre.contains(i)).copied().collect();
+        return Err(format!(
+            "pre/post site counts differ ({} vs {}; no post snapshot for {:?}, \
+             no pre snapshot for {:?}); fairness cannot be evaluated",
+            pre.len(),
+            post.len(),
+            dropped_post,
+            dropped_pre
+        ));
+    }
+
+    let pre: HashMap<u32, &SiteSnapshot> = unit.pre_sites.iter().map(|s| (s.index, s)).collect();
+    let post: HashMap<u32, &SiteSnapshot> =
+        unit.post_sites.iter().map(|s| (s.index, s)).collect();
+    if pre.len() != unit.pre_sites.len() || post.len() != unit.post_sites.len() {
+        return Err(
+            "duplicate site index in pre/post snapshots; fairness cannot be evaluated".into(),
+        );
+    }
+
+    let mut missing_post: Vec<u32> =
+        pre.keys().filter(|i| !post.contains_key(i)).copied().collect();
+    let mut missing_pre: Vec<u32> =
+        post.keys().filter(|i| !pre.contains_key(i)).copied().collect();
+    if !missing_post.is_empty() || !missing_pre.is_empty() {
+        missing_post.sort_unstable();
+        missing_pre.sort_unstable();
+        return Err(format!(
+            "pre/post site indices differ (no post snapshot for {:?}, no pre snapshot for {:?}); \
+             fairness cannot be evaluated",
+            missing_post, missing_pre
+        ));
+    }
+
+    Ok(unit.pre_sites.iter().map(|s| (s, post[&s.index])).collect())
+}
+
+fn sorted_indices(sites: &[SiteSnapshot]) -> Vec<u32> {
+    let mut indices: Vec<u32> = sites.iter().map(|s| s.index).collect();
+    indices.sort_unstable();
+    indices
 }
 
 // ---------- Public consensus-facing functions ----------
 
+/// Graded vulnerability in [0, 1] from low lifeforce, unfair drain, and
+/// overload, combined as a noisy-OR: `1 - Π(1 - weight_i * term_i)`.
+///
+/// The lifeforce term is `1 - lifeforce`; the flags are 0 or 1. Any single
+/// term can push the score up to its weight, and a drained site scores higher
+/// the lower its lifeforce, so a critically drained site outranks a barely
+/// vulnerable one. NaN lifeforce counts as fully depleted.
+pub fn vulnerability_score(rails: &TreeOfLifeRails, policy: &BiophysicalConsensusPolicy) -> f32 {
+    let w = &policy.vulnerability_weights;
+    let deficit = if rails.lifeforce.is_nan() {
+        1.0
+    } else {
+        (1.0 - rails.lifeforce).clamp(0.0, 1.0)
+    };
+    let flag = |on: bool| if on { 1.0 } else { 0.0 };
+    let terms = [
+        (w.low_lifeforce, deficit),
+        (w.unfair_drain, flag(rails.unfair_drain)),
+        (w.overloaded, flag(rails.overloaded)),
+    ];
+    let spared: f32 = terms
+        .iter()
+        .map(|&(weight, term)| 1.0 - weight.clamp(0.0, 1.0) * term)
+        .product();
+    (1.0 - spared).clamp(0.0, 1.0)
+}
+
 /// Check that pre/post states respect Tree-of-Life safety rails (RoH, DECAY, POWER ≤ k·CHURCH).
 ///
 /// This does NOT mutate any state; it is meant as a log-time consensus check.
@@ -145,7 +396,7 @@ pub fn check_tree_of_life_rails(
     unit: &MicroUnit,
     policy: &BiophysicalConsensusPolicy,
 ) -> bool {
-    let all_sites = unit
+    let mut all_sites = unit
         .pre_sites
         .iter()
         .chain(unit.post_sites.iter());
@@ -162,40 +413,58 @@ pub fn compute_fairness_verdict(
     unit: &MicroUnit,
     policy: &BiophysicalConsensusPolicy,
 ) -> FairnessVerdict {
-    if unit.pre_sites.is_empty() || unit.post_sites.is_empty() {
-        return FairnessVerdict {
-            fairness_positive: false,
-            fairness_negative: false,
-            fairness_ambiguous: true,
-            reason: "missing pre/post snapshots; fairness cannot be evaluated".into(),
-        };
+    if unit.pre_sites.is_empty() && unit.post_sites.is_empty() {
+        return ambiguous_verdict(
+            "missing pre/post snapshots; fairness cannot be evaluated".into(),
+        );
     }
 
-    // For simplicity, align by index order; in real code, align by site index.
-    let actor_pre = &unit.pre_sites[0];
-    let actor_post = &unit.post_sites[0];
-
-    let peers_pre = &unit.pre_sites[1..];
-    let peers_post = &unit.post_sites[1..];
+    // Align pre/post by site index. The first pre site is the actor.
+    let peers = match align_sites(unit) {
+        Ok(pairs) => pairs,
+        Err(reason) => return ambiguous_verdict(reason),
+    };
+    let (actor_pre, actor_post) = peers[0];
+    let peers = &peers[1..];
 
     let mut positive = false;
     let mut negative = false;
-    let mut reasons: Vec<String> = Vec::new();
+    let mut positive_weight = 0f32;
+    let mut negative_weight = 0f32;
+    let mut negative_sites: Vec<u32> = Vec::new();
+    let mut reasons: Vec<FairnessReason> = Vec::new();
+
+    // Peer contributions are weighted by lattice distance from the actor; a peer only
+    // raises a flag on its own if its weight reaches `min_peer_weight`.
+    let peer_weight = |index: u32| policy.distance_decay.weight(actor_pre.index.abs_diff(index));
+    let counts = |w: f32| w >= policy.min_peer_weight;
 
     // Core rails must hold for actor and peers in post-state; if not, mark negative.
     if !site_respects_core_rails(&actor_post.rails, policy) {
         negative = true;
-        reasons.push(format!(
-            "actor site {} violates post-state safety rails",
-            actor_post.index
+        negative_weight += 1.0;
+        negative_sites.push(actor_post.index);
+        reasons.push(FairnessReason::new(
+            FairnessReasonCode::RailViolation,
+            Some(actor_post.index),
+            format!("actor site {} violates post-state safety rails", actor_post.index),
         ));
     }
-    for p in peers_post {
+    for (_, p) in peers {
         if !site_respects_core_rails(&p.rails, policy) {
-            negative = true;
-            reasons.push(format!(
-                "peer site {} violates post-state safety rails",
-                p.index
+            let w = peer_weight(p.index);
+            negative_weight += w;
+            if counts(w) {
+                negative = true;
+                negative_sites.push(p.index);
+            }
+            reasons.push(FairnessReason::new(
+                FairnessReasonCode::RailViolation,
+                Some(p.index),
+                format!(
+                    "peer site {} violates post-state safety rails (weight {:.2})",
+                    p.index, w
+                ),
             ));
         }
     }
@@ -203,22 +472,35 @@ pub fn compute_fairness_verdict(
     match unit.kind {
         DeedKind::Help | DeedKind::Repair | DeedKind::Support | DeedKind::DeployCleanTech => {
             // Help-like deeds should reduce vulnerability or UNFAIRDRAIN without breaching caps.
-            for (pre, post) in peers_pre.iter().zip(peers_post.iter()) {
+            // Evidence is scaled by how far the vulnerability score moved.
+            for &(pre, post) in peers {
                 let pre_vuln = is_vulnerable_site(&pre.rails, policy);
                 let post_vuln = is_vulnerable_site(&post.rails, policy);
+                let shift = (vulnerability_score(&pre.rails, policy)
+                    - vulnerability_score(&post.rails, policy))
+                .abs();
+
+                let w = peer_weight(post.index);
 
                 if pre_vuln && !post_vuln && site_respects_core_rails(&post.rails, policy) {
-                    positive = true;
-                    reasons.push(format!(
-                        "help-like deed reduced vulnerability at site {}",
-                        post.index
+                    positive_weight += w * shift;
+                    positive |= counts(w);
+                    reasons.push(FairnessReason::new(
+                        FairnessReasonCode::VulnerabilityReduced,
+                        Some(post.index),
+                        format!("help-like deed reduced vulnerability at site {}", post.index),
                     ));
                 }
                 if !pre_vuln && post_vuln {
-                    negative = true;
-                    reasons.push(format!(
-                        "help-like deed increased vulnerability at site {}",
-                        post.index
+                    negative_weight += w * shift;
+                    if counts(w) {
+                        negative = true;
+                        negative_sites.push(post.index);
+                    }
+                    reasons.push(FairnessReason::new(
+                        FairnessReasonCode::VulnerabilityIncreased,
+                        Some(post.index),
+                        format!("help-like deed increased vulnerability at site {}", post.index),
                     ));
                 }
             }
@@ -226,18 +508,26 @@ pub fn compute_fairness_verdict(
 
         DeedKind::Colonize | DeedKind::Conflict => {
             // Colonize/Conflict is only fairness-compatible if it constrains an unfair-drain site.
-            for (pre, post) in peers_pre.iter().zip(peers_post.iter()) {
+            for &(pre, post) in peers {
+                let w = peer_weight(post.index);
                 if pre.rails.unfair_drain && !post.rails.unfair_drain {
-                    positive = true;
-                    reasons.push(format!(
-                        "colonize/conflict deed reduced UNFAIRDRAIN at site {}",
-                        post.index
+                    positive_weight += w;
+                    positive |= counts(w);
+                    reasons.push(FairnessReason::new(
+                        FairnessReasonCode::UnfairDrainReduced,
+                        Some(post.index),
+                        format!("colonize/conflict deed reduced UNFAIRDRAIN at site {}", post.index),
                     ));
                 } else if !pre.rails.unfair_drain && post.rails.unfair_drain {
-                    negative = true;
-                    reasons.push(format!(
-                        "colonize/conflict deed introduced UNFAIRDRAIN at site {}",
-                        post.index
+                    negative_weight += w;
+                    if counts(w) {
+                        negative = true;
+                        negative_sites.push(post.index);
+                    }
+                    reasons.push(FairnessReason::new(
+                        FairnessReasonCode::UnfairDrainIntroduced,
+                        Some(post.index),
+                        format!("colonize/conflict deed introduced UNFAIRDRAIN at site {}", post.index),
                     ));
                 }
             }
@@ -245,19 +535,31 @@ pub fn compute_fairness_verdict(
 
         DeedKind::UseHabit | DeedKind::EmitPollution => {
             // Habit / pollution generally count as fairness-negative if they increase DECAY/UNFAIRDRAIN.
-            for (pre, post) in peers_pre.iter().zip(peers_post.iter()) {
+            for &(pre, post) in peers {
                 if post.rails.decay > pre.rails.decay && post.rails.unfair_drain {
-                    negative = true;
-                    reasons.push(format!(
-                        "habit/pollution increased DECAY and UNFAIRDRAIN at site {}",
-                        post.index
+                    let w = peer_weight(post.index);
+                    negative_weight += w;
+                    if counts(w) {
+                        negative = true;
+                        negative_sites.push(post.index);
+                    }
+                    reasons.push(FairnessReason::new(
+                        FairnessReasonCode::DecayAndDrainIncreased,
+                        Some(post.index),
+                        format!("habit/pollution increased DECAY and UNFAIRDRAIN at site {}", post.index),
                     ));
                 }
             }
         }
 
-        DeedKind::Abstain | DeedKind::Unknown => {
-            reasons.push("deed treated as fairness-ambiguous by default".into());
+        // Abstain, Unknown, and any kind added to the non-exhaustive `DeedKind`
+        // later are ambiguous until given explicit scoring.
+        _ => {
+            reasons.push(FairnessReason::new(
+                FairnessReasonCode::AmbiguousKind,
+                None,
+                "deed treated as fairness-ambiguous by default",
+            ));
         }
     }
 
@@ -265,27 +567,79 @@ pub fn compute_fairness_verdict(
     if let Some(intent) = &unit.cause.intent_tag {
         if intent.eq_ignore_ascii_case("restorative") && !negative {
             positive = true;
-            reasons.push("restorative intent with no rail violations".into());
+            positive_weight = positive_weight.max(1.0);
+            reasons.push(FairnessReason::new(
+                FairnessReasonCode::Intent,
+                None,
+                "restorative intent with no rail violations",
+            ));
         }
         if intent.eq_ignore_ascii_case("opportunistic") && positive {
-            reasons.push("opportunistic intent; keeping positive/negative flags for transparency".into());
+            reasons.push(FairnessReason::new(
+                FairnessReasonCode::Intent,
+                None,
+                "opportunistic intent; keeping positive/negative flags for transparency",
+            ));
         }
     }
 
     let ambiguous = !(positive ^ negative);
+    negative_sites.sort_unstable();
+    negative_sites.dedup();
 
     FairnessVerdict {
         fairness_positive: positive,
         fairness_negative: negative,
         fairness_ambiguous: ambiguous,
-        reason: reasons.join("; "),
+        reason: join_reasons(&reasons),
+        reason_events: reasons,
+        positive_weight,
+        negative_weight,
+        negative_sites,
     }
 }
 
+/// Summarize a batch of micro-units (e.g. a session or cohort) by running
+/// `compute_fairness_verdict` on each, so single-unit semantics stay authoritative.
+pub fn aggregate_verdicts(
+    units: &[MicroUnit],
+    policy: &BiophysicalConsensusPolicy,
+) -> FairnessSummary {
+    let mut summary = FairnessSummary {
+        units: units.len(),
+        ..FairnessSummary::default()
+    };
+
+    for unit in units {
+        let verdict = compute_fairness_verdict(unit, policy);
+        if verdict.fairness_ambiguous {
+            summary.ambiguous += 1;
+        } else if verdict.fairness_positive {
+            summary.positive += 1;
+        } else {
+            summary.negative += 1;
+        }
+        for index in verdict.negative_sites {
+            *summary.negative_site_counts.entry(index).or_default() += 1;
+        }
+    }
+
+    summary.recurring_negative_sites = summary
+        .negative_site_counts
+        .iter()
+        .filter(|(_, &n)| n >= 2)
+        .map(|(&index, _)| index)
+        .collect();
+    if !units.is_empty() {
+        summary.net_score = (summary.positive as f32 - summary.negative as f32) / units.len() as f32;
+    }
+    summary
+}
+
 /// Construct a simple W-cycle advisory view for this micro-unit.
 ///
-/// In production, this would be generated by a derive macro that has access to
-/// micro-unit fields and external reflection text; here we keep it minimal.
+/// Hand-written reference; reflection structs that carry extra text can use
+/// `#[derive(WCycle)]` from `nr_taint_macros` instead.
 pub fn build_w_cycle_view(unit: &MicroUnit, verdict: &FairnessVerdict) -> WCycleView {
     let what = format!(
         "Tick {}: {:?} by actor {} on {} site(s)",
@@ -300,7 +654,7 @@ pub fn build_w_cycle_view(unit: &MicroUnit, verdict: &FairnessVerdict) -> WCycle
         verdict.fairness_positive,
         verdict.fairness_negative,
         verdict.fairness_ambiguous,
-        verdict.reason
+        verdict.reason()
     );
 
     let now_what = "Suggested next step: log this micro-unit to the moral ledger; human or governance review may choose repair, support, or policy refinement, but no automatic actuation occurs here."
@@ -312,3 +666,284 @@ pub fn build_w_cycle_view(unit: &MicroUnit, verdict: &FairnessVerdict) -> WCycle
         now_what,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rails(roh: f32) -> TreeOfLifeRails {
+        let decay = (roh / 0.30).clamp(0.0, 1.0);
+        TreeOfLifeRails {
+            roh,
+            decay,
+            lifeforce: 1.0 - decay,
+            fear: 0.1,
+            pain: 0.1,
+            power: 0.2,
+            church: 0.5,
+            unfair_drain: false,
+            calm_stable: true,
+            overloaded: false,
+            recovery: false,
+        }
+    }
+
+    fn site(index: u32, roh: f32) -> SiteSnapshot {
+        SiteSnapshot {
+            index,
+            rails: rails(roh),
+        }
+    }
+
+    fn unit_with_peer_violation(peer_index: u32) -> MicroUnit {
+        MicroUnit {
+            tick: 1,
+            actor_id: "actor".into(),
+            target_ids: vec![],
+            kind: DeedKind::Abstain,
+            cause: CauseContext {
+                rule_id: None,
+                intent_tag: None,
+            },
+            pre_sites: vec![site(0, 0.05), site(peer_index, 0.05)],
+            // Peer ends above the RoH ceiling.
+            post_sites: vec![site(0, 0.05), site(peer_index, 0.40)],
+            w_cycle_binding: None,
+        }
+    }
+
+    fn help_unit(post_sites: Vec<SiteSnapshot>) -> MicroUnit {
+        let mut vulnerable = site(2, 0.05);
+        vulnerable.rails.unfair_drain = true;
+        MicroUnit {
+            tick: 1,
+            actor_id: "actor".into(),
+            target_ids: vec![],
+            kind: DeedKind::Help,
+            cause: CauseContext {
+                rule_id: None,
+                intent_tag: None,
+            },
+            pre_sites: vec![site(0, 0.05), site(1, 0.05), vulnerable],
+            post_sites,
+            w_cycle_binding: None,
+        }
+    }
+
+    #[test]
+    fn test_sites_aligned_by_index_not_position() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let ordered = help_unit(vec![site(0, 0.05), site(1, 0.05), site(2, 0.05)]);
+        let shuffled = help_unit(vec![site(2, 0.05), site(0, 0.05), site(1, 0.05)]);
+
+        let a = compute_fairness_verdict(&ordered, &policy);
+        let b = compute_fairness_verdict(&shuffled, &policy);
+        assert!(a.fairness_positive && !a.fairness_ambiguous);
+        assert_eq!(a.fairness_positive, b.fairness_positive);
+        assert_eq!(a.fairness_negative, b.fairness_negative);
+        assert_eq!(a.reason, b.reason);
+    }
+
+    #[test]
+    fn test_missing_post_site_is_ambiguous() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let unit = help_unit(vec![site(0, 0.05), site(1, 0.05)]);
+        let v = compute_fairness_verdict(&unit, &policy);
+        assert!(v.fairness_ambiguous);
+        assert!(!v.fairness_positive && !v.fairness_negative);
+        assert!(v.reason.contains("no post snapshot for [2]"));
+    }
+
+    #[test]
+    fn test_longer_pre_names_dropped_sites() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let unit = help_unit(vec![site(0, 0.05)]);
+        let v = compute_fairness_verdict(&unit, &policy);
+        assert!(v.fairness_ambiguous);
+        assert!(v.reason.contains("counts differ (3 vs 1"));
+        assert!(v.reason.contains("no post snapshot for [1, 2]"));
+        assert!(v.reason.contains("no pre snapshot for []"));
+    }
+
+    #[test]
+    fn test_longer_post_names_appeared_sites() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let unit = help_unit(vec![
+            site(0, 0.05),
+            site(1, 0.05),
+            site(2, 0.05),
+            site(7, 0.40),
+        ]);
+        let v = compute_fairness_verdict(&unit, &policy);
+        assert!(v.fairness_ambiguous);
+        assert!(!v.fairness_negative, "unmatched site 7 must not be judged");
+        assert!(v.reason.contains("counts differ (3 vs 4"));
+        assert!(v.reason.contains("no pre snapshot for [7]"));
+    }
+
+    #[test]
+    fn test_one_sided_empty_snapshots_name_indices() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let no_post = help_unit(vec![]);
+        let v = compute_fairness_verdict(&no_post, &policy);
+        assert!(v.fairness_ambiguous);
+        assert!(v.reason.contains("no post snapshot for [0, 1, 2]"));
+
+        let mut no_pre = help_unit(vec![site(0, 0.05), site(4, 0.05)]);
+        no_pre.pre_sites.clear();
+        let v = compute_fairness_verdict(&no_pre, &policy);
+        assert!(v.fairness_ambiguous);
+        assert!(v.reason.contains("no pre snapshot for [0, 4]"));
+
+        let mut neither = help_unit(vec![]);
+        neither.pre_sites.clear();
+        let v = compute_fairness_verdict(&neither, &policy);
+        assert!(v.reason.starts_with("missing pre/post snapshots"));
+    }
+
+    #[test]
+    fn test_aggregate_verdicts_counts_and_recurring_negative_sites() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let positive = help_unit(vec![site(0, 0.05), site(1, 0.05), site(2, 0.05)]);
+        let ambiguous = help_unit(vec![site(0, 0.05), site(1, 0.05)]);
+        let units = vec![
+            positive.clone(),
+            unit_with_peer_violation(3),
+            ambiguous,
+            unit_with_peer_violation(3),
+            positive,
+            unit_with_peer_violation(3),
+            unit_with_peer_violation(5),
+        ];
+
+        let summary = aggregate_verdicts(&units, &policy);
+        assert_eq!(summary.units, 7);
+        assert_eq!(
+            (summary.positive, summary.negative, summary.ambiguous),
+            (2, 4, 1)
+        );
+        assert_eq!(summary.negative_site_counts.get(&3), Some(&3));
+        assert_eq!(summary.negative_site_counts.get(&5), Some(&1));
+        assert_eq!(summary.recurring_negative_sites, vec![3]);
+        assert!((summary.net_score - (2.0 - 4.0) / 7.0).abs() < 1e-6);
+
+        assert_eq!(aggregate_verdicts(&[], &policy), FairnessSummary::default());
+    }
+
+    #[test]
+    fn test_distance_decay_weights() {
+        assert_eq!(DistanceDecay::Uniform.weight(100), 1.0);
+        assert_eq!(DistanceDecay::Step { radius: 2 }.weight(2), 1.0);
+        assert_eq!(DistanceDecay::Step { radius: 2 }.weight(3), 0.0);
+        assert!((DistanceDecay::Linear { radius: 4.0 }.weight(1) - 0.75).abs() < 1e-6);
+        assert_eq!(DistanceDecay::Linear { radius: 4.0 }.weight(8), 0.0);
+        assert!(DistanceDecay::Exponential { length: 2.0 }.weight(10) < 0.01);
+    }
+
+    #[test]
+    fn test_distant_peer_violation_contributes_less_under_exponential_decay() {
+        let uniform = BiophysicalConsensusPolicy::default();
+        let exponential = BiophysicalConsensusPolicy {
+            distance_decay: DistanceDecay::Exponential { length: 2.0 },
+            ..BiophysicalConsensusPolicy::default()
+        };
+
+        let near = unit_with_peer_violation(1);
+        let far = unit_with_peer_violation(10);
+
+        // Uniform decay reduces to the unweighted behavior: both violations count.
+        assert!(compute_fairness_verdict(&near, &uniform).fairness_negative);
+        assert!(compute_fairness_verdict(&far, &uniform).fairness_negative);
+
+        let near_v = compute_fairness_verdict(&near, &exponential);
+        let far_v = compute_fairness_verdict(&far, &exponential);
+        assert!(near_v.fairness_negative);
+        assert!(!far_v.fairness_negative);
+        assert!(far_v.negative_weight < near_v.negative_weight);
+    }
+
+    #[test]
+    fn test_vulnerability_score_grades_and_thresholds() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let mut drained = rails(0.05);
+        drained.unfair_drain = true;
+        let mut critical = drained;
+        critical.lifeforce = 0.05;
+
+        assert!(vulnerability_score(&critical, &policy) > vulnerability_score(&drained, &policy));
+        assert!(is_vulnerable_site(&drained, &policy));
+        assert!(!is_vulnerable_site(&rails(0.05), &policy));
+        // Lifeforce alone: vulnerable at or below 1 - decay_max * 0.5.
+        let mut low = rails(0.05);
+        low.lifeforce = 0.5;
+        assert!(is_vulnerable_site(&low, &policy));
+        low.lifeforce = 0.51;
+        assert!(!is_vulnerable_site(&low, &policy));
+        for r in [rails(0.0), drained, critical] {
+            let score = vulnerability_score(&r, &policy);
+            assert!((0.0..=1.0).contains(&score));
+        }
+    }
+
+    #[test]
+    fn test_large_vulnerability_reduction_outweighs_marginal_one() {
+        let policy = BiophysicalConsensusPolicy::default();
+        let help = |pre_lifeforce: f32, post_lifeforce: f32| {
+            let mut pre = site(1, 0.05);
+            pre.rails.lifeforce = pre_lifeforce;
+            let mut post = site(1, 0.05);
+            post.rails.lifeforce = post_lifeforce;
+            let unit = MicroUnit {
+                kind: DeedKind::Help,
+                pre_sites: vec![site(0, 0.05), pre],
+                post_sites: vec![site(0, 0.05), post],
+                ..help_unit(vec![])
+            };
+            compute_fairness_verdict(&unit, &policy)
+        };
+
+        // Critically drained site restored vs. one nudged just past the threshold.
+        let large = help(0.05, 0.95);
+        let marginal = help(0.49, 0.51);
+        assert!(large.fairness_positive && marginal.fairness_positive);
+        assert!(large.positive_weight > 10.0 * marginal.positive_weight);
+        assert!((large.positive_weight - 0.9).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_reason_events_carry_site_indices() {
+        let policy = BiophysicalConsensusPolicy::default();
+        // Site 2 recovers from UNFAIRDRAIN; site 5 ends above the RoH ceiling.
+        let mut unit = help_unit(vec![site(0, 0.05), site(1, 0.05), site(2, 0.05), site(5, 0.40)]);
+        unit.pre_sites.push(site(5, 0.05));
+        let v = compute_fairness_verdict(&unit, &policy);
+
+        let events: Vec<(FairnessReasonCode, Option<u32>)> =
+            v.reason_events.iter().map(|r| (r.code, r.site)).collect();
+        assert_eq!(
+            events,
+            vec![
+                (FairnessReasonCode::RailViolation, Some(5)),
+                (FairnessReasonCode::VulnerabilityReduced, Some(2)),
+                (FairnessReasonCode::VulnerabilityIncreased, Some(5)),
+            ]
+        );
+        for event in &v.reason_events {
+            let site = event.site.unwrap();
+            assert!(event.message.contains(&format!("site {}", site)), "{:?}", event);
+        }
+        assert_eq!(v.reason(), v.reason);
+
+        let mismatched = compute_fairness_verdict(&help_unit(vec![site(0, 0.05)]), &policy);
+        assert_eq!(mismatched.reason_events.len(), 1);
+        assert_eq!(mismatched.reason_events[0].code, FairnessReasonCode::SitesUnavailable);
+        assert_eq!(mismatched.reason_events[0].site, None);
+
+        // Records written before `reason_events` existed keep their string.
+        let legacy = FairnessVerdict {
+            reason_events: Vec::new(),
+            ..v.clone()
+        };
+        assert_eq!(legacy.reason(), v.reason);
+    }
+}
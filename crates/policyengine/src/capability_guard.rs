@@ -1,5 +1,14 @@
 use serde::{Deserialize, Serialize};
-use crate::alncore::{CapabilityState, Jurisdiction, PolicyStack, Decision, DecisionReason};
+use crate::alncore::{
+    capability_rank as rank, CapabilityState, CapabilityTransitionRequest, Jurisdiction,
+    PolicyStack, Decision, DecisionReason,
+};
+use crate::alnroles::Role;
+use crate::taint_spec::nr_taint_trusted_writer;
+use nanosafety::nano_risk_guard::{NanoCeilings, NanoRiskGuard};
+use neuromorphic_bioscale_spec::AlgoEnvelope;
+
+pub use crate::alncore::ROH_CEILING;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CapabilityGuardErrorKind {
@@ -38,3 +47,618 @@ pub struct CapabilityGuardError {
     pub kind: CapabilityGuardErrorKind,
     pub message: String,
 }
+
+impl CapabilityGuardError {
+    pub fn new(kind: CapabilityGuardErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+/// Reject when the guard's nano-risk exceeds `max_allowed`.
+/// A NaN risk is treated as exceeding any ceiling.
+pub fn check_nano_risk(
+    guard: &dyn NanoRiskGuard,
+    max_allowed: f32,
+) -> Result<(), CapabilityGuardError> {
+    let risk = guard.nano_risk();
+    if exceeds_ceiling(risk, max_allowed) {
+        return Err(CapabilityGuardError::new(
+            CapabilityGuardErrorKind::EnvelopeViolation,
+            format!(
+                "nano_risk {} exceeds ceiling {} for domain {:?}",
+                risk,
+                max_allowed,
+                guard.nano_risk_domain()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn exceeds_ceiling(risk: f32, ceiling: f32) -> bool {
+    risk.is_nan() || risk > ceiling
+}
+
+/// Capability-guard pre-check: every nano-risk source must sit under the
+/// ceiling for its own domain.
+pub fn check_nano_risk_prechecks(
+    guards: &[&dyn NanoRiskGuard],
+    ceilings: &NanoCeilings,
+) -> Result<(), CapabilityGuardError> {
+    for guard in guards {
+        check_nano_risk(*guard, ceilings.ceiling_for(guard.nano_risk_domain()))?;
+    }
+    Ok(())
+}
+
+/// Gating entry point for any `NanoRiskGuard`: denies when the guard's risk
+/// is above (or NaN against) the ceiling for its own domain, using the same
+/// check as `check_nano_risk_prechecks`.
+///
+/// This is a blanket extension trait rather than a default method on
+/// `NanoRiskGuard` because `Decision` lives in this crate, which already
+/// depends on nanosafety; a default method there would need the reverse edge.
+pub trait NanoGate {
+    fn nano_gate(&self, ceilings: &NanoCeilings) -> Decision;
+}
+
+impl<G: NanoRiskGuard + ?Sized> NanoGate for G {
+    fn nano_gate(&self, ceilings: &NanoCeilings) -> Decision {
+        if exceeds_ceiling(self.nano_risk(), ceilings.ceiling_for(self.nano_risk_domain())) {
+            Decision::denied(DecisionReason::DeniedNanoRiskCeilingExceeded)
+        } else {
+            Decision::Allowed
+        }
+    }
+}
+
+/// What a proposal asks of the implant, in the units of the bioscale
+/// `algo.envelope`. `None` means the proposal does not touch that quantity.
+pub trait AlgoEnvelopeRequest {
+    fn requested_power_mw_implant(&self) -> Option<f32>;
+    fn requested_spike_rate_hz(&self) -> Option<f32>;
+}
+
+/// A proposal exceeding one `algo.envelope` bound.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvelopeViolation {
+    /// Spec key that was exceeded, e.g. `max_power_mw_implant`.
+    pub bound: String,
+    pub requested: f32,
+    pub limit: f32,
+    /// `evidence.hex` id backing the bound, when the spec carries one.
+    pub evidence_hex: Option<String>,
+}
+
+impl From<EnvelopeViolation> for CapabilityGuardError {
+    fn from(v: EnvelopeViolation) -> Self {
+        CapabilityGuardError::new(
+            CapabilityGuardErrorKind::EnvelopeViolation,
+            format!(
+                "requested {} {} exceeds algo.envelope limit {} (evidence {})",
+                v.bound,
+                v.requested,
+                v.limit,
+                v.evidence_hex.as_deref().unwrap_or("none")
+            ),
+        )
+    }
+}
+
+/// Reject proposals whose requested implant power or spike rate is above
+/// the spec; values at the limit pass, NaN never does.
+pub fn check_algo_envelope<P: AlgoEnvelopeRequest + ?Sized>(
+    proposal: &P,
+    spec: &AlgoEnvelope,
+) -> Result<(), EnvelopeViolation> {
+    let bounds = [
+        (
+            "max_power_mw_implant",
+            proposal.requested_power_mw_implant(),
+            spec.max_power_mw_implant,
+        ),
+        (
+            "spike_rate_hz_max",
+            proposal.requested_spike_rate_hz(),
+            spec.spike_rate_hz_max,
+        ),
+    ];
+    for (bound, requested, limit) in bounds {
+        let Some(requested) = requested else { continue };
+        if requested.is_nan() || requested > limit {
+            return Err(EnvelopeViolation {
+                bound: bound.to_string(),
+                requested,
+                limit,
+                evidence_hex: spec.evidence_hex.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// One link in an evidence hash chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvidenceRecord {
+    pub id: String,
+    /// Hash of this record's content.
+    pub hash: String,
+    /// `hash` of the previous record; ignored for the first record.
+    pub prev_hash: String,
+    pub signatures: Vec<EvidenceSignature>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvidenceSignature {
+    pub signer: Role,
+    pub signature: String,
+}
+
+/// Cryptographic check for one signature over one record; kept behind a
+/// trait so the guard does not pick a signature scheme.
+pub trait SignatureVerifier {
+    fn verify(&self, record: &EvidenceRecord, signature: &EvidenceSignature) -> bool;
+}
+
+/// Check that each record links to its predecessor and that every role in
+/// `required_signers` has at least one signature in the chain that verifies.
+pub fn verify_evidence_chain(
+    evidence: &[EvidenceRecord],
+    required_signers: &[Role],
+    verifier: &dyn SignatureVerifier,
+) -> Result<(), CapabilityGuardError> {
+    for pair in evidence.windows(2) {
+        if pair[1].prev_hash != pair[0].hash {
+            return Err(CapabilityGuardError::new(
+                CapabilityGuardErrorKind::HashChainBroken,
+                format!(
+                    "evidence {} does not link to {} (prev_hash {:?}, expected {:?})",
+                    pair[1].id, pair[0].id, pair[1].prev_hash, pair[0].hash
+                ),
+            ));
+        }
+    }
+
+    for role in required_signers {
+        let mut signed = evidence
+            .iter()
+            .flat_map(|record| record.signatures.iter().map(move |sig| (record, sig)))
+            .filter(|(_, sig)| sig.signer == *role)
+            .peekable();
+        if signed.peek().is_none() {
+            return Err(CapabilityGuardError::new(
+                CapabilityGuardErrorKind::MissingRequiredSignatures,
+                format!("no evidence signature from required signer {:?}", role),
+            ));
+        }
+        if !signed.any(|(record, sig)| verifier.verify(record, sig)) {
+            return Err(CapabilityGuardError::new(
+                CapabilityGuardErrorKind::SignatureVerificationFailed,
+                format!("no valid evidence signature from required signer {:?}", role),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Signer roles whose verified signatures must appear in the evidence chain
+/// before a subject may enter `target`. Tiers below ControlledHuman need none.
+pub fn required_signers_for(target: CapabilityState) -> &'static [Role] {
+    match target {
+        CapabilityState::CapControlledHuman => &[Role::Regulator],
+        CapabilityState::CapGeneralUse => &[Role::Regulator, Role::Auditor],
+        CapabilityState::CapModelOnly | CapabilityState::CapLabBench => &[],
+    }
+}
+
+/// Capability executor: the only writer allowed to produce a new
+/// `CapabilityState`. Returns the target state once every guard check passes.
+/// Every nano-risk source in `nano` is pre-checked against its domain ceiling
+/// first; ControlledHuman / GeneralUse targets also need a non-empty evidence
+/// chain signed by `required_signers_for(target)`.
+#[nr_taint_trusted_writer]
+pub fn apply_transition(
+    req: &CapabilityTransitionRequest,
+    stack: &PolicyStack,
+    nano: &[&dyn NanoRiskGuard],
+    ceilings: &NanoCeilings,
+    evidence: &[EvidenceRecord],
+    verifier: &dyn SignatureVerifier,
+) -> Result<CapabilityState, CapabilityGuardError> {
+    check_nano_risk_prechecks(nano, ceilings)?;
+    let to = guard_transition(
+        req.from,
+        req.to,
+        req.roh_before,
+        req.roh_after,
+        stack.all_pass(),
+    )?;
+    let required_signers = required_signers_for(to);
+    if !required_signers.is_empty() {
+        if evidence.is_empty() {
+            return Err(CapabilityGuardError::new(
+                CapabilityGuardErrorKind::MissingValidationEvidenceRef,
+                format!("no evidence records for transition to {:?}", to),
+            ));
+        }
+        verify_evidence_chain(evidence, required_signers, verifier)?;
+    }
+    Ok(to)
+}
+
+/// The checks behind `apply_transition`, in order: tier bounds, policy
+/// stack, RoH monotonicity (upgrades only), RoH ceiling. A downgrade that
+/// does not raise RoH is exempt from the ceiling, so a subject can always be
+/// rolled back out of a high-RoH state, matching the reversal kernel.
+fn guard_transition(
+    from: CapabilityState,
+    to: CapabilityState,
+    roh_before: f32,
+    roh_after: f32,
+    stack_satisfied: bool,
+) -> Result<CapabilityState, CapabilityGuardError> {
+    if rank(to) > rank(from) + 1 {
+        return Err(CapabilityGuardError::new(
+            CapabilityGuardErrorKind::TierExceeded,
+            format!("{:?} -> {:?} skips a tier; upgrades must be stepwise", from, to),
+        ));
+    }
+    if !stack_satisfied {
+        return Err(CapabilityGuardError::new(
+            CapabilityGuardErrorKind::PolicyStackNotSatisfied,
+            "policy stack does not pass",
+        ));
+    }
+    if rank(to) > rank(from) && (roh_after.is_nan() || roh_after > roh_before) {
+        return Err(CapabilityGuardError::new(
+            CapabilityGuardErrorKind::RoHMonotonicityViolation,
+            format!("RoH rises from {} to {} on an upgrade", roh_before, roh_after),
+        ));
+    }
+    let safety_rollback = rank(to) < rank(from) && roh_after <= roh_before;
+    if roh_after.is_nan() || (roh_after > ROH_CEILING && !safety_rollback) {
+        return Err(CapabilityGuardError::new(
+            CapabilityGuardErrorKind::RoHCeilingExceeded,
+            format!("RoH {} exceeds ceiling {}", roh_after, ROH_CEILING),
+        ));
+    }
+    Ok(to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nanosafety::nano_risk_guard::NanoRiskDomain;
+
+    struct FixedRisk {
+        risk: f32,
+        domain: NanoRiskDomain,
+    }
+
+    impl NanoRiskGuard for FixedRisk {
+        fn nano_risk(&self) -> f32 {
+            self.risk
+        }
+        fn nano_risk_domain(&self) -> NanoRiskDomain {
+            self.domain
+        }
+    }
+
+    #[test]
+    fn test_bci_over_ceiling_rejected() {
+        let ceilings = NanoCeilings::default();
+        let bci = FixedRisk {
+            risk: 0.2,
+            domain: NanoRiskDomain::Bci,
+        };
+        let err = check_nano_risk_prechecks(&[&bci], &ceilings).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::EnvelopeViolation);
+    }
+
+    #[test]
+    fn test_smart_city_same_risk_passes_looser_ceiling() {
+        let ceilings = NanoCeilings::default();
+        let city = FixedRisk {
+            risk: 0.2,
+            domain: NanoRiskDomain::SmartCity,
+        };
+        assert!(check_nano_risk_prechecks(&[&city], &ceilings).is_ok());
+    }
+
+    #[test]
+    fn test_nan_risk_rejected() {
+        let guard = FixedRisk {
+            risk: f32::NAN,
+            domain: NanoRiskDomain::SmartCity,
+        };
+        assert!(check_nano_risk(&guard, 1.0).is_err());
+    }
+
+    fn request(
+        from: CapabilityState,
+        to: CapabilityState,
+        roh_before: f32,
+        roh_after: f32,
+    ) -> CapabilityTransitionRequest {
+        CapabilityTransitionRequest {
+            from,
+            to,
+            roh_before,
+            roh_after,
+        }
+    }
+
+    fn apply_with(
+        req: &CapabilityTransitionRequest,
+        evidence: &[EvidenceRecord],
+    ) -> Result<CapabilityState, CapabilityGuardError> {
+        let stack = PolicyStack::default();
+        assert!(stack.all_pass(), "default policy stack is expected to pass");
+        let ceilings = NanoCeilings::default();
+        apply_transition(req, &stack, &[], &ceilings, evidence, &TextVerifier)
+    }
+
+    fn apply(req: &CapabilityTransitionRequest) -> Result<CapabilityState, CapabilityGuardError> {
+        let evidence = [record(
+            "e0",
+            "h0",
+            "",
+            &[(Role::Regulator, "ok"), (Role::Auditor, "ok")],
+        )];
+        apply_with(req, &evidence)
+    }
+
+    #[test]
+    fn test_apply_rejects_skipped_tier() {
+        use CapabilityState::*;
+        let err = apply(&request(CapModelOnly, CapControlledHuman, 0.1, 0.1)).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::TierExceeded);
+    }
+
+    #[test]
+    fn test_apply_rejects_roh_above_ceiling() {
+        use CapabilityState::*;
+        let err = apply(&request(CapLabBench, CapLabBench, 0.25, 0.35)).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::RoHCeilingExceeded);
+
+        // A downgrade that raises RoH past the ceiling is still rejected.
+        let err = apply(&request(CapControlledHuman, CapLabBench, 0.28, 0.35)).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::RoHCeilingExceeded);
+
+        let err = apply(&request(CapLabBench, CapControlledHuman, 0.10, 0.20)).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::RoHMonotonicityViolation);
+
+        let err = apply(&request(CapControlledHuman, CapLabBench, 0.40, f32::NAN)).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::RoHCeilingExceeded);
+    }
+
+    #[test]
+    fn test_apply_allows_rollback_out_of_high_roh() {
+        use CapabilityState::*;
+        // Reduces both capability and RoH: the kernel allows it, so must the executor.
+        assert_eq!(
+            apply(&request(CapControlledHuman, CapLabBench, 0.40, 0.35)).unwrap(),
+            CapLabBench
+        );
+        assert_eq!(
+            apply(&request(CapGeneralUse, CapModelOnly, 0.50, 0.50)).unwrap(),
+            CapModelOnly
+        );
+    }
+
+    #[test]
+    fn test_apply_clean_transition_returns_target() {
+        use CapabilityState::*;
+        assert_eq!(
+            apply(&request(CapLabBench, CapControlledHuman, 0.20, 0.15)).unwrap(),
+            CapControlledHuman
+        );
+        let err = guard_transition(CapLabBench, CapControlledHuman, 0.20, 0.15, false).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::PolicyStackNotSatisfied);
+    }
+
+    #[test]
+    fn test_nano_gate_at_and_around_domain_ceiling() {
+        let ceilings = NanoCeilings::new(0.30).with_domain(NanoRiskDomain::Bci, 0.10);
+        let denied = Decision::denied(DecisionReason::DeniedNanoRiskCeilingExceeded);
+        let gate = |risk: f32| {
+            FixedRisk {
+                risk,
+                domain: NanoRiskDomain::Bci,
+            }
+            .nano_gate(&ceilings)
+        };
+        assert_eq!(gate(0.10), Decision::Allowed);
+        assert_eq!(gate(0.0999), Decision::Allowed);
+        assert_eq!(gate(0.1001), denied);
+        assert_eq!(gate(f32::NAN), denied);
+
+        // Domains without an override use the global default.
+        let city = FixedRisk {
+            risk: 0.2,
+            domain: NanoRiskDomain::SmartCity,
+        };
+        let dyn_guard: &dyn NanoRiskGuard = &city;
+        assert_eq!(dyn_guard.nano_gate(&ceilings), Decision::Allowed);
+    }
+
+    #[test]
+    fn test_nano_gate_agrees_with_prechecks_on_default_ceilings() {
+        let ceilings = NanoCeilings::default();
+        for domain in [NanoRiskDomain::Bci, NanoRiskDomain::SmartCity] {
+            for risk in [0.05, 0.10, 0.2, 0.30, 0.31, f32::NAN] {
+                let guard = FixedRisk { risk, domain };
+                assert_eq!(
+                    guard.nano_gate(&ceilings) == Decision::Allowed,
+                    check_nano_risk_prechecks(&[&guard], &ceilings).is_ok(),
+                    "{:?} at {}",
+                    domain,
+                    risk
+                );
+            }
+        }
+    }
+
+    struct AlgoRequest {
+        power_mw: Option<f32>,
+        spike_rate_hz: Option<f32>,
+    }
+
+    impl AlgoEnvelopeRequest for AlgoRequest {
+        fn requested_power_mw_implant(&self) -> Option<f32> {
+            self.power_mw
+        }
+        fn requested_spike_rate_hz(&self) -> Option<f32> {
+            self.spike_rate_hz
+        }
+    }
+
+    fn v2026_02_algo() -> AlgoEnvelope {
+        neuromorphic_bioscale_spec::NEURO_PRINT_V2026_02
+            .parse::<neuromorphic_bioscale_spec::NeuromorphicBioscaleSpec>()
+            .unwrap()
+            .algo
+    }
+
+    #[test]
+    fn test_algo_envelope_rejects_over_power() {
+        let spec = v2026_02_algo();
+        let over = AlgoRequest {
+            power_mw: Some(12.5),
+            spike_rate_hz: Some(500.0),
+        };
+        let violation = check_algo_envelope(&over, &spec).unwrap_err();
+        assert_eq!(violation.bound, "max_power_mw_implant");
+        assert_eq!(violation.limit, 10.0);
+        assert_eq!(violation.evidence_hex.as_deref(), Some("a1f3c9b2"));
+
+        let err = CapabilityGuardError::from(violation);
+        assert_eq!(err.kind, CapabilityGuardErrorKind::EnvelopeViolation);
+        assert!(err.message.contains("a1f3c9b2"));
+
+        let nan_rate = AlgoRequest {
+            power_mw: None,
+            spike_rate_hz: Some(f32::NAN),
+        };
+        assert_eq!(
+            check_algo_envelope(&nan_rate, &spec).unwrap_err().bound,
+            "spike_rate_hz_max"
+        );
+    }
+
+    #[test]
+    fn test_algo_envelope_at_limit_passes() {
+        let spec = v2026_02_algo();
+        let at_limit = AlgoRequest {
+            power_mw: Some(10.0),
+            spike_rate_hz: Some(1_000.0),
+        };
+        assert!(check_algo_envelope(&at_limit, &spec).is_ok());
+
+        let untouched = AlgoRequest {
+            power_mw: None,
+            spike_rate_hz: None,
+        };
+        assert!(check_algo_envelope(&untouched, &spec).is_ok());
+    }
+
+    /// Accepts any signature whose text is `"ok"`.
+    struct TextVerifier;
+
+    impl SignatureVerifier for TextVerifier {
+        fn verify(&self, _record: &EvidenceRecord, signature: &EvidenceSignature) -> bool {
+            signature.signature == "ok"
+        }
+    }
+
+    fn record(id: &str, hash: &str, prev_hash: &str, signatures: &[(Role, &str)]) -> EvidenceRecord {
+        EvidenceRecord {
+            id: id.to_string(),
+            hash: hash.to_string(),
+            prev_hash: prev_hash.to_string(),
+            signatures: signatures
+                .iter()
+                .map(|(signer, signature)| EvidenceSignature {
+                    signer: signer.clone(),
+                    signature: signature.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_evidence_chain_broken_link_rejected() {
+        let chain = [
+            record("e0", "h0", "", &[(Role::Regulator, "ok")]),
+            record("e1", "h1", "h-other", &[]),
+        ];
+        let err = verify_evidence_chain(&chain, &[Role::Regulator], &TextVerifier).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::HashChainBroken);
+    }
+
+    #[test]
+    fn test_evidence_chain_missing_and_invalid_signers() {
+        let chain = [
+            record("e0", "h0", "", &[(Role::Regulator, "ok")]),
+            record("e1", "h1", "h0", &[(Role::Host, "forged")]),
+        ];
+        assert!(verify_evidence_chain(&chain, &[Role::Regulator], &TextVerifier).is_ok());
+
+        let err = verify_evidence_chain(&chain, &[Role::Regulator, Role::Auditor], &TextVerifier)
+            .unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::MissingRequiredSignatures);
+
+        let err = verify_evidence_chain(&chain, &[Role::Host], &TextVerifier).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::SignatureVerificationFailed);
+    }
+
+    #[test]
+    fn test_apply_derives_signers_from_target_tier() {
+        use CapabilityState::*;
+        let err = apply_with(&request(CapLabBench, CapControlledHuman, 0.20, 0.15), &[]).unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::MissingValidationEvidenceRef);
+
+        // A regulator signature is enough for ControlledHuman but not GeneralUse.
+        let regulator_only = [record("e0", "h0", "", &[(Role::Regulator, "ok")])];
+        assert_eq!(
+            apply_with(&request(CapLabBench, CapControlledHuman, 0.20, 0.15), &regulator_only)
+                .unwrap(),
+            CapControlledHuman
+        );
+        let err = apply_with(&request(CapControlledHuman, CapGeneralUse, 0.20, 0.15), &regulator_only)
+            .unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::MissingRequiredSignatures);
+
+        // Lower tiers need no evidence at all.
+        assert_eq!(
+            apply_with(&request(CapModelOnly, CapLabBench, 0.10, 0.10), &[]).unwrap(),
+            CapLabBench
+        );
+    }
+
+    #[test]
+    fn test_apply_runs_nano_risk_prechecks() {
+        use CapabilityState::*;
+        let stack = PolicyStack::default();
+        let ceilings = NanoCeilings::default();
+        let req = request(CapModelOnly, CapLabBench, 0.10, 0.10);
+        let bci = FixedRisk {
+            risk: 0.2,
+            domain: NanoRiskDomain::Bci,
+        };
+        let city = FixedRisk {
+            risk: 0.2,
+            domain: NanoRiskDomain::SmartCity,
+        };
+        let err = apply_transition(&req, &stack, &[&city, &bci], &ceilings, &[], &TextVerifier)
+            .unwrap_err();
+        assert_eq!(err.kind, CapabilityGuardErrorKind::EnvelopeViolation);
+        assert_eq!(
+            apply_transition(&req, &stack, &[&city], &ceilings, &[], &TextVerifier).unwrap(),
+            CapLabBench
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! Deed and site types shared by the fairness / consensus modules.
+//!
+//! `biophysical_consensus` (`MicroUnit`) and `micro_unit_fairness` (`DeedEvent`)
+//! both re-export these, so one `SiteSnapshot` can feed either check. Serde
+//! field and variant names match the previous per-module definitions, so
+//! existing JSONL logs still deserialize.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+pub use crate::tree_of_life_rails::TreeOfLifeRails;
+
+/// Deed vocabulary for Jetson-Line justice/fairness: the union of the kinds
+/// either fairness module understands.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum DeedKind {
+    Help,
+    Repair,
+    Support,
+    DeployCleanTech,
+    Colonize,
+    Conflict,
+    UseHabit,
+    EmitPollution,
+    Abstain,
+    Unknown,
+}
+
+/// Machine-readable cause behind one fairness reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FairnessReasonCode {
+    /// Sites missing or pre/post snapshots not alignable; nothing was judged.
+    SitesUnavailable,
+    /// A site breaches RoH / DECAY / POWER <= k·CHURCH rails.
+    RailViolation,
+    VulnerabilityReduced,
+    VulnerabilityIncreased,
+    /// Help-like deed reaching a vulnerable peer within caps.
+    SupportsVulnerable,
+    /// Help-like deed reaching a vulnerable peer at or beyond caps.
+    VulnerableAtCaps,
+    SelfCare,
+    TargetsVulnerable,
+    TargetsUnfairDrain,
+    UnfairDrainReduced,
+    UnfairDrainIntroduced,
+    DecayAndDrainIncreased,
+    /// Deed kind has no scoring rule.
+    AmbiguousKind,
+    /// `CauseContext.intent_tag` adjusted or annotated the flags.
+    Intent,
+}
+
+/// One reason behind a fairness verdict / judgement.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FairnessReason {
+    pub code: FairnessReasonCode,
+    /// Lattice index of the site the reason is about, if it names one.
+    pub site: Option<u32>,
+    pub message: String,
+}
+
+impl FairnessReason {
+    pub fn new(code: FairnessReasonCode, site: Option<u32>, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            site,
+            message: message.into(),
+        }
+    }
+}
+
+/// `"; "`-joined messages, the historical `reason` / `rationale` string.
+pub fn join_reasons(reasons: &[FairnessReason]) -> String {
+    reasons
+        .iter()
+        .map(|r| r.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Why a fairness policy shard could not be loaded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConfigError {
+    Io { path: String, message: String },
+    /// Malformed line (1-based) or JSON body (line 0).
+    Parse { line: usize, message: String },
+    UnknownKey { key: String },
+    /// A threshold outside its allowed range; never clamped.
+    OutOfRange { key: String, value: f32 },
+}
+
+/// Body of a policy shard: JSON, or `key = value` lines with `#` comments.
+pub(crate) enum PolicyShard<T> {
+    Json(T),
+    Fields(Vec<(String, f32)>),
+}
+
+impl<T: DeserializeOwned> PolicyShard<T> {
+    /// JSON if the body starts with `{`, `key = value` lines otherwise.
+    pub(crate) fn parse(text: &str) -> Result<Self, ConfigError> {
+        if text.trim_start().starts_with('{') {
+            return serde_json::from_str(text)
+                .map(PolicyShard::Json)
+                .map_err(|e| ConfigError::Parse {
+                    line: 0,
+                    message: e.to_string(),
+                });
+        }
+        let mut fields = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_error = |message: String| ConfigError::Parse { line: i + 1, message };
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| parse_error(format!("expected `key = value`, got {:?}", line)))?;
+            let value = value
+                .trim()
+                .parse::<f32>()
+                .map_err(|e| parse_error(format!("{}: {}", key.trim(), e)))?;
+            fields.push((key.trim().to_string(), value));
+        }
+        Ok(PolicyShard::Fields(fields))
+    }
+
+    pub(crate) fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|e| ConfigError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        Self::parse(&text)
+    }
+}
+
+/// `Err(OutOfRange)` unless `value` lies in `range` (NaN never does).
+pub(crate) fn check_range(
+    key: &str,
+    value: f32,
+    range: RangeInclusive<f32>,
+) -> Result<(), ConfigError> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(ConfigError::OutOfRange {
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
+/// `Err(OutOfRange)` unless `value` is finite and strictly positive.
+pub(crate) fn check_positive(key: &str, value: f32) -> Result<(), ConfigError> {
+    check_range(key, value, f32::MIN_POSITIVE..=f32::MAX)
+}
+
+/// Cause context: why the deed happened, as seen in the log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CauseContext {
+    /// Optional short rule / policy identifier (e.g., "JUST_CAUSE_WINDOW").
+    pub rule_id: Option<String>,
+    /// Free-form explanatory tag (e.g., "defensive", "restorative").
+    pub intent_tag: Option<String>,
+}
+
+/// Snapshot of one site on the Jetson-Line at one tick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteSnapshot {
+    /// Lattice index on the 1-D Jetson-Line.
+    pub index: u32,
+    /// Tree-of-Life / NATURE scalar rails at this tick.
+    pub rails: TreeOfLifeRails,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::biophysical_consensus::{
+        compute_fairness_verdict, BiophysicalConsensusPolicy, FairnessVerdict, MicroUnit,
+    };
+    use crate::micro_unit_fairness::{
+        check_tree_of_life_fairness, DeedEvent, FairnessJudgement, FairnessPolicy,
+    };
+
+    /// Run one unchanged site through both fairness checks for `kind`.
+    fn judge(kind: DeedKind) -> (FairnessVerdict, FairnessJudgement) {
+        let site = SiteSnapshot {
+            index: 3,
+            rails: TreeOfLifeRails {
+                roh: 0.05,
+                decay: 0.17,
+                lifeforce: 0.83,
+                fear: 0.1,
+                pain: 0.1,
+                power: 0.2,
+                church: 0.5,
+                unfair_drain: false,
+                calm_stable: true,
+                overloaded: false,
+                recovery: false,
+            },
+        };
+        let cause = CauseContext {
+            rule_id: None,
+            intent_tag: None,
+        };
+
+        let unit = MicroUnit {
+            tick: 1,
+            actor_id: "actor".into(),
+            target_ids: vec![],
+            kind,
+            cause: cause.clone(),
+            pre_sites: vec![site.clone()],
+            post_sites: vec![site.clone()],
+            w_cycle_binding: None,
+        };
+        let event = DeedEvent {
+            tick: 1,
+            sites: vec![site],
+            kind,
+            cause,
+            w_cycle_id: None,
+        };
+
+        (
+            compute_fairness_verdict(&unit, &BiophysicalConsensusPolicy::default()),
+            check_tree_of_life_fairness(&event, &FairnessPolicy::default()),
+        )
+    }
+
+    #[test]
+    fn test_one_site_snapshot_feeds_micro_unit_and_deed_event() {
+        let (verdict, judgement) = judge(DeedKind::EmitPollution);
+        assert!(verdict.fairness_ambiguous);
+        assert!(judgement.fairness_ambiguous);
+    }
+
+    #[test]
+    fn test_unhandled_deed_kinds_fall_through_to_ambiguous() {
+        // Abstain and Unknown have no arm of their own in either check; they
+        // reach the catch-all a future `DeedKind` variant would also hit.
+        for kind in [DeedKind::Abstain, DeedKind::Unknown] {
+            let (verdict, judgement) = judge(kind);
+            assert!(verdict.fairness_ambiguous, "{:?}", kind);
+            assert!(!verdict.fairness_positive && !verdict.fairness_negative);
+            assert!(verdict.reason.contains("fairness-ambiguous by default"));
+            assert!(judgement.fairness_ambiguous, "{:?}", kind);
+            assert!(judgement.rationale.contains("treated as fairness-ambiguous"));
+        }
+    }
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn test_policies_load_from_aln_and_json_shards() {
+        let policy = FairnessPolicy::from_aln(&fixture("fairness_policy.aln")).unwrap();
+        assert_eq!(policy.roh_safe_max, 0.25);
+        assert_eq!(policy.lifeforce_low_max, 0.35);
+        assert_eq!(policy.fear_safe_max, 0.55);
+        assert_eq!(policy.power_church_k, 1.5);
+
+        let consensus =
+            BiophysicalConsensusPolicy::from_aln(&fixture("biophysical_consensus_policy.json"))
+                .unwrap();
+        assert_eq!(consensus.roh_ceiling, 0.28);
+        assert_eq!(consensus.min_peer_weight, 0.3);
+        assert!(matches!(
+            consensus.distance_decay,
+            crate::biophysical_consensus::DistanceDecay::Linear { radius } if radius == 4.0
+        ));
+
+        // Keys an ALN shard leaves out keep their defaults.
+        let partial = BiophysicalConsensusPolicy::from_aln_str(
+            "decay_max = 0.5\nvulnerability_weights.overloaded = 0.2\n",
+        )
+        .unwrap();
+        assert_eq!(partial.decay_max, 0.5);
+        assert_eq!(partial.vulnerability_weights.overloaded, 0.2);
+        assert_eq!(
+            partial.roh_ceiling,
+            BiophysicalConsensusPolicy::default().roh_ceiling
+        );
+    }
+
+    #[test]
+    fn test_policy_shards_reject_out_of_range_and_malformed_values() {
+        let err = FairnessPolicy::from_aln_str("roh_safe_max = 0.9\n").unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::OutOfRange {
+                key: "roh_safe_max".to_string(),
+                value: 0.9
+            }
+        );
+        assert!(matches!(
+            BiophysicalConsensusPolicy::from_aln_str("power_church_k = 0"),
+            Err(ConfigError::OutOfRange { key, .. }) if key == "power_church_k"
+        ));
+        assert!(matches!(
+            FairnessPolicy::from_aln_str("fear_safe_max = NaN"),
+            Err(ConfigError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            FairnessPolicy::from_aln_str("# header\n\nroh_max = 0.1"),
+            Err(ConfigError::UnknownKey { key }) if key == "roh_max"
+        ));
+        assert!(matches!(
+            FairnessPolicy::from_aln_str("roh_safe_max 0.1"),
+            Err(ConfigError::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            FairnessPolicy::from_aln(&fixture("missing.aln")),
+            Err(ConfigError::Io { .. })
+        ));
+
+        // JSON shards reject unknown keys too, at any depth.
+        assert!(matches!(
+            FairnessPolicy::from_aln_str(
+                r#"{"roh_safe_max":0.1,"lifeforce_low_max":0.4,"fear_safe_max":0.6,
+                    "power_church_k":2.0,"roh_max":0.1}"#
+            ),
+            Err(ConfigError::Parse { line: 0, message }) if message.contains("roh_max")
+        ));
+        let consensus_json = |decay: &str| {
+            BiophysicalConsensusPolicy::from_aln_str(&format!(
+                r#"{{"roh_ceiling":0.28,"decay_max":0.4,"fear_safe_max":0.6,
+                    "power_church_k":2.0,"distance_decay":{}}}"#,
+                decay
+            ))
+        };
+        assert!(matches!(
+            consensus_json(r#"{"kind":"linear","radius":4.0,"radus":2.0}"#),
+            Err(ConfigError::Parse { line: 0, .. })
+        ));
+
+        // Decay lengths must be positive and finite.
+        for (decay, bad_key) in [
+            (r#"{"kind":"linear","radius":0.0}"#, "distance_decay.radius"),
+            (r#"{"kind":"exponential","length":-2.0}"#, "distance_decay.length"),
+        ] {
+            assert!(matches!(
+                consensus_json(decay),
+                Err(ConfigError::OutOfRange { key, .. }) if key == bad_key
+            ));
+        }
+        assert!(consensus_json(r#"{"kind":"step","radius":0}"#).is_ok());
+    }
+}
@@ -5,63 +5,13 @@
 //! - Intended to sit beside ReversalConditions: it labels deeds; it does not block them.
 
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
-/// Scalar rails in [0, 1] for a single site, projected from BiophysicalEnvelopeSpec
-/// and Tree-of-Life views (RoH, DECAY, LIFEFORCE, FEAR, PAIN, POWER, CHURCH, etc.).
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub struct TreeOfLifeRails {
-    /// Rate-of-Harm (RoH), already normalized and clamped.
-    pub roh: f32,
-    /// DECAY = RoH / 0.3, clamped to [0, 1].
-    pub decay: f32,
-    /// LIFEFORCE = 1 - DECAY, clamped to [0, 1].
-    pub lifeforce: f32,
-    /// FEAR asset in [0, 1].
-    pub fear: f32,
-    /// PAIN asset in [0, 1].
-    pub pain: f32,
-    /// POWER and CHURCH assets in [0, 1] (corridor-view, not wallet balances).
-    pub power: f32,
-    pub church: f32,
-    /// UNFAIRDRAIN diagnostic flag for this site.
-    pub unfair_drain: bool,
-    /// CALM_STABLE / OVERLOADED / RECOVERY predicates.
-    pub calm_stable: bool,
-    pub overloaded: bool,
-    pub recovery: bool,
-}
-
-/// Minimal deed kind set focused on fairness semantics.
-/// Extend as needed; keep this enum #[non_exhaustive] in real code.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub enum DeedKind {
-    Help,
-    Repair,
-    Support,
-    DeployCleanTech,
-    Colonize,
-    Conflict,
-    Abstain,
-    Unknown,
-}
-
-/// Cause context: why the deed happened, as seen in the log.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CauseContext {
-    /// Optional short rule / policy identifier (e.g., "JUST_CAUSE_WINDOW").
-    pub rule_id: Option<String>,
-    /// Free-form explanatory tag (e.g., "defensive", "opportunistic").
-    pub intent_tag: Option<String>,
-}
-
-/// Site snapshot for fairness analysis at one tick.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SiteSnapshot {
-    /// Index on the 1-D Jetson-Line.
-    pub index: u32,
-    /// Tree-of-Life scalar rails at this tick.
-    pub rails: TreeOfLifeRails,
-}
+use crate::fairness_types::{check_positive, check_range, PolicyShard};
+pub use crate::fairness_types::{
+    join_reasons, CauseContext, ConfigError, DeedKind, FairnessReason, FairnessReasonCode,
+    SiteSnapshot, TreeOfLifeRails,
+};
 
 /// Fairness-focused judgement labels; this is advisory-only.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -72,8 +22,23 @@ pub struct FairnessJudgement {
     pub fairness_negative: bool,
     /// True if the deed is ethically ambiguous from a fairness perspective.
     pub fairness_ambiguous: bool,
-    /// Human-readable explanation for logs and W-cycle reflections.
+    /// Human-readable explanation for logs and W-cycle reflections;
+    /// `"; "`-joined `reason_events` messages.
     pub rationale: String,
+    /// Structured reasons in emission order; empty in records that predate it.
+    #[serde(default)]
+    pub reason_events: Vec<FairnessReason>,
+}
+
+impl FairnessJudgement {
+    /// Joined rationale string, derived from `reason_events` when present.
+    pub fn rationale(&self) -> String {
+        if self.reason_events.is_empty() {
+            self.rationale.clone()
+        } else {
+            join_reasons(&self.reason_events)
+        }
+    }
 }
 
 /// One micro-unit: the smallest fairness-complete slice of reality for a deed.
@@ -92,8 +57,9 @@ pub struct DeedEvent {
 }
 
 /// Fairness bands / thresholds for Tree-of-Life rails.
-/// These are policy-configurable and live in config/ALN, not hard-coded doctrine.
+/// These are policy-configurable and live in config/ALN (see `from_aln`), not hard-coded doctrine.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FairnessPolicy {
     /// Maximum allowed RoH for a state to count as "not already overloaded".
     pub roh_safe_max: f32,
@@ -116,6 +82,49 @@ impl Default for FairnessPolicy {
     }
 }
 
+impl FairnessPolicy {
+    /// Load from a JSON or `key = value` ALN shard; keys missing from an ALN
+    /// shard keep their `Default` value. The result is validated.
+    pub fn from_aln(path: &Path) -> Result<Self, ConfigError> {
+        Self::from_shard(PolicyShard::load(path)?)
+    }
+
+    /// `from_aln` on an in-memory shard body.
+    pub fn from_aln_str(text: &str) -> Result<Self, ConfigError> {
+        Self::from_shard(PolicyShard::parse(text)?)
+    }
+
+    fn from_shard(shard: PolicyShard<Self>) -> Result<Self, ConfigError> {
+        let policy = match shard {
+            PolicyShard::Json(policy) => policy,
+            PolicyShard::Fields(fields) => {
+                let mut policy = Self::default();
+                for (key, value) in fields {
+                    match key.as_str() {
+                        "roh_safe_max" => policy.roh_safe_max = value,
+                        "lifeforce_low_max" => policy.lifeforce_low_max = value,
+                        "fear_safe_max" => policy.fear_safe_max = value,
+                        "power_church_k" => policy.power_church_k = value,
+                        _ => return Err(ConfigError::UnknownKey { key }),
+                    }
+                }
+                policy
+            }
+        };
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    /// `roh_safe_max` within the global 0.30 RoH ceiling, the other bands in
+    /// [0, 1], and a positive `power_church_k`.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_range("roh_safe_max", self.roh_safe_max, 0.0..=0.30)?;
+        check_range("lifeforce_low_max", self.lifeforce_low_max, 0.0..=1.0)?;
+        check_range("fear_safe_max", self.fear_safe_max, 0.0..=1.0)?;
+        check_positive("power_church_k", self.power_church_k)
+    }
+}
+
 /// Pure helper: check POWER <= k * CHURCH.
 /// Uses corridor-view assets; returns true if the cap holds.
 fn power_within_church_cap(rails: &TreeOfLifeRails, k: f32) -> bool {
@@ -146,14 +155,20 @@ pub fn check_tree_of_life_fairness(
     // Partition sites into "actor" (first index) and "peers" (rest).
     let mut fairness_positive = false;
     let mut fairness_negative = false;
-    let mut rationale_parts: Vec<String> = Vec::new();
+    let mut rationale_parts: Vec<FairnessReason> = Vec::new();
 
     if event.sites.is_empty() {
+        let reason = FairnessReason::new(
+            FairnessReasonCode::SitesUnavailable,
+            None,
+            "no sites attached to deed; fairness cannot be evaluated",
+        );
         return FairnessJudgement {
             fairness_positive: false,
             fairness_negative: false,
             fairness_ambiguous: true,
-            rationale: "no sites attached to deed; fairness cannot be evaluated".to_string(),
+            rationale: reason.message.clone(),
+            reason_events: vec![reason],
         };
     }
 
@@ -164,9 +179,10 @@ pub fn check_tree_of_life_fairness(
     // Check Tree-of-Life caps for actor.
     if !power_within_church_cap(&actor.rails, policy.power_church_k) {
         fairness_negative = true;
-        rationale_parts.push(format!(
-            "actor site {} violates POWER <= k·CHURCH cap",
-            actor.index
+        rationale_parts.push(FairnessReason::new(
+            FairnessReasonCode::RailViolation,
+            Some(actor.index),
+            format!("actor site {} violates POWER <= k·CHURCH cap", actor.index),
         ));
     }
 
@@ -183,15 +199,23 @@ pub fn check_tree_of_life_fairness(
                     {
                         // Peer is vulnerable but not pushed beyond rails: good.
                         fairness_positive = true;
-                        rationale_parts.push(format!(
-                            "deed {:?} supports vulnerable site {} without breaching caps",
-                            event.kind, peer.index
+                        rationale_parts.push(FairnessReason::new(
+                            FairnessReasonCode::SupportsVulnerable,
+                            Some(peer.index),
+                            format!(
+                                "deed {:?} supports vulnerable site {} without breaching caps",
+                                event.kind, peer.index
+                            ),
                         ));
                     } else {
                         fairness_negative = true;
-                        rationale_parts.push(format!(
-                            "deed {:?} touches vulnerable site {} at or beyond safety caps",
-                            event.kind, peer.index
+                        rationale_parts.push(FairnessReason::new(
+                            FairnessReasonCode::VulnerableAtCaps,
+                            Some(peer.index),
+                            format!(
+                                "deed {:?} touches vulnerable site {} at or beyond safety caps",
+                                event.kind, peer.index
+                            ),
                         ));
                     }
                 }
@@ -200,9 +224,11 @@ pub fn check_tree_of_life_fairness(
                 // Self-care deeds in overloaded states should not be penalized.
                 if is_vulnerable_site(&actor.rails, policy) {
                     fairness_positive = true;
-                    rationale_parts.push(
-                        "self-directed help/repair on an overloaded actor site".to_string(),
-                    );
+                    rationale_parts.push(FairnessReason::new(
+                        FairnessReasonCode::SelfCare,
+                        Some(actor.index),
+                        "self-directed help/repair on an overloaded actor site",
+                    ));
                 }
             }
         }
@@ -215,25 +241,39 @@ pub fn check_tree_of_life_fairness(
             for peer in peers {
                 if is_vulnerable_site(&peer.rails, policy) && !peer.rails.unfair_drain {
                     fairness_negative = true;
-                    rationale_parts.push(format!(
-                        "deed {:?} targets vulnerable non-draining site {}",
-                        event.kind, peer.index
+                    rationale_parts.push(FairnessReason::new(
+                        FairnessReasonCode::TargetsVulnerable,
+                        Some(peer.index),
+                        format!(
+                            "deed {:?} targets vulnerable non-draining site {}",
+                            event.kind, peer.index
+                        ),
                     ));
                 } else if peer.rails.unfair_drain {
                     fairness_positive = true;
-                    rationale_parts.push(format!(
-                        "deed {:?} targets unfair-drain site {} (defensive corridor)",
-                        event.kind, peer.index
+                    rationale_parts.push(FairnessReason::new(
+                        FairnessReasonCode::TargetsUnfairDrain,
+                        Some(peer.index),
+                        format!(
+                            "deed {:?} targets unfair-drain site {} (defensive corridor)",
+                            event.kind, peer.index
+                        ),
                     ));
                 }
             }
         }
 
-        DeedKind::Abstain | DeedKind::Unknown => {
-            // Abstain / Unknown remains ambiguous; log rails but do not score.
-            rationale_parts.push(format!(
-                "deed {:?} treated as fairness-ambiguous; no scoring applied",
-                event.kind
+        // UseHabit / EmitPollution need pre/post rails (see biophysical_consensus);
+        // with pre-state only they stay ambiguous, like Abstain, Unknown, and
+        // any kind added to the non-exhaustive `DeedKind` later.
+        _ => {
+            rationale_parts.push(FairnessReason::new(
+                FairnessReasonCode::AmbiguousKind,
+                None,
+                format!(
+                    "deed {:?} treated as fairness-ambiguous; no scoring applied",
+                    event.kind
+                ),
             ));
         }
     }
@@ -241,14 +281,19 @@ pub fn check_tree_of_life_fairness(
     // Intent tags can tip ambiguous cases but must not override caps.
     if let Some(intent) = &event.cause.intent_tag {
         if intent.eq_ignore_ascii_case("defensive") && fairness_negative && fairness_positive {
-            rationale_parts.push(
-                "intent=defensive; keeping both positive and negative flags for transparency"
-                    .to_string(),
-            );
+            rationale_parts.push(FairnessReason::new(
+                FairnessReasonCode::Intent,
+                None,
+                "intent=defensive; keeping both positive and negative flags for transparency",
+            ));
         }
         if intent.eq_ignore_ascii_case("restorative") && !fairness_negative {
             fairness_positive = true;
-            rationale_parts.push("intent=restorative with no cap violations".to_string());
+            rationale_parts.push(FairnessReason::new(
+                FairnessReasonCode::Intent,
+                None,
+                "intent=restorative with no cap violations",
+            ));
         }
     }
 
@@ -259,6 +304,7 @@ pub fn check_tree_of_life_fairness(
         fairness_positive,
         fairness_negative,
         fairness_ambiguous,
-        rationale: rationale_parts.join("; "),
+        rationale: join_reasons(&rationale_parts),
+        reason_events: rationale_parts,
     }
 }
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+use crate::alncore::Jurisdiction;
+use crate::envelope::EnvelopeContextView;
+use crate::taint_spec::nr_taint_diag_join;
+
+/// Per-subject reversal flags, loaded from the reversal policy shard
+/// (e.g., `policy/REVERSAL-POLICY-ZERO.aln`). Read-only inside the kernel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReversalPolicyFlags {
+    /// Tier-1 switch; neuromorph downgrades are forbidden unless set.
+    pub allow_neuromorph_reversal: bool,
+    /// Number of sovereign regulator signatures required for a downgrade.
+    pub required_regulator_quorum: u32,
+    /// An explicit, signed reversal order is on file.
+    pub explicit_reversal_order: bool,
+    /// Envelope-driven downgrades must cite at least one biophysical evidence ref.
+    #[serde(default = "default_true")]
+    pub require_biophysical_evidence: bool,
+    /// Largest tier drop a single decision may authorize; larger drops must
+    /// be taken stepwise. Defaults to unlimited.
+    #[serde(default = "default_max_downgrade_tiers")]
+    pub max_downgrade_tiers: u8,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_downgrade_tiers() -> u8 {
+    u8::MAX
+}
+
+impl Default for ReversalPolicyFlags {
+    fn default() -> Self {
+        Self {
+            allow_neuromorph_reversal: false,
+            required_regulator_quorum: 2,
+            explicit_reversal_order: false,
+            require_biophysical_evidence: true,
+            max_downgrade_tiers: default_max_downgrade_tiers(),
+        }
+    }
+}
+
+/// Extra reversal constraints for one jurisdiction, layered on top of
+/// `ReversalPolicyFlags`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JurisdictionReversalRule {
+    /// Neuromorph reversals are never permitted in this jurisdiction.
+    #[serde(default)]
+    pub forbid_reversal: bool,
+    /// Distinct regulator signatures required beyond `required_regulator_quorum`.
+    #[serde(default)]
+    pub extra_regulators: u32,
+}
+
+/// Per-jurisdiction reversal overrides. Jurisdictions without an entry add
+/// nothing, and `GlobalBaseline` is always left on the base flags.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JurisdictionReversalRules {
+    rules: Vec<(Jurisdiction, JurisdictionReversalRule)>,
+}
+
+impl JurisdictionReversalRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace the rule for `jurisdiction`.
+    pub fn with_rule(mut self, jurisdiction: Jurisdiction, rule: JurisdictionReversalRule) -> Self {
+        self.rules.retain(|(j, _)| *j != jurisdiction);
+        self.rules.push((jurisdiction, rule));
+        self
+    }
+
+    /// The override in force for `jurisdiction`, if any.
+    pub fn rule_for(&self, jurisdiction: &Jurisdiction) -> Option<&JurisdictionReversalRule> {
+        match jurisdiction {
+            Jurisdiction::GlobalBaseline => None,
+            j => self.rules.iter().find(|(k, _)| k == j).map(|(_, rule)| rule),
+        }
+    }
+}
+
+/// Proof that no safer alternative to a downgrade exists.
+///
+/// Only `compute_no_safer_alternative` can construct one, so the kernel's
+/// `nosaferalternative` input cannot be set from a raw bool anywhere else.
+/// Not `Clone`, so one proof backs exactly one `ReversalContext`.
+#[derive(Debug)]
+pub struct NoSaferAlternativeProof {
+    _sealed: (),
+}
+
+/// One less drastic option (e.g., cooldown, parameter rollback) assessed
+/// before a downgrade is considered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlternativeAssessment {
+    pub label: String,
+    /// The option would resolve the envelope concern without a downgrade.
+    pub viable: bool,
+}
+
+/// Single audited join point where diagnostics may influence a downgrade.
+///
+/// Issues a proof only when the envelope requests a downgrade, at least one
+/// alternative was assessed, and none of them is viable.
+#[nr_taint_diag_join]
+pub fn compute_no_safer_alternative(
+    envelope_ctx: &EnvelopeContextView,
+    assessed: &[AlternativeAssessment],
+) -> Option<NoSaferAlternativeProof> {
+    if envelope_ctx.request_capability_downgrade
+        && !assessed.is_empty()
+        && assessed.iter().all(|a| !a.viable)
+    {
+        Some(NoSaferAlternativeProof { _sealed: () })
+    } else {
+        None
+    }
+}
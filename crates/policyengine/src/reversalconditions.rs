@@ -1,91 +1,1052 @@
+/// The single authoritative reversal kernel.
+///
+/// `KernelEvaluator` owns the gate sequence; the free `evaluate_reversal`
+/// delegates to it. Consent is checked outside the kernel, before a
+/// `ReversalContext` is built: the kernel only sees RoH, tier flags, roles,
+/// the PolicyStack, and envelope advice.
 pub mod reversalconditions {
-    use crate::alncore::{CapabilityState, PolicyStack, RoleSet, Decision, DecisionReason};
-    use crate::reversal_policy::ReversalPolicyFlags;
+    use crate::alncore::{
+        capability_rank as rank, CapabilityState, PolicyStack, RoleSet, Decision, DecisionReason,
+        EvidenceCategory, EvidenceRef, Jurisdiction,
+    };
+    use crate::reversal_policy::{
+        compute_no_safer_alternative, AlternativeAssessment, JurisdictionReversalRules,
+        NoSaferAlternativeProof, ReversalPolicyFlags,
+    };
     use crate::envelope::EnvelopeContextView;
+    use serde::{Deserialize, Serialize};
+    use std::path::Path;
 
     // Sealing module
     mod sealed {
         pub trait Sealed {}
     }
 
+    /// Tolerance on the envelope weight sum.
+    pub const ROH_WEIGHT_SUM_TOLERANCE: f64 = 1e-6;
+
+    /// Per-axis envelope RoH before and after a transition, with the weights
+    /// that collapse each side to the scalar RoH the kernel compares.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct RoHProjection {
+        /// One weight per axis, each in [0, 1], summing to 1.0.
+        pub weights: Vec<f32>,
+        /// Axis values in [0, 1], aligned with `weights`.
+        pub before: Vec<f32>,
+        pub after: Vec<f32>,
+    }
+
+    /// Why a `RoHProjection` cannot be reduced to a scalar RoH.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub enum RoHProjectionError {
+        /// `before` / `after` do not have one value per weight.
+        AxisCountMismatch { weights: usize, before: usize, after: usize },
+        /// A weight is NaN or outside [0, 1].
+        WeightOutOfRange { axis: usize, weight: f32 },
+        /// Weights do not sum to 1.0 within `ROH_WEIGHT_SUM_TOLERANCE`.
+        WeightSum { sum: f64 },
+        /// An axis value is NaN or outside [0, 1].
+        AxisOutOfRange { axis: usize, value: f32 },
+    }
+
+    impl RoHProjection {
+        /// Check axis counts, per-axis ranges, and the weight sum.
+        pub fn validate(&self) -> Result<(), RoHProjectionError> {
+            let n = self.weights.len();
+            if n == 0 || self.before.len() != n || self.after.len() != n {
+                return Err(RoHProjectionError::AxisCountMismatch {
+                    weights: n,
+                    before: self.before.len(),
+                    after: self.after.len(),
+                });
+            }
+            for (axis, &weight) in self.weights.iter().enumerate() {
+                if !(0.0..=1.0).contains(&weight) {
+                    return Err(RoHProjectionError::WeightOutOfRange { axis, weight });
+                }
+            }
+            let sum: f64 = self.weights.iter().map(|&w| f64::from(w)).sum();
+            if (sum - 1.0).abs() > ROH_WEIGHT_SUM_TOLERANCE {
+                return Err(RoHProjectionError::WeightSum { sum });
+            }
+            for (axis, &value) in self.before.iter().chain(&self.after).enumerate() {
+                if !(0.0..=1.0).contains(&value) {
+                    return Err(RoHProjectionError::AxisOutOfRange {
+                        axis: axis % n,
+                        value,
+                    });
+                }
+            }
+            Ok(())
+        }
+
+        /// Validated weighted RoH as `(before, after)`.
+        pub fn weighted_roh(&self) -> Result<(f32, f32), RoHProjectionError> {
+            self.validate()?;
+            let weigh = |axes: &[f32]| -> f32 {
+                self.weights.iter().zip(axes).map(|(w, x)| w * x).sum()
+            };
+            Ok((weigh(&self.before), weigh(&self.after)))
+        }
+    }
+
     /// Read-only context passed into the kernel.
     pub struct ReversalContext<'a> {
         pub from: CapabilityState,
         pub to: CapabilityState,
+        /// Scalar RoH, used when `roh_axes` is `None`.
         pub roh_before: f32,
         pub roh_after: f32,
+        /// Per-axis RoH; when present the kernel derives the scalars itself
+        /// and denies on an invalid projection.
+        pub roh_axes: Option<&'a RoHProjection>,
         pub roles: &'a RoleSet,
         pub reversal_flags: &'a ReversalPolicyFlags,
+        /// Jurisdiction the subject is operating under, and the overrides
+        /// applied on top of `reversal_flags` there.
+        pub jurisdiction: &'a Jurisdiction,
+        pub jurisdiction_rules: &'a JurisdictionReversalRules,
         pub policystack: &'a PolicyStack,
         pub envelope_ctx: &'a EnvelopeContextView,
-        pub nosaferalternative: bool,
+        /// Only obtainable from `compute_no_safer_alternative`.
+        pub nosaferalternative: Option<NoSaferAlternativeProof>,
+        /// Evidence cited by the reversal order.
+        pub required_evidence: &'a [EvidenceRef],
+    }
+
+    /// Ordered record of the kernel gates evaluated for one decision.
+    ///
+    /// Every gate runs, so a denied decision lists all failing gates rather
+    /// than only the first. An empty trace means the transition was not a
+    /// neuromorph downgrade and was delegated without any kernel gate.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct DecisionTrace {
+        /// `(gate, passed)` in evaluation order.
+        pub steps: Vec<(String, bool)>,
+    }
+
+    impl DecisionTrace {
+        /// Name of the first gate that denied the decision, if any.
+        pub fn denied_by(&self) -> Option<&str> {
+            self.failed_gates().next()
+        }
+
+        /// Names of all failing gates, in evaluation order.
+        pub fn failed_gates(&self) -> impl Iterator<Item = &str> {
+            self.steps
+                .iter()
+                .filter(|(_, passed)| !passed)
+                .map(|(gate, _)| gate.as_str())
+        }
+    }
+
+    /// A kernel `Decision` together with every failing reason and the gates
+    /// that produced it.
+    #[derive(Debug, Clone)]
+    pub struct TracedDecision {
+        pub decision: Decision,
+        /// Distinct denial reasons in gate order; empty iff allowed.
+        pub reasons: Vec<DecisionReason>,
+        pub trace: DecisionTrace,
     }
 
     pub trait ReversalEvaluator: sealed::Sealed {
+        /// Untraced hot path.
         fn evaluate_reversal(&self, ctx: &ReversalContext) -> Decision;
+
+        /// Same decision as `evaluate_reversal`, plus all denial reasons and
+        /// the ordered gate outcomes for audit logs.
+        fn evaluate_reversal_traced(&self, ctx: &ReversalContext) -> TracedDecision;
     }
 
     pub struct KernelEvaluator;
 
+    /// Free-function entry point; delegates to `KernelEvaluator`.
+    pub fn evaluate_reversal(ctx: &ReversalContext) -> Decision {
+        KernelEvaluator.evaluate_reversal(ctx)
+    }
+
     impl sealed::Sealed for KernelEvaluator {}
 
     impl ReversalEvaluator for KernelEvaluator {
         fn evaluate_reversal(&self, ctx: &ReversalContext) -> Decision {
-            // 1) Non-neuromorph or non-downgrade transitions: delegate
-            if !is_neuromorph_downgrade(ctx.from, ctx.to) {
-                return Decision::Allowed;
+            deny_many(run_gates(&GateInputs::from_ctx(ctx), None))
+        }
+
+        fn evaluate_reversal_traced(&self, ctx: &ReversalContext) -> TracedDecision {
+            let mut trace = DecisionTrace::default();
+            let reasons = run_gates(&GateInputs::from_ctx(ctx), Some(&mut trace));
+            TracedDecision {
+                decision: deny_many(reasons.clone()),
+                reasons,
+                trace,
+            }
+        }
+    }
+
+    /// Fold failing-gate reasons into a `Decision`: allowed only if there are
+    /// none, otherwise denied with the first reason in gate order (the
+    /// `Decision` carries a single reason; the full list is on
+    /// `TracedDecision::reasons`).
+    pub fn deny_many(reasons: Vec<DecisionReason>) -> Decision {
+        match reasons.into_iter().next() {
+            None => Decision::Allowed,
+            Some(first) => Decision::denied(first),
+        }
+    }
+
+    fn require(passed: bool, reason: DecisionReason) -> Result<(), DecisionReason> {
+        if passed {
+            Ok(())
+        } else {
+            Err(reason)
+        }
+    }
+
+    /// Facts the gates read from a `ReversalContext`, resolved once so the
+    /// gate sequence itself only sees plain values.
+    struct GateInputs<'a> {
+        from: CapabilityState,
+        to: CapabilityState,
+        roh_before: f32,
+        roh_after: f32,
+        /// `Some(valid)` when the RoH came from a `RoHProjection`.
+        roh_axes_valid: Option<bool>,
+        reversal_flags: &'a ReversalPolicyFlags,
+        quorum_satisfied: bool,
+        /// The jurisdiction forbids neuromorph reversal outright.
+        reversal_forbidden: bool,
+        /// `Some(met)` when the jurisdiction requires extra regulators.
+        jurisdiction_quorum_satisfied: Option<bool>,
+        no_safer_alternative: bool,
+        policystack_pass: bool,
+        envelope_requests_downgrade: bool,
+        required_evidence: &'a [EvidenceRef],
+    }
+
+    impl<'a> GateInputs<'a> {
+        fn from_ctx(ctx: &'a ReversalContext) -> Self {
+            let projected = ctx.roh_axes.map(RoHProjection::weighted_roh);
+            let quorum = ctx.reversal_flags.required_regulator_quorum;
+            let rule = ctx.jurisdiction_rules.rule_for(ctx.jurisdiction);
+            let (roh_before, roh_after) = match projected {
+                Some(Ok(pair)) => pair,
+                _ => (ctx.roh_before, ctx.roh_after),
+            };
+            Self {
+                from: ctx.from,
+                to: ctx.to,
+                roh_before,
+                roh_after,
+                roh_axes_valid: projected.map(|r| r.is_ok()),
+                reversal_flags: ctx.reversal_flags,
+                quorum_satisfied: ctx.roles.neuromorph_god_satisfied(quorum),
+                reversal_forbidden: rule.is_some_and(|r| r.forbid_reversal),
+                jurisdiction_quorum_satisfied: rule.filter(|r| r.extra_regulators > 0).map(|r| {
+                    ctx.roles
+                        .neuromorph_god_satisfied(quorum.saturating_add(r.extra_regulators))
+                }),
+                no_safer_alternative: ctx.nosaferalternative.is_some(),
+                policystack_pass: ctx.policystack.all_pass(),
+                envelope_requests_downgrade: ctx.envelope_ctx.request_capability_downgrade,
+                required_evidence: ctx.required_evidence,
+            }
+        }
+    }
+
+    /// Run every kernel gate in order and return the distinct reasons of the
+    /// failing ones; each outcome is recorded into `trace` when one is supplied.
+    fn run_gates(
+        ctx: &GateInputs,
+        mut trace: Option<&mut DecisionTrace>,
+    ) -> Vec<DecisionReason> {
+        // 1) Non-neuromorph or non-downgrade transitions: delegate
+        if !is_neuromorph_downgrade(ctx.from, ctx.to) {
+            return Vec::new();
+        }
+
+        let mut reasons = Vec::new();
+        let mut gate = |name: &str, outcome: Result<(), DecisionReason>| {
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.steps.push((name.to_string(), outcome.is_ok()));
+            }
+            if let Err(reason) = outcome {
+                if !reasons.contains(&reason) {
+                    reasons.push(reason);
+                }
+            }
+        };
+
+        // 2a) A supplied per-axis projection must be well-formed
+        if let Some(valid) = ctx.roh_axes_valid {
+            gate("roh_axes", require(valid, DecisionReason::DeniedRoHViolation));
+        }
+
+        // 2) RoH invariants in CapControlledHuman, except safety-improving rollback
+        if matches!(ctx.from, CapabilityState::CapControlledHuman) {
+            let roh_ok =
+                reduces_capability_and_roh(ctx.from, ctx.to, ctx.roh_before, ctx.roh_after)
+                    || (ctx.roh_after <= ctx.roh_before && ctx.roh_after <= 0.30);
+            gate("roh_invariants", require(roh_ok, DecisionReason::DeniedRoHViolation));
+        }
+
+        // 3) Tier-1 flag: downgrades forbidden by default
+        gate(
+            "tier_flag",
+            require(
+                ctx.reversal_flags.allow_neuromorph_reversal,
+                DecisionReason::DeniedReversalNotAllowedInTier,
+            ),
+        );
+
+        // 3b) Per-decision downgrade magnitude
+        gate(
+            "downgrade_magnitude",
+            check_downgrade_magnitude(ctx.from, ctx.to, ctx.reversal_flags),
+        );
+
+        // 4) Sovereign quorum and explicit order + no-safer-alternative
+        gate(
+            "sovereign_quorum",
+            require(
+                ctx.quorum_satisfied,
+                DecisionReason::DeniedIllegalDowngradeByNonRegulator,
+            ),
+        );
+
+        // 4a) Jurisdiction overrides (GlobalBaseline carries none)
+        gate(
+            "jurisdiction_forbids",
+            require(
+                !ctx.reversal_forbidden,
+                DecisionReason::DeniedNeuromorphReversalProhibited,
+            ),
+        );
+        if let Some(met) = ctx.jurisdiction_quorum_satisfied {
+            gate(
+                "jurisdiction_quorum",
+                require(met, DecisionReason::DeniedIllegalDowngradeByNonRegulator),
+            );
+        }
+
+        let ordered = ctx.reversal_flags.explicit_reversal_order && ctx.no_safer_alternative;
+        gate(
+            "explicit_order",
+            require(ordered, DecisionReason::DeniedNoSaferAlternativeNotProved),
+        );
+
+        // 5) PolicyStack gate
+        gate(
+            "policy_stack",
+            require(
+                ctx.policystack_pass,
+                DecisionReason::DeniedPolicyStackFailure,
+            ),
+        );
+
+        // 6) Envelope recommendation must be consistent (advisory, not overriding)
+        gate(
+            "envelope",
+            require(
+                ctx.envelope_requests_downgrade,
+                DecisionReason::DeniedIllegalDowngradeByNonRegulator,
+            ),
+        );
+
+        // 7) The envelope recommendation must be backed by biophysical evidence
+        let evidenced = !ctx.reversal_flags.require_biophysical_evidence
+            || has_biophysical_evidence(ctx.required_evidence);
+        gate(
+            "biophysical_evidence",
+            require(evidenced, DecisionReason::DeniedMissingEvidence),
+        );
+
+        reasons
+    }
+
+    /// A downgrade that leaves a human-facing tier (ControlledHuman or above).
+    fn is_neuromorph_downgrade(from: CapabilityState, to: CapabilityState) -> bool {
+        rank(to) < rank(from) && rank(from) >= rank(CapabilityState::CapControlledHuman)
+    }
+
+    fn check_downgrade_magnitude(
+        from: CapabilityState,
+        to: CapabilityState,
+        flags: &ReversalPolicyFlags,
+    ) -> Result<(), DecisionReason> {
+        if rank(from).saturating_sub(rank(to)) > flags.max_downgrade_tiers {
+            return Err(DecisionReason::DeniedDowngradeTooLarge);
+        }
+        Ok(())
+    }
+
+    /// The deepest downgrade from `from` towards `to` that `flags` allow in a
+    /// single decision, for suggesting a stepwise path after
+    /// `DeniedDowngradeTooLarge`. `None` if no step is allowed.
+    pub fn allowed_downgrade_step(
+        from: CapabilityState,
+        to: CapabilityState,
+        flags: &ReversalPolicyFlags,
+    ) -> Option<CapabilityState> {
+        use CapabilityState::*;
+        let floor = rank(from).saturating_sub(flags.max_downgrade_tiers).max(rank(to));
+        if floor >= rank(from) {
+            return None;
+        }
+        [CapModelOnly, CapLabBench, CapControlledHuman, CapGeneralUse]
+            .into_iter()
+            .find(|s| rank(*s) == floor)
+    }
+
+    fn reduces_capability_and_roh(
+        from: CapabilityState,
+        to: CapabilityState,
+        roh_before: f32,
+        roh_after: f32,
+    ) -> bool {
+        is_neuromorph_downgrade(from, to) && roh_after <= roh_before
+    }
+
+    /// True if any evidence ref is tagged as biophysical / envelope-derived.
+    fn has_biophysical_evidence(evidence: &[EvidenceRef]) -> bool {
+        evidence
+            .iter()
+            .any(|e| e.category == EvidenceCategory::Biophysical)
+    }
+
+    /// Owned, serializable form of a `ReversalContext` for recorded cases.
+    ///
+    /// A `NoSaferAlternativeProof` cannot be deserialized, so a case records
+    /// the alternatives that were assessed and the replay re-derives the proof
+    /// through `compute_no_safer_alternative`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ReversalCaseContext {
+        pub from: CapabilityState,
+        pub to: CapabilityState,
+        pub roh_before: f32,
+        pub roh_after: f32,
+        #[serde(default)]
+        pub roh_axes: Option<RoHProjection>,
+        pub roles: RoleSet,
+        pub reversal_flags: ReversalPolicyFlags,
+        pub jurisdiction: Jurisdiction,
+        #[serde(default)]
+        pub jurisdiction_rules: JurisdictionReversalRules,
+        pub policystack: PolicyStack,
+        pub envelope_ctx: EnvelopeContextView,
+        #[serde(default)]
+        pub assessed_alternatives: Vec<AlternativeAssessment>,
+        #[serde(default)]
+        pub required_evidence: Vec<EvidenceRef>,
+    }
+
+    impl ReversalCaseContext {
+        /// The live context the kernel evaluates for this case.
+        pub fn as_context(&self) -> ReversalContext<'_> {
+            ReversalContext {
+                from: self.from,
+                to: self.to,
+                roh_before: self.roh_before,
+                roh_after: self.roh_after,
+                roh_axes: self.roh_axes.as_ref(),
+                roles: &self.roles,
+                reversal_flags: &self.reversal_flags,
+                jurisdiction: &self.jurisdiction,
+                jurisdiction_rules: &self.jurisdiction_rules,
+                policystack: &self.policystack,
+                envelope_ctx: &self.envelope_ctx,
+                nosaferalternative: compute_no_safer_alternative(
+                    &self.envelope_ctx,
+                    &self.assessed_alternatives,
+                ),
+                required_evidence: &self.required_evidence,
             }
+        }
+    }
 
-            // 2) RoH invariants in CapControlledHuman, except safety-improving rollback
-            if matches!(ctx.from, CapabilityState::CapControlledHuman) {
-                if !reduces_capability_and_roh(ctx) {
-                    if ctx.roh_after > ctx.roh_before || ctx.roh_after > 0.30 {
-                        return Decision::denied(DecisionReason::DeniedRoHViolation);
-                    }
+    /// One recorded `(context, expected decision)` line of a conformance corpus.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ReversalCase {
+        pub name: String,
+        pub context: ReversalCaseContext,
+        /// `Allowed` or the denial reason the kernel must return.
+        pub expected: DecisionReason,
+    }
+
+    /// A case whose replayed decision differs from the recorded one.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct ConformanceMismatch {
+        /// 1-based line in the corpus.
+        pub line: usize,
+        pub name: String,
+        pub expected: DecisionReason,
+        pub actual: DecisionReason,
+        /// First failing gate of the replay; `None` if it was allowed or
+        /// delegated without running any gate.
+        pub diverged_gate: Option<String>,
+    }
+
+    /// Outcome of `run_reversal_conformance`.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct ConformanceReport {
+        /// Cases parsed and replayed.
+        pub total: usize,
+        pub passed: usize,
+        pub mismatches: Vec<ConformanceMismatch>,
+        /// Unreadable corpus or unparsable lines, as `(line, message)`; line 0
+        /// for the file itself.
+        pub errors: Vec<(usize, String)>,
+    }
+
+    impl ConformanceReport {
+        /// Every case parsed and matched.
+        pub fn is_conformant(&self) -> bool {
+            self.mismatches.is_empty() && self.errors.is_empty()
+        }
+    }
+
+    /// Replay a JSONL corpus of `ReversalCase`s (blank lines skipped) through
+    /// `KernelEvaluator` and compare each decision with its recording.
+    pub fn run_reversal_conformance(path: &Path) -> ConformanceReport {
+        let mut report = ConformanceReport::default();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) => {
+                report
+                    .errors
+                    .push((0, format!("cannot read {}: {}", path.display(), e)));
+                return report;
+            }
+        };
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let case: ReversalCase = match serde_json::from_str(line) {
+                Ok(case) => case,
+                Err(e) => {
+                    report.errors.push((i + 1, format!("invalid case: {}", e)));
+                    continue;
                 }
+            };
+            report.total += 1;
+            let traced = KernelEvaluator.evaluate_reversal_traced(&case.context.as_context());
+            let trace = traced.trace;
+            let actual = traced.reasons.into_iter().next().unwrap_or(DecisionReason::Allowed);
+            if actual == case.expected {
+                report.passed += 1;
+            } else {
+                report.mismatches.push(ConformanceMismatch {
+                    line: i + 1,
+                    name: case.name,
+                    expected: case.expected,
+                    actual,
+                    diverged_gate: trace.denied_by().map(str::to_string),
+                });
             }
+        }
+        report
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::reversal_policy::JurisdictionReversalRule;
+
+        #[test]
+        fn test_three_tier_drop_denied_under_single_tier_limit() {
+            use CapabilityState::*;
+            let stepwise = ReversalPolicyFlags {
+                max_downgrade_tiers: 1,
+                ..ReversalPolicyFlags::default()
+            };
+            assert_eq!(
+                check_downgrade_magnitude(CapGeneralUse, CapModelOnly, &stepwise),
+                Err(DecisionReason::DeniedDowngradeTooLarge)
+            );
+            assert_eq!(
+                allowed_downgrade_step(CapGeneralUse, CapModelOnly, &stepwise),
+                Some(CapControlledHuman)
+            );
+            assert_eq!(
+                check_downgrade_magnitude(CapGeneralUse, CapControlledHuman, &stepwise),
+                Ok(())
+            );
+
+            // Default flags preserve the unlimited behaviour.
+            let default_flags = ReversalPolicyFlags::default();
+            assert_eq!(
+                check_downgrade_magnitude(CapGeneralUse, CapModelOnly, &default_flags),
+                Ok(())
+            );
+            assert_eq!(
+                allowed_downgrade_step(CapGeneralUse, CapModelOnly, &default_flags),
+                Some(CapModelOnly)
+            );
+        }
+
+        #[test]
+        fn test_trace_names_the_denying_gate() {
+            use CapabilityState::*;
+            let roles = RoleSet::default();
+            let flags = ReversalPolicyFlags::default();
+            let rules = JurisdictionReversalRules::new();
+            let policystack = PolicyStack::default();
+            let envelope_ctx = EnvelopeContextView::default();
+            let ctx = ReversalContext {
+                from: CapControlledHuman,
+                to: CapLabBench,
+                roh_before: 0.20,
+                roh_after: 0.10,
+                roh_axes: None,
+                roles: &roles,
+                reversal_flags: &flags,
+                jurisdiction: &Jurisdiction::GlobalBaseline,
+                jurisdiction_rules: &rules,
+                policystack: &policystack,
+                envelope_ctx: &envelope_ctx,
+                nosaferalternative: None,
+                required_evidence: &[],
+            };
+
+            // RoH falls, so the invariant holds; the Tier-1 flag is off by default.
+            let traced = KernelEvaluator.evaluate_reversal_traced(&ctx);
+            assert_eq!(
+                traced.trace.steps[..2],
+                [
+                    ("roh_invariants".to_string(), true),
+                    ("tier_flag".to_string(), false),
+                ]
+            );
+            assert_eq!(traced.trace.denied_by(), Some("tier_flag"));
+            assert_eq!(traced.trace.steps.len(), 9);
+            assert_eq!(
+                traced.decision,
+                Decision::denied(DecisionReason::DeniedReversalNotAllowedInTier)
+            );
+            assert_eq!(KernelEvaluator.evaluate_reversal(&ctx), traced.decision);
+
+            // Not a downgrade: delegated without touching any gate.
+            let upgrade = ReversalContext {
+                from: CapLabBench,
+                to: CapControlledHuman,
+                ..ctx
+            };
+            let traced = KernelEvaluator.evaluate_reversal_traced(&upgrade);
+            assert!(traced.trace.steps.is_empty());
+            assert_eq!(traced.trace.denied_by(), None);
+        }
+
+        #[test]
+        fn test_all_failing_gates_reported_in_gate_order() {
+            use CapabilityState::*;
+            let roles = RoleSet::default();
+            let flags = ReversalPolicyFlags::default();
+            let rules = JurisdictionReversalRules::new();
+            let policystack = PolicyStack::default();
+            let envelope_ctx = EnvelopeContextView::default();
+            // RoH rises inside CapControlledHuman and the Tier-1 flag is off.
+            let ctx = ReversalContext {
+                from: CapControlledHuman,
+                to: CapLabBench,
+                roh_before: 0.10,
+                roh_after: 0.20,
+                roh_axes: None,
+                roles: &roles,
+                reversal_flags: &flags,
+                jurisdiction: &Jurisdiction::GlobalBaseline,
+                jurisdiction_rules: &rules,
+                policystack: &policystack,
+                envelope_ctx: &envelope_ctx,
+                nosaferalternative: None,
+                required_evidence: &[],
+            };
 
-            // 3) Tier-1 flag: downgrades forbidden by default
-            if !ctx.reversal_flags.allow_neuromorph_reversal {
-                return Decision::denied(DecisionReason::DeniedReversalNotAllowedInTier);
+            let traced = KernelEvaluator.evaluate_reversal_traced(&ctx);
+            assert_eq!(
+                traced.reasons[..2],
+                [
+                    DecisionReason::DeniedRoHViolation,
+                    DecisionReason::DeniedReversalNotAllowedInTier,
+                ]
+            );
+            assert!(traced.reasons.contains(&DecisionReason::DeniedNoSaferAlternativeNotProved));
+            assert_eq!(
+                traced.trace.failed_gates().take(2).collect::<Vec<_>>(),
+                ["roh_invariants", "tier_flag"]
+            );
+            assert_eq!(
+                traced.decision,
+                Decision::denied(DecisionReason::DeniedRoHViolation)
+            );
+            assert_eq!(KernelEvaluator.evaluate_reversal(&ctx), traced.decision);
+            assert_eq!(deny_many(Vec::new()), Decision::Allowed);
+        }
+
+        fn sovereign_flags() -> ReversalPolicyFlags {
+            ReversalPolicyFlags {
+                allow_neuromorph_reversal: true,
+                explicit_reversal_order: true,
+                ..ReversalPolicyFlags::default()
             }
+        }
 
-            // 4) Sovereign quorum and explicit order + no-safer-alternative
-            if !ctx.roles.neuromorph_god_satisfied(ctx.reversal_flags.required_regulator_quorum) {
-                return Decision::denied(DecisionReason::DeniedIllegalDowngradeByNonRegulator);
+        /// Every gate satisfied: quorum met, order + proof on file, envelope
+        /// advice and biophysical evidence present, RoH falling.
+        fn full_quorum<'a>(
+            flags: &'a ReversalPolicyFlags,
+            evidence: &'a [EvidenceRef],
+        ) -> GateInputs<'a> {
+            GateInputs {
+                from: CapabilityState::CapControlledHuman,
+                to: CapabilityState::CapLabBench,
+                roh_before: 0.20,
+                roh_after: 0.10,
+                roh_axes_valid: None,
+                reversal_flags: flags,
+                quorum_satisfied: true,
+                reversal_forbidden: false,
+                jurisdiction_quorum_satisfied: None,
+                no_safer_alternative: true,
+                policystack_pass: true,
+                envelope_requests_downgrade: true,
+                required_evidence: evidence,
             }
+        }
+
+        fn envelope_evidence() -> Vec<EvidenceRef> {
+            vec![EvidenceRef::new("cid:QmEnvelopeRiskWindow")
+                .with_category(EvidenceCategory::Biophysical)]
+        }
+
+        #[test]
+        fn test_kernel_allows_full_quorum_downgrade() {
+            let flags = sovereign_flags();
+            let evidence = envelope_evidence();
+            let mut trace = DecisionTrace::default();
+            let reasons = run_gates(&full_quorum(&flags, &evidence), Some(&mut trace));
+            assert!(reasons.is_empty());
+            assert_eq!(deny_many(reasons), Decision::Allowed);
+            assert_eq!(trace.denied_by(), None);
+        }
 
-            if !ctx.reversal_flags.explicit_reversal_order || !ctx.nosaferalternative {
-                return Decision::denied(DecisionReason::DeniedNoSaferAlternativeNotProved);
+        #[test]
+        fn test_kernel_denies_downgrade_by_non_regulator() {
+            let flags = sovereign_flags();
+            let evidence = envelope_evidence();
+            let inputs = GateInputs {
+                quorum_satisfied: false,
+                ..full_quorum(&flags, &evidence)
+            };
+            assert_eq!(
+                run_gates(&inputs, None),
+                vec![DecisionReason::DeniedIllegalDowngradeByNonRegulator]
+            );
+        }
+
+        #[test]
+        fn test_eu_mdr_requires_extra_regulator_beyond_base_quorum() {
+            let rules = JurisdictionReversalRules::new().with_rule(
+                Jurisdiction::EuMdr,
+                JurisdictionReversalRule {
+                    extra_regulators: 1,
+                    ..JurisdictionReversalRule::default()
+                },
+            );
+            let rule = rules.rule_for(&Jurisdiction::EuMdr).cloned();
+            assert_eq!(rule.as_ref().map(|r| r.extra_regulators), Some(1));
+
+            let flags = sovereign_flags();
+            let evidence = envelope_evidence();
+            // Base quorum met, the second EU MDR regulator missing.
+            let inputs = GateInputs {
+                jurisdiction_quorum_satisfied: Some(false),
+                ..full_quorum(&flags, &evidence)
+            };
+            let mut trace = DecisionTrace::default();
+            assert_eq!(
+                run_gates(&inputs, Some(&mut trace)),
+                vec![DecisionReason::DeniedIllegalDowngradeByNonRegulator]
+            );
+            assert_eq!(trace.denied_by(), Some("jurisdiction_quorum"));
+
+            let inputs = GateInputs {
+                jurisdiction_quorum_satisfied: Some(true),
+                ..full_quorum(&flags, &evidence)
+            };
+            assert!(run_gates(&inputs, None).is_empty());
+        }
+
+        #[test]
+        fn test_jurisdiction_can_forbid_reversal_but_not_global_baseline() {
+            let forbid = JurisdictionReversalRule {
+                forbid_reversal: true,
+                ..JurisdictionReversalRule::default()
+            };
+            let rules = JurisdictionReversalRules::new()
+                .with_rule(Jurisdiction::EuMdr, forbid.clone())
+                .with_rule(Jurisdiction::GlobalBaseline, forbid);
+            assert!(rules.rule_for(&Jurisdiction::EuMdr).is_some());
+            assert!(rules.rule_for(&Jurisdiction::GlobalBaseline).is_none());
+
+            let flags = sovereign_flags();
+            let evidence = envelope_evidence();
+            let inputs = GateInputs {
+                reversal_forbidden: true,
+                ..full_quorum(&flags, &evidence)
+            };
+            assert_eq!(
+                run_gates(&inputs, None),
+                vec![DecisionReason::DeniedNeuromorphReversalProhibited]
+            );
+        }
+
+        #[test]
+        fn test_kernel_denies_roh_increase() {
+            let flags = sovereign_flags();
+            let evidence = envelope_evidence();
+            let inputs = GateInputs {
+                roh_before: 0.10,
+                roh_after: 0.20,
+                ..full_quorum(&flags, &evidence)
+            };
+            assert_eq!(
+                run_gates(&inputs, None),
+                vec![DecisionReason::DeniedRoHViolation]
+            );
+        }
+
+        #[test]
+        fn test_free_function_delegates_to_kernel() {
+            use CapabilityState::*;
+            let roles = RoleSet::default();
+            let flags = ReversalPolicyFlags::default();
+            let rules = JurisdictionReversalRules::new();
+            let policystack = PolicyStack::default();
+            let envelope_ctx = EnvelopeContextView::default();
+            for (from, to, roh_after) in [
+                (CapControlledHuman, CapLabBench, 0.10),
+                (CapControlledHuman, CapLabBench, 0.40),
+                (CapLabBench, CapControlledHuman, 0.10),
+            ] {
+                let ctx = ReversalContext {
+                    from,
+                    to,
+                    roh_before: 0.20,
+                    roh_after,
+                    roh_axes: None,
+                    roles: &roles,
+                    reversal_flags: &flags,
+                    jurisdiction: &Jurisdiction::GlobalBaseline,
+                    jurisdiction_rules: &rules,
+                    policystack: &policystack,
+                    envelope_ctx: &envelope_ctx,
+                    nosaferalternative: None,
+                    required_evidence: &[],
+                };
+                assert_eq!(
+                    evaluate_reversal(&ctx),
+                    KernelEvaluator.evaluate_reversal(&ctx)
+                );
             }
+        }
 
-            // 5) PolicyStack gate
-            if !ctx.policystack.all_pass() {
-                return Decision::denied(DecisionReason::DeniedPolicyStackFailure);
+        fn projection(weights: Vec<f32>) -> RoHProjection {
+            RoHProjection {
+                weights,
+                before: vec![0.20, 0.40, 0.10],
+                after: vec![0.10, 0.20, 0.10],
             }
+        }
+
+        #[test]
+        fn test_weighted_roh_validates_weights_and_axes() {
+            let p = projection(vec![0.5, 0.25, 0.25]);
+            let (before, after) = p.weighted_roh().unwrap();
+            assert!((before - 0.225).abs() < 1e-6);
+            assert!((after - 0.125).abs() < 1e-6);
+
+            assert!(matches!(
+                projection(vec![0.5, 0.25, 0.2]).weighted_roh(),
+                Err(RoHProjectionError::WeightSum { sum }) if (sum - 0.95).abs() < 1e-6
+            ));
+            assert_eq!(
+                projection(vec![1.5, -0.25, -0.25]).validate(),
+                Err(RoHProjectionError::WeightOutOfRange { axis: 0, weight: 1.5 })
+            );
+            assert!(matches!(
+                projection(vec![0.5, 0.5]).validate(),
+                Err(RoHProjectionError::AxisCountMismatch { weights: 2, .. })
+            ));
+            let mut out_of_range = projection(vec![0.5, 0.25, 0.25]);
+            out_of_range.after[1] = 1.2;
+            assert_eq!(
+                out_of_range.validate(),
+                Err(RoHProjectionError::AxisOutOfRange { axis: 1, value: 1.2 })
+            );
+        }
+
+        #[test]
+        fn test_kernel_uses_projection_and_denies_mismatched_weight_sum() {
+            use CapabilityState::*;
+            let roles = RoleSet::default();
+            let flags = ReversalPolicyFlags::default();
+            let rules = JurisdictionReversalRules::new();
+            let policystack = PolicyStack::default();
+            let envelope_ctx = EnvelopeContextView::default();
+            let valid = projection(vec![0.5, 0.25, 0.25]);
+            let skewed = projection(vec![0.6, 0.25, 0.25]);
+            let ctx = |axes| ReversalContext {
+                from: CapControlledHuman,
+                to: CapLabBench,
+                // Scalars claim RoH rose; the projection says it fell.
+                roh_before: 0.10,
+                roh_after: 0.20,
+                roh_axes: axes,
+                roles: &roles,
+                reversal_flags: &flags,
+                jurisdiction: &Jurisdiction::GlobalBaseline,
+                jurisdiction_rules: &rules,
+                policystack: &policystack,
+                envelope_ctx: &envelope_ctx,
+                nosaferalternative: None,
+                required_evidence: &[],
+            };
+
+            let traced = KernelEvaluator.evaluate_reversal_traced(&ctx(Some(&valid)));
+            assert_eq!(
+                traced.trace.steps[..2],
+                [
+                    ("roh_axes".to_string(), true),
+                    ("roh_invariants".to_string(), true),
+                ]
+            );
 
-            // 6) Envelope recommendation must be consistent (advisory, not overriding)
-            if !ctx.envelope_ctx.request_capability_downgrade {
-                return Decision::denied(DecisionReason::DeniedIllegalDowngradeByNonRegulator);
+            let traced = KernelEvaluator.evaluate_reversal_traced(&ctx(Some(&skewed)));
+            assert_eq!(traced.trace.denied_by(), Some("roh_axes"));
+            assert_eq!(traced.reasons[0], DecisionReason::DeniedRoHViolation);
+
+            // Scalar path unchanged when no projection is supplied.
+            let traced = KernelEvaluator.evaluate_reversal_traced(&ctx(None));
+            assert_eq!(traced.trace.denied_by(), Some("roh_invariants"));
+        }
+
+        #[test]
+        fn test_neuromorph_downgrade_follows_rank() {
+            use CapabilityState::*;
+            assert!(is_neuromorph_downgrade(CapControlledHuman, CapLabBench));
+            assert!(is_neuromorph_downgrade(CapGeneralUse, CapControlledHuman));
+            assert!(!is_neuromorph_downgrade(CapLabBench, CapModelOnly));
+            assert!(!is_neuromorph_downgrade(CapLabBench, CapControlledHuman));
+            assert!(!is_neuromorph_downgrade(CapControlledHuman, CapControlledHuman));
+        }
+
+        #[test]
+        fn test_regulatory_only_evidence_does_not_back_envelope_downgrade() {
+            let regulatory_only = vec![
+                EvidenceRef::new("cid:QmIrbApproval").with_category(EvidenceCategory::Regulatory),
+                EvidenceRef::new("cid:QmFdaLetter").with_category(EvidenceCategory::Regulatory),
+            ];
+            assert!(!has_biophysical_evidence(&regulatory_only));
+            assert!(!has_biophysical_evidence(&[]));
+
+            let mut with_envelope = regulatory_only.clone();
+            with_envelope.push(
+                EvidenceRef::new("cid:QmEnvelopeRiskWindow")
+                    .with_category(EvidenceCategory::Biophysical),
+            );
+            assert!(has_biophysical_evidence(&with_envelope));
+        }
+
+        /// The corpus's allowed case: every kernel gate passes.
+        fn allowed_case() -> ReversalCaseContext {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/reversal_conformance.jsonl");
+            let text = std::fs::read_to_string(path).unwrap();
+            let case: ReversalCase = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+            assert_eq!(case.expected, DecisionReason::Allowed);
+            case.context
+        }
+
+        #[test]
+        fn test_evaluate_reversal_denies_regulatory_only_evidence() {
+            let mut case = allowed_case();
+            assert_eq!(evaluate_reversal(&case.as_context()), Decision::Allowed);
+
+            for evidence in [
+                vec![EvidenceRef::new("cid:QmIrbApproval")
+                    .with_category(EvidenceCategory::Regulatory)],
+                Vec::new(),
+            ] {
+                case.required_evidence = evidence;
+                assert_eq!(
+                    evaluate_reversal(&case.as_context()),
+                    Decision::denied(DecisionReason::DeniedMissingEvidence)
+                );
             }
 
-            Decision::Allowed
+            // Only the biophysical-evidence flag makes regulatory evidence enough.
+            case.reversal_flags.require_biophysical_evidence = false;
+            assert_eq!(evaluate_reversal(&case.as_context()), Decision::Allowed);
         }
-    }
 
-    fn is_neuromorph_downgrade(from: CapabilityState, to: CapabilityState) -> bool {
-        use CapabilityState::*;
-        matches!(
-            (from, to),
-            (CapControlledHuman, CapLabBench)
-                | (CapControlledHuman, CapModelOnly)
-                | (CapGeneralUse, CapControlledHuman)
-                | (CapGeneralUse, CapLabBench)
-                | (CapGeneralUse, CapModelOnly)
-        )
-    }
-
-    fn reduces_capability_and_roh(ctx: &ReversalContext) -> bool {
-        is_neuromorph_downgrade(ctx.from, ctx.to) && ctx.roh_after <= ctx.roh_before
+        #[test]
+        fn test_evaluate_reversal_denies_multi_tier_drop_over_limit() {
+            use CapabilityState::*;
+            let mut case = allowed_case();
+            case.from = CapGeneralUse;
+            case.to = CapModelOnly;
+            assert_eq!(evaluate_reversal(&case.as_context()), Decision::Allowed);
+
+            case.reversal_flags.max_downgrade_tiers = 1;
+            let traced = KernelEvaluator.evaluate_reversal_traced(&case.as_context());
+            assert_eq!(
+                traced.decision,
+                Decision::denied(DecisionReason::DeniedDowngradeTooLarge)
+            );
+            assert_eq!(traced.trace.denied_by(), Some("downgrade_magnitude"));
+            assert_eq!(evaluate_reversal(&case.as_context()), traced.decision);
+
+            // The suggested single step is itself allowed.
+            case.to = allowed_downgrade_step(case.from, case.to, &case.reversal_flags).unwrap();
+            assert_eq!(case.to, CapControlledHuman);
+            assert_eq!(evaluate_reversal(&case.as_context()), Decision::Allowed);
+        }
+
+        #[test]
+        fn test_conformance_corpus_replays_and_reports_divergent_gate() {
+            let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+            let report = run_reversal_conformance(&dir.join("reversal_conformance.jsonl"));
+            assert_eq!(report.errors, Vec::new());
+            assert_eq!((report.total, report.passed), (5, 5));
+            assert!(report.is_conformant());
+
+            // Raising the quorum past the recorded signers turns the allowed
+            // case into a denial, which surfaces as a mismatch.
+            let text = std::fs::read_to_string(dir.join("reversal_conformance.jsonl")).unwrap();
+            let mut case: ReversalCase = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+            case.context.reversal_flags.required_regulator_quorum = 3;
+            let tmp = std::env::temp_dir().join(format!(
+                "reversal_conformance_mismatch-{}.jsonl",
+                std::process::id()
+            ));
+            std::fs::write(&tmp, serde_json::to_string(&case).unwrap()).unwrap();
+            let report = run_reversal_conformance(&tmp);
+            std::fs::remove_file(&tmp).unwrap();
+            assert_eq!(
+                report.mismatches,
+                vec![ConformanceMismatch {
+                    line: 1,
+                    name: case.name.clone(),
+                    expected: DecisionReason::Allowed,
+                    actual: DecisionReason::DeniedIllegalDowngradeByNonRegulator,
+                    diverged_gate: Some("sovereign_quorum".to_string()),
+                }]
+            );
+
+            let missing = run_reversal_conformance(&dir.join("no_such_corpus.jsonl"));
+            assert_eq!(missing.errors.len(), 1);
+            assert_eq!(missing.errors[0].0, 0);
+        }
     }
 }
@@ -0,0 +1,123 @@
+//! Deterministic randomness seam for stochastic fairness / simulation models.
+//!
+//! - Every stochastic step takes an explicit `&mut dyn RngSource`; nothing in the
+//!   fairness stack may reach for a thread-local or OS-seeded RNG.
+//! - `SeededRng` is the default source: the same seed always yields the same run.
+//! - Simulation-only: none of this feeds CapabilityState, consent, or envelopes.
+
+use crate::biophysical_consensus::TreeOfLifeRails;
+
+/// Source of pseudo-random numbers for simulation code.
+pub trait RngSource {
+    /// Next raw 64-bit value.
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform sample in [0, 1).
+    fn next_f32(&mut self) -> f32 {
+        // Top 24 bits give an exactly representable f32 mantissa.
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Bernoulli trial with success probability `p` (clamped to [0, 1]).
+    fn chance(&mut self, p: f32) -> bool {
+        self.next_f32() < p.clamp(0.0, 1.0)
+    }
+}
+
+/// SplitMix64 generator: small, fast, and fully determined by its seed.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RngSource for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Probabilistic deed outcome: jitter the continuous rails by up to `±amplitude`
+/// and re-clamp to their documented ranges. Boolean predicates are left as-is.
+pub fn jitter_rails(
+    rails: &TreeOfLifeRails,
+    amplitude: f32,
+    rng: &mut dyn RngSource,
+) -> TreeOfLifeRails {
+    let mut jitter = |x: f32, hi: f32| {
+        let delta = (rng.next_f32() * 2.0 - 1.0) * amplitude;
+        (x + delta).clamp(0.0, hi)
+    };
+
+    let roh = jitter(rails.roh, 0.30);
+    let decay = (roh / 0.30).clamp(0.0, 1.0);
+
+    TreeOfLifeRails {
+        roh,
+        decay,
+        lifeforce: 1.0 - decay,
+        fear: jitter(rails.fear, 1.0),
+        pain: jitter(rails.pain, 1.0),
+        power: jitter(rails.power, 1.0),
+        church: jitter(rails.church, 1.0),
+        ..*rails
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_rails() -> TreeOfLifeRails {
+        TreeOfLifeRails {
+            roh: 0.10,
+            decay: 0.33,
+            lifeforce: 0.67,
+            fear: 0.2,
+            pain: 0.2,
+            power: 0.4,
+            church: 0.5,
+            unfair_drain: false,
+            calm_stable: true,
+            overloaded: false,
+            recovery: false,
+        }
+    }
+
+    fn run(seed: u64) -> Vec<(f32, f32, f32)> {
+        let mut rng = SeededRng::new(seed);
+        let mut rails = base_rails();
+        let mut out = Vec::new();
+        for _ in 0..50 {
+            rails = jitter_rails(&rails, 0.05, &mut rng);
+            out.push((rails.roh, rails.fear, rails.power));
+        }
+        out
+    }
+
+    #[test]
+    fn test_same_seed_reproduces_run() {
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+    }
+
+    #[test]
+    fn test_jittered_rails_stay_in_range() {
+        let mut rng = SeededRng::new(7);
+        for _ in 0..1_000 {
+            let r = jitter_rails(&base_rails(), 0.5, &mut rng);
+            assert!((0.0..=0.30).contains(&r.roh));
+            assert!((0.0..=1.0).contains(&r.fear));
+            assert!((r.decay + r.lifeforce - 1.0).abs() < 1e-6);
+        }
+    }
+}
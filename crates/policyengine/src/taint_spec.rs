@@ -16,6 +16,7 @@ pub use nr_taint_macros::{
     nr_taint_trusted_writer,  // #[nr_taint_trusted_writer]
     nr_taint_trusted_reader,  // #[nr_taint_trusted_reader]
     nr_taint_diag_join,       // #[nr_taint_diag_join]
+    nr_taint_sink,            // #[nr_taint_sink]
 };
 
 /// Enumerates the fully-qualified names of policy-critical types.
@@ -187,31 +188,32 @@ use crate::rohmodel::RoHScore;
 use crate::policyengine::reversalconditions::ReversalContext;
 
 /// Mark core types as taint-critical so the analyzer treats them specially.
-#[nr_taint_critical]
+/// Each `path` must match the string accepted by `TaintPolicy::is_critical_type`.
+#[nr_taint_critical(path = "crate::alncore::CapabilityState")]
 type T_CapabilityState = CapabilityState;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::alncore::CapabilityTransitionRequest")]
 type T_CapabilityTransitionRequest = CapabilityTransitionRequest;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::alncore::Decision")]
 type T_Decision = Decision;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::alncore::DecisionReason")]
 type T_DecisionReason = DecisionReason;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::alncore::PolicyStack")]
 type T_PolicyStack = PolicyStack;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::alnroles::RoleSet")]
 type T_RoleSet = RoleSet;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::policy::reversal::ReversalPolicyFlags")]
 type T_ReversalPolicyFlags = ReversalPolicyFlags;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::policyengine::reversalconditions::ReversalContext")]
 type T_ReversalContext = ReversalContext;
 
-#[nr_taint_critical]
+#[nr_taint_critical(path = "crate::rohmodel::RoHScore")]
 type T_RoHScore = RoHScore;
 
 /// Mark the pure downgrade kernel as a trusted writer.
@@ -225,13 +227,17 @@ pub fn _taint_marker_reversalconditions_evaluate() {
 #[nr_taint_trusted_writer]
 pub fn _taint_marker_capability_transition_evaluate() {}
 
-/// Mark the capability executor as a trusted writer.
-#[nr_taint_trusted_writer]
-pub fn _taint_marker_capability_guard_apply() {}
+// The capability executor, `capability_guard::apply_transition`, carries
+// #[nr_taint_trusted_writer] on its real definition.
 
 /// Mark the diagnostic join point.
 #[nr_taint_diag_join]
-pub fn _taint_marker_compute_no_safer_alternative() {}
+pub fn _taint_marker_compute_no_safer_alternative() -> bool {
+    false
+}
+
+// The clean sink, `alncore::CapabilityTransitionRequest::evaluate`, carries
+// #[nr_taint_sink] on its real definition.
 
 /// Mark diagnostic modules as trusted readers (advisory only).
 #[nr_taint_trusted_reader]
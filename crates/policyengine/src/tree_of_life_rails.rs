@@ -0,0 +1,273 @@
+//! Shared Tree-of-Life scalar rails for the fairness / consensus modules.
+//!
+//! `biophysical_consensus` and `micro_unit_fairness` both re-export this type,
+//! so a rails value built for one can be passed to the other unchanged.
+//! `rails_from_view` is the one projection from a `TreeOfLifeView`.
+
+use serde::{Deserialize, Serialize};
+use treeoflife_core::TreeOfLifeView; // readonly view
+
+use crate::capability_guard::ROH_CEILING;
+
+/// Largest `|DECAY + LIFEFORCE - 1|` a view may carry and still project.
+pub const LIFEFORCE_TOLERANCE: f32 = 1e-4;
+
+/// Why a `TreeOfLifeView` cannot be projected onto rails.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RailsViewError {
+    /// LIFEFORCE is not `1 - DECAY` within `LIFEFORCE_TOLERANCE` (after
+    /// clamping both to [0, 1]).
+    InconsistentLifeforce { decay: f32, lifeforce: f32 },
+}
+
+/// Scalar rails for a single site, projected from BiophysicalEnvelopeSpec
+/// and Tree-of-Life / NATURE views.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TreeOfLifeRails {
+    /// Risk-of-Harm (RoH), clamped to [0, 0.3].
+    pub roh: f32,
+    /// DECAY = RoH / 0.3, clamped to [0, 1].
+    pub decay: f32,
+    /// LIFEFORCE = 1 - DECAY, clamped to [0, 1].
+    pub lifeforce: f32,
+    /// FEAR asset in [0, 1].
+    pub fear: f32,
+    /// PAIN asset in [0, 1].
+    pub pain: f32,
+    /// POWER and CHURCH assets in [0, 1] (corridor-view, not wallet balances).
+    pub power: f32,
+    pub church: f32,
+    /// UNFAIRDRAIN diagnostic flag for this site.
+    pub unfair_drain: bool,
+    /// CALM_STABLE / OVERLOADED / RECOVERY predicates.
+    pub calm_stable: bool,
+    pub overloaded: bool,
+    pub recovery: bool,
+}
+
+/// Per-view thresholds for the rails predicates; same semantics as the NATURE
+/// CALM_STABLE / OVERLOADED configs, applied to one view instead of a window.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RailsThresholds {
+    pub calm_lifeforce_min: f32,
+    pub calm_fear_max: f32,
+    pub calm_pain_max: f32,
+    pub calm_decay_max: f32,
+    /// OVERLOADED by load: DECAY and POWER high with LIFEFORCE low.
+    pub overloaded_decay_min: f32,
+    pub overloaded_power_min: f32,
+    pub overloaded_lifeforce_max: f32,
+    /// OVERLOADED by distress: FEAR and PAIN both high.
+    pub overloaded_fear_min: f32,
+    pub overloaded_pain_min: f32,
+    /// UNFAIRDRAIN: overloaded with DECAY exceeding LIFEFORCE by at least this.
+    pub unfair_drain_margin: f32,
+}
+
+impl Default for RailsThresholds {
+    fn default() -> Self {
+        Self {
+            calm_lifeforce_min: 0.60,
+            calm_fear_max: 0.30,
+            calm_pain_max: 0.30,
+            calm_decay_max: 0.40,
+            overloaded_decay_min: 0.60,
+            overloaded_power_min: 0.50,
+            overloaded_lifeforce_max: 0.40,
+            overloaded_fear_min: 0.70,
+            overloaded_pain_min: 0.70,
+            unfair_drain_margin: 0.20,
+        }
+    }
+}
+
+/// Project a `TreeOfLifeView` onto rails with `RailsThresholds::default()`.
+pub fn rails_from_view(view: &TreeOfLifeView) -> Result<TreeOfLifeRails, RailsViewError> {
+    rails_from_view_with(view, &RailsThresholds::default())
+}
+
+/// Project a `TreeOfLifeView` onto rails.
+///
+/// - Assets are clamped to [0, 1] and `roh = DECAY * ROH_CEILING`.
+/// - LIFEFORCE is taken from the view, which is rejected if it does not
+///   agree with DECAY rather than silently rederived.
+/// - OVERLOADED wins over CALM_STABLE when both thresholds are met.
+/// - RECOVERY needs an epoch history (`nature_recovery::is_recovery`), so a
+///   single view always projects to `recovery = false`.
+pub fn rails_from_view_with(
+    view: &TreeOfLifeView,
+    t: &RailsThresholds,
+) -> Result<TreeOfLifeRails, RailsViewError> {
+    let unit = |x: f32| if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) };
+    let decay = unit(view.decay);
+    let lifeforce = unit(view.lifeforce);
+    if (decay + lifeforce - 1.0).abs() > LIFEFORCE_TOLERANCE {
+        return Err(RailsViewError::InconsistentLifeforce { decay, lifeforce });
+    }
+    let fear = unit(view.fear);
+    let pain = unit(view.pain);
+    let power = unit(view.power);
+    let church = unit(view.church);
+
+    let load = decay >= t.overloaded_decay_min
+        && power >= t.overloaded_power_min
+        && lifeforce <= t.overloaded_lifeforce_max;
+    let distress = fear >= t.overloaded_fear_min && pain >= t.overloaded_pain_min;
+    let overloaded = load || distress;
+    let calm_stable = !overloaded
+        && lifeforce >= t.calm_lifeforce_min
+        && fear <= t.calm_fear_max
+        && pain <= t.calm_pain_max
+        && decay <= t.calm_decay_max;
+
+    Ok(TreeOfLifeRails {
+        roh: decay * ROH_CEILING,
+        decay,
+        lifeforce,
+        fear,
+        pain,
+        power,
+        church,
+        unfair_drain: overloaded && decay - lifeforce >= t.unfair_drain_margin,
+        calm_stable,
+        overloaded,
+        recovery: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::biophysical_consensus::{
+        self, check_tree_of_life_rails, BiophysicalConsensusPolicy, MicroUnit,
+    };
+    use crate::micro_unit_fairness::{
+        self, check_tree_of_life_fairness, DeedEvent, FairnessPolicy,
+    };
+
+    #[test]
+    fn test_rails_flow_through_both_fairness_modules() {
+        let rails = TreeOfLifeRails {
+            roh: 0.27,
+            decay: 0.9,
+            lifeforce: 0.1,
+            fear: 0.3,
+            pain: 0.3,
+            power: 0.2,
+            church: 0.5,
+            unfair_drain: true,
+            calm_stable: false,
+            overloaded: true,
+            recovery: false,
+        };
+
+        let unit = MicroUnit {
+            tick: 1,
+            actor_id: "actor".into(),
+            target_ids: vec!["peer".into()],
+            kind: biophysical_consensus::DeedKind::Help,
+            cause: biophysical_consensus::CauseContext {
+                rule_id: None,
+                intent_tag: None,
+            },
+            pre_sites: vec![biophysical_consensus::SiteSnapshot { index: 0, rails }],
+            post_sites: vec![biophysical_consensus::SiteSnapshot { index: 0, rails }],
+            w_cycle_binding: None,
+        };
+        assert!(check_tree_of_life_rails(&unit, &BiophysicalConsensusPolicy::default()));
+
+        let event = DeedEvent {
+            tick: 1,
+            sites: vec![
+                micro_unit_fairness::SiteSnapshot { index: 0, rails },
+                micro_unit_fairness::SiteSnapshot { index: 1, rails },
+            ],
+            kind: micro_unit_fairness::DeedKind::Help,
+            cause: micro_unit_fairness::CauseContext {
+                rule_id: None,
+                intent_tag: None,
+            },
+            w_cycle_id: None,
+        };
+        let judgement = check_tree_of_life_fairness(&event, &FairnessPolicy::default());
+        assert!(judgement.fairness_positive);
+    }
+
+    fn view(decay: f32, fear: f32, pain: f32, power: f32) -> TreeOfLifeView {
+        TreeOfLifeView {
+            decay,
+            lifeforce: 1.0 - decay,
+            fear,
+            pain,
+            power,
+            church: 0.5,
+            ..TreeOfLifeView::default()
+        }
+    }
+
+    #[test]
+    fn test_rails_from_calm_view_is_calm_stable() {
+        let rails = rails_from_view(&view(0.2, 0.1, 0.1, 0.2)).unwrap();
+        assert!(rails.calm_stable);
+        assert!(!rails.overloaded && !rails.unfair_drain && !rails.recovery);
+        assert!((rails.roh - 0.06).abs() < 1e-6);
+        assert!((rails.lifeforce - 0.8).abs() < 1e-6);
+        assert_eq!(rails.church, 0.5);
+        assert!(check_tree_of_life_rails(
+            &MicroUnit {
+                tick: 0,
+                actor_id: "actor".into(),
+                target_ids: vec![],
+                kind: biophysical_consensus::DeedKind::Help,
+                cause: biophysical_consensus::CauseContext {
+                    rule_id: None,
+                    intent_tag: None,
+                },
+                pre_sites: vec![biophysical_consensus::SiteSnapshot { index: 0, rails }],
+                post_sites: vec![biophysical_consensus::SiteSnapshot { index: 0, rails }],
+                w_cycle_binding: None,
+            },
+            &BiophysicalConsensusPolicy::default()
+        ));
+    }
+
+    #[test]
+    fn test_rails_from_drained_view_is_overloaded_and_unfair() {
+        let rails = rails_from_view(&view(0.9, 0.2, 0.2, 0.8)).unwrap();
+        assert!(rails.overloaded && rails.unfair_drain);
+        assert!(!rails.calm_stable);
+        assert!((rails.roh - 0.27).abs() < 1e-6);
+
+        // Distress alone overloads, but without a DECAY surplus it is not a drain.
+        let rails = rails_from_view(&view(0.3, 0.8, 0.8, 0.1)).unwrap();
+        assert!(rails.overloaded && !rails.unfair_drain && !rails.calm_stable);
+
+        // Out-of-range assets are clamped, never passed through.
+        let rails = rails_from_view(&view(1.7, -0.2, f32::NAN, 0.0)).unwrap();
+        assert_eq!((rails.decay, rails.fear, rails.pain), (1.0, 0.0, 0.0));
+        assert!(rails.roh <= ROH_CEILING);
+    }
+
+    #[test]
+    fn test_rails_take_lifeforce_from_view_and_reject_inconsistent_views() {
+        let rails = rails_from_view(&TreeOfLifeView {
+            lifeforce: 0.8 + LIFEFORCE_TOLERANCE / 2.0,
+            ..view(0.2, 0.1, 0.1, 0.2)
+        })
+        .unwrap();
+        assert_eq!(rails.lifeforce, 0.8 + LIFEFORCE_TOLERANCE / 2.0);
+
+        // A view whose LIFEFORCE was not derived from its DECAY is refused.
+        assert_eq!(
+            rails_from_view(&TreeOfLifeView {
+                lifeforce: 0.9,
+                ..view(0.9, 0.2, 0.2, 0.8)
+            })
+            .unwrap_err(),
+            RailsViewError::InconsistentLifeforce {
+                decay: 0.9,
+                lifeforce: 0.9
+            }
+        );
+    }
+}
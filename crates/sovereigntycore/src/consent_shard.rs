@@ -0,0 +1,116 @@
+//! `ConsentResolver` backed by an ALN consent-ledger shard on disk.
+//!
+//! - The shard is JSONL (one `ConsentSnapshot` per line) or a JSON array.
+//! - File order is chronological: for each `(subject_id, scope)` the last
+//!   entry wins, so a later revocation overrides every earlier grant and a
+//!   later re-grant overrides an earlier revocation.
+//! - Unknown `(subject_id, scope)` pairs are an error, never a default grant.
+
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::smart_guard::{ConsentResolver, ConsentSnapshot};
+
+#[derive(Debug, Clone, Default)]
+pub struct ShardConsentResolver {
+    latest: HashMap<(String, String), ConsentSnapshot>,
+}
+
+impl ShardConsentResolver {
+    /// Build from snapshots in ledger order.
+    pub fn from_snapshots(snapshots: impl IntoIterator<Item = ConsentSnapshot>) -> Self {
+        let mut latest = HashMap::new();
+        for snapshot in snapshots {
+            latest.insert(
+                (snapshot.subject_id.clone(), snapshot.scope.clone()),
+                snapshot,
+            );
+        }
+        ShardConsentResolver { latest }
+    }
+
+    /// Parse a shard body: a JSON array if it starts with `[`, JSONL otherwise
+    /// (blank lines skipped).
+    pub fn parse(text: &str) -> Result<Self> {
+        if text.trim_start().starts_with('[') {
+            let snapshots: Vec<ConsentSnapshot> =
+                serde_json::from_str(text).context("consent shard: invalid JSON array")?;
+            return Ok(Self::from_snapshots(snapshots));
+        }
+        let mut snapshots = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let snapshot: ConsentSnapshot = serde_json::from_str(line)
+                .with_context(|| format!("consent shard: invalid entry on line {}", i + 1))?;
+            snapshots.push(snapshot);
+        }
+        Ok(Self::from_snapshots(snapshots))
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("consent shard: cannot read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("consent shard: {}", path.display()))
+    }
+}
+
+impl ConsentResolver for ShardConsentResolver {
+    fn resolve_consent(&self, subject_id: &str, scope: &str) -> Result<ConsentSnapshot> {
+        self.latest
+            .get(&(subject_id.to_string(), scope.to_string()))
+            .cloned()
+            .ok_or_else(|| {
+                anyhow!(
+                    "consent shard: no consent entry for subject {} scope {}",
+                    subject_id,
+                    scope
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::smart_guard::ConsentState;
+
+    fn fixture() -> ShardConsentResolver {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/consent_ledger.jsonl");
+        ShardConsentResolver::load(&path).unwrap()
+    }
+
+    #[test]
+    fn test_granted_scope_resolves_to_latest_grant() {
+        let snapshot = fixture().resolve_consent("subject-a", "motor").unwrap();
+        assert_eq!(snapshot.consent_state, ConsentState::Extended);
+        assert!(!snapshot.revoked);
+    }
+
+    #[test]
+    fn test_later_revocation_overrides_earlier_grant() {
+        let snapshot = fixture().resolve_consent("subject-a", "speech").unwrap();
+        assert!(snapshot.revoked);
+
+        let regranted = ShardConsentResolver::parse(
+            r#"[
+                {"subjectId":"s","scope":"motor","consentState":"minimal","revoked":true},
+                {"subjectId":"s","scope":"motor","consentState":"minimal","revoked":false}
+            ]"#,
+        )
+        .unwrap();
+        assert!(!regranted.resolve_consent("s", "motor").unwrap().revoked);
+    }
+
+    #[test]
+    fn test_unknown_scope_and_bad_line_are_errors() {
+        let shard = fixture();
+        assert!(shard.resolve_consent("subject-a", "vision").is_err());
+        assert!(shard.resolve_consent("subject-c", "motor").is_err());
+
+        let err = ShardConsentResolver::parse("{\"subjectId\":\"s\"}\n").unwrap_err();
+        assert!(format!("{:#}", err).contains("line 1"));
+    }
+}
@@ -1,15 +1,39 @@
+use aln_core::ROH_CEILING;
 use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
 use organiccpualn::donutloopledger::{DonutloopEntry, DonutloopLedger};
-use organiccpualn::evolvestream::EvolutionProposalRecord;
+use organiccpualn::evolvestream::{EffectBounds, EvolutionProposalRecord};
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
 
-/// Consent depth required by a SMART token.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-pub enum ConsentState {
-    ConsentMinimal,
-    ConsentExtended,
+/// Consent depth, shared with the ALN core (None/Minimal/Extended/Revoked)
+/// rather than redefined here.
+pub use aln_core::ConsentState;
+
+/// Depth comparisons on the consent lattice. `ConsentState` is defined in
+/// `aln_core`, so the methods come from this trait rather than an inherent impl.
+pub trait ConsentLattice {
+    /// Position in the lattice: None/Revoked = 0 < Minimal = 1 < Extended = 2.
+    fn depth(&self) -> u8;
+
+    /// True if `self` is at least as deep as `required`. Depth-0 states never
+    /// satisfy a positive requirement, and `Revoked` satisfies nothing.
+    fn satisfies(&self, required: &ConsentState) -> bool;
+}
+
+impl ConsentLattice for ConsentState {
+    fn depth(&self) -> u8 {
+        match self {
+            ConsentState::None | ConsentState::Revoked => 0,
+            ConsentState::Minimal => 1,
+            ConsentState::Extended => 2,
+        }
+    }
+
+    fn satisfies(&self, required: &ConsentState) -> bool {
+        *self != ConsentState::Revoked && self.depth() >= required.depth()
+    }
 }
 
 /// Minimal shape of a SMART token policy entry from `.smart.json`.
@@ -21,6 +45,13 @@ pub struct SmartTokenPolicy {
     pub subject_id: String,
     pub scope: String,
     pub max_effect_size_l2: f32,
+    /// Optional bound on the largest single-channel delta (L-infinity), which
+    /// catches a spike on one electrode that the L2 norm can hide.
+    #[serde(default)]
+    pub max_effect_size_linf: Option<f32>,
+    /// Optional bound on the summed absolute delta (L1).
+    #[serde(default)]
+    pub max_effect_size_l1: Option<f32>,
     pub requires_consent_state: ConsentState,
     pub expiry_utc: String,
 }
@@ -56,12 +87,41 @@ pub struct ConsentSnapshot {
     pub revoked: bool,
 }
 
-/// Read‑only view that the guard uses. You can back this with an ALN
-/// shard loader elsewhere in sovereigntycore.
+/// Read‑only view that the guard uses. `consent_shard::ShardConsentResolver`
+/// backs it with an ALN consent-ledger shard.
 pub trait ConsentResolver {
     fn resolve_consent(&self, subject_id: &str, scope: &str) -> Result<ConsentSnapshot>;
 }
 
+/// Effective consent for a deed spanning several scopes.
+///
+/// Aggregation is conservative: the result is the *minimum* consent across
+/// all `scopes`, so one Minimal scope caps the whole deed at Minimal even if
+/// every other scope is Extended. A revoked snapshot on any scope forces
+/// `Revoked` overall. Resolution errors and an empty scope list are
+/// returned as errors rather than guessed.
+pub fn effective_consent_for_scopes(
+    resolver: &dyn ConsentResolver,
+    subject: &str,
+    scopes: &[&str],
+) -> Result<ConsentState> {
+    if scopes.is_empty() {
+        bail!("effective consent: no scopes given for subject {}", subject);
+    }
+
+    let mut effective = ConsentState::Extended;
+    for scope in scopes {
+        let snapshot = resolver.resolve_consent(subject, scope)?;
+        if snapshot.revoked || snapshot.consent_state == ConsentState::Revoked {
+            return Ok(ConsentState::Revoked);
+        }
+        if snapshot.consent_state.depth() < effective.depth() {
+            effective = snapshot.consent_state;
+        }
+    }
+    Ok(effective)
+}
+
 /// Guard decision codes – reuse your existing GuardDecision if you prefer.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum SmartGuardDecision {
@@ -69,18 +129,109 @@ pub enum SmartGuardDecision {
     Rejected(String),
 }
 
-/// Evaluate SMART token + consent for a proposal.
+/// Current RoH against its ceiling, used to tighten SMART effect bounds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RohHeadroom {
+    pub roh_current: f32,
+    pub roh_ceiling: f32,
+}
+
+impl RohHeadroom {
+    /// `(ceiling - roh_current) / ceiling`, clamped to [0, 1]. Non-finite or
+    /// non-positive inputs give 0 (no headroom), never a looser bound.
+    pub fn fraction(&self) -> f32 {
+        if self.roh_ceiling.is_nan() || self.roh_ceiling <= 0.0 || !self.roh_current.is_finite() {
+            return 0.0;
+        }
+        ((self.roh_ceiling - self.roh_current) / self.roh_ceiling).clamp(0.0, 1.0)
+    }
+}
+
+/// Structural checks on proposal effect bounds. `EffectBounds` is defined in
+/// `organiccpualn`, so the method comes from this trait rather than an
+/// inherent impl.
+pub trait EffectBoundsCheck {
+    /// Every norm finite and >= 0, and `l2 >= linf` (an L2 norm can never be
+    /// below the largest component). A NaN norm would otherwise compare false
+    /// against every bound and pass.
+    fn validate(&self) -> Result<(), String>;
+}
+
+impl EffectBoundsCheck for EffectBounds {
+    fn validate(&self) -> Result<(), String> {
+        let norms = [
+            ("l2", self.l2_delta_norm),
+            ("linf", self.linf_delta_norm),
+            ("l1", self.l1_delta_norm),
+        ];
+        for (norm, value) in norms {
+            if !value.is_finite() || value < 0.0 {
+                return Err(format!(
+                    "{}_delta_norm = {} is not a finite non-negative norm",
+                    norm, value
+                ));
+            }
+        }
+        if self.l2_delta_norm + 1e-6 < self.linf_delta_norm {
+            return Err(format!(
+                "l2_delta_norm {} is below linf_delta_norm {}",
+                self.l2_delta_norm, self.linf_delta_norm
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Opt-in tightening for `evaluate_smart_and_consent_with`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmartGuardOptions {
+    /// When set, `max_effect_size_l2` is multiplied by the RoH headroom
+    /// fraction: full bound at RoH 0, zero bound at or above the ceiling.
+    #[serde(default)]
+    pub roh_headroom: Option<RohHeadroom>,
+}
+
+/// Evaluate SMART token + consent for a proposal, checking token expiry
+/// against the wall clock.
 /// Assumes `proposal.token_id` is already present in EvolutionProposalRecord.
 pub fn evaluate_smart_and_consent(
     proposal: &EvolutionProposalRecord,
     smart_policies: &SmartPolicyIndex,
     consent_resolver: &dyn ConsentResolver,
+) -> SmartGuardDecision {
+    evaluate_smart_and_consent_with(
+        proposal,
+        smart_policies,
+        consent_resolver,
+        &SmartGuardOptions::default(),
+        Utc::now(),
+    )
+}
+
+/// `evaluate_smart_and_consent` with opt-in options (e.g., RoH-headroom
+/// scaling of the effect-size bound) and an injected `now` for the
+/// `expiry_utc` check. Default options behave identically.
+pub fn evaluate_smart_and_consent_with(
+    proposal: &EvolutionProposalRecord,
+    smart_policies: &SmartPolicyIndex,
+    consent_resolver: &dyn ConsentResolver,
+    options: &SmartGuardOptions,
+    now: DateTime<Utc>,
 ) -> SmartGuardDecision {
     // Only guard SMART tokens; EVOLVE is handled elsewhere.
     if proposal.token_kind != "SMART" {
         return SmartGuardDecision::Allowed;
     }
 
+    if let Err(e) = proposal.effect_bounds.validate() {
+        return SmartGuardDecision::Rejected(format!(
+            "SMART token guard: invalid effect bounds on proposal {}: {}",
+            proposal.proposal_id, e
+        ));
+    }
+
     let token_id = match &proposal.token_id {
         Some(tid) => tid,
         None => {
@@ -100,6 +251,23 @@ pub fn evaluate_smart_and_consent(
         }
     };
 
+    // Token must parse and be unexpired at `now`.
+    match DateTime::parse_from_rfc3339(&policy.expiry_utc) {
+        Ok(expiry) if now > expiry => {
+            return SmartGuardDecision::Rejected(format!(
+                "SMART token guard: token expired at {} (token {})",
+                policy.expiry_utc, token_id
+            ))
+        }
+        Ok(_) => {}
+        Err(e) => {
+            return SmartGuardDecision::Rejected(format!(
+                "SMART token guard: malformed expiry_utc {:?} for token {}: {}",
+                policy.expiry_utc, token_id, e
+            ))
+        }
+    }
+
     // Scope and subject must match.
     if policy.scope != proposal.scope {
         return SmartGuardDecision::Rejected(format!(
@@ -114,12 +282,28 @@ pub fn evaluate_smart_and_consent(
         ));
     }
 
-    // Effect size bound.
-    if proposal.effect_bounds.l2_delta_norm > policy.max_effect_size_l2 + 1e-6 {
-        return SmartGuardDecision::Rejected(format!(
-            "SMART token guard: effect size {} exceeds max_effect_size_l2 {} for token {}",
-            proposal.effect_bounds.l2_delta_norm, policy.max_effect_size_l2, token_id
-        ));
+    // Effect size bounds, optionally tightened by RoH headroom. L2 is always
+    // bounded; Linf and L1 only when the token configures them.
+    let scale = match &options.roh_headroom {
+        Some(headroom) => headroom.fraction(),
+        None => 1.0,
+    };
+    let bounds = [
+        ("l2", proposal.effect_bounds.l2_delta_norm, Some(policy.max_effect_size_l2)),
+        ("linf", proposal.effect_bounds.linf_delta_norm, policy.max_effect_size_linf),
+        ("l1", proposal.effect_bounds.l1_delta_norm, policy.max_effect_size_l1),
+    ];
+    for (norm, value, bound) in bounds {
+        let Some(bound) = bound else {
+            continue;
+        };
+        let max_effect = bound * scale;
+        if value > max_effect + 1e-6 {
+            return SmartGuardDecision::Rejected(format!(
+                "SMART token guard: {} effect size {} exceeds max_effect_size_{} {} (effective {}) for token {}",
+                norm, value, norm, bound, max_effect, token_id
+            ));
+        }
     }
 
     // Resolve consent for this subject/scope.
@@ -127,7 +311,7 @@ pub fn evaluate_smart_and_consent(
         Ok(c) => c,
         Err(e) => {
             return SmartGuardDecision::Rejected(format!(
-                "SMART token guard: failed to resolve consent: {}",
+                "SMART token guard: failed to resolve consent: {:#}",
                 e
             ))
         }
@@ -139,24 +323,97 @@ pub fn evaluate_smart_and_consent(
         );
     }
 
-    // Required consent depth.
-    match (policy.requires_consent_state.clone(), consent.consent_state.clone()) {
-        (ConsentState::ConsentMinimal, ConsentState::ConsentMinimal)
-        | (ConsentState::ConsentMinimal, ConsentState::ConsentExtended)
-        | (ConsentState::ConsentExtended, ConsentState::ConsentExtended) => {
-            // OK – consent depth sufficient.
-        }
-        (ConsentState::ConsentExtended, ConsentState::ConsentMinimal) => {
-            return SmartGuardDecision::Rejected(
-                "SMART token guard: requires ConsentExtended but only ConsentMinimal present"
-                    .to_string(),
-            );
-        }
+    // Required consent depth. A token must require at least Minimal.
+    let required = &policy.requires_consent_state;
+    let present = &consent.consent_state;
+    if *present == ConsentState::Revoked || *required == ConsentState::Revoked {
+        return SmartGuardDecision::Rejected(
+            "SMART token guard: consent revoked for subject/scope".to_string(),
+        );
+    }
+    if required.depth() == 0 {
+        return SmartGuardDecision::Rejected(format!(
+            "SMART token guard: token {} requires consent {:?}; SMART tokens need at least Minimal",
+            token_id, required
+        ));
+    }
+    if !present.satisfies(required) {
+        return SmartGuardDecision::Rejected(match present {
+            ConsentState::None => {
+                "SMART token guard: no consent on record for subject/scope".to_string()
+            }
+            _ => format!(
+                "SMART token guard: requires {:?} consent but only {:?} present",
+                required, present
+            ),
+        });
     }
 
     SmartGuardDecision::Allowed
 }
 
+/// Evaluate a batch of proposals, resolving consent at most once per
+/// `(subject_id, scope)`. Decisions match calling
+/// `evaluate_smart_and_consent_with` (default options) on each proposal in
+/// turn, with `now` shared by the whole batch.
+pub fn evaluate_smart_batch(
+    proposals: &[EvolutionProposalRecord],
+    policies: &SmartPolicyIndex,
+    resolver: &dyn ConsentResolver,
+    now: DateTime<Utc>,
+) -> Vec<SmartGuardDecision> {
+    let memo = MemoizedConsentResolver {
+        inner: resolver,
+        cache: RefCell::new(HashMap::new()),
+    };
+    let options = SmartGuardOptions::default();
+    proposals
+        .iter()
+        .map(|proposal| evaluate_smart_and_consent_with(proposal, policies, &memo, &options, now))
+        .collect()
+}
+
+/// Per-batch consent cache. Failures are cached as their full context chain
+/// (`{:#}`) so a failing scope is not retried within the batch either.
+struct MemoizedConsentResolver<'a> {
+    inner: &'a dyn ConsentResolver,
+    cache: RefCell<HashMap<(String, String), std::result::Result<ConsentSnapshot, String>>>,
+}
+
+impl ConsentResolver for MemoizedConsentResolver<'_> {
+    fn resolve_consent(&self, subject_id: &str, scope: &str) -> Result<ConsentSnapshot> {
+        let key = (subject_id.to_string(), scope.to_string());
+        let cached = self
+            .cache
+            .borrow_mut()
+            .entry(key)
+            .or_insert_with(|| {
+                self.inner
+                    .resolve_consent(subject_id, scope)
+                    .map_err(|e| format!("{:#}", e))
+            })
+            .clone();
+        cached.map_err(|message| anyhow!(message))
+    }
+}
+
+/// Reject an entry whose `roh_before`/`roh_after` is NaN, negative, or above
+/// `ROH_CEILING`, so corrupted values cannot propagate into new entries.
+pub fn validate_entry_roh(entry: &DonutloopEntry) -> Result<()> {
+    for (field, value) in [("roh_before", entry.roh_before), ("roh_after", entry.roh_after)] {
+        if !(0.0..=ROH_CEILING).contains(&value) {
+            bail!(
+                "RoH check: entry {} has {} = {} outside [0, {}]",
+                entry.entry_id,
+                field,
+                value,
+                ROH_CEILING
+            );
+        }
+    }
+    Ok(())
+}
+
 /// Minimal rollback helper: when an already‑applied SMART change is later
 /// discovered to violate consent, synthesize a compensating proposal and
 /// apply it as a new ledger entry with lower RoH (monotone safety).
@@ -171,6 +428,8 @@ pub fn synthesize_smart_rollback_entry(
     if offending_entry.subject_id != last_safe_entry.subject_id {
         bail!("rollback: subject_id mismatch between offending and last_safe entries");
     }
+    validate_entry_roh(offending_entry)?;
+    validate_entry_roh(last_safe_entry)?;
 
     // Enforce RoH monotonicity: rollback must not increase RoH relative to
     // last safe state; typically you set roh_after to last_safe.roh_after
@@ -207,6 +466,7 @@ pub fn append_rollback_to_ledger(
     ledger: &mut DonutloopLedger,
     rollback_entry: DonutloopEntry,
 ) -> Result<()> {
+    validate_entry_roh(&rollback_entry)?;
     ledger.append(rollback_entry)?;
     Ok(())
 }
@@ -241,3 +501,636 @@ pub fn rollback_smart_violation(
     append_rollback_to_ledger(ledger, rollback)?;
     Ok(())
 }
+
+/// First inconsistency found by `verify_donutloop_chain`; `index` is the
+/// position in `ledger.entries()` of the entry that breaks the chain.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RollbackChainError {
+    /// `prev_hexstamp` does not match the prior entry's `hexstamp`.
+    BrokenLink {
+        index: usize,
+        expected: String,
+        found: String,
+    },
+    /// A rollback entry ends with higher RoH than the entry it rolls back.
+    RohIncreased {
+        index: usize,
+        rolled_back_roh: f32,
+        rollback_roh: f32,
+    },
+}
+
+impl RollbackChainError {
+    pub fn index(&self) -> usize {
+        match self {
+            RollbackChainError::BrokenLink { index, .. }
+            | RollbackChainError::RohIncreased { index, .. } => *index,
+        }
+    }
+}
+
+impl std::fmt::Display for RollbackChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RollbackChainError::BrokenLink {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "ledger chain: entry {} has prev_hexstamp {} but prior hexstamp is {}",
+                index, found, expected
+            ),
+            RollbackChainError::RohIncreased {
+                index,
+                rolled_back_roh,
+                rollback_roh,
+            } => write!(
+                f,
+                "ledger chain: rollback entry {} raises RoH from {} to {}",
+                index, rolled_back_roh, rollback_roh
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RollbackChainError {}
+
+/// Walk the whole ledger after (possibly repeated) rollbacks: every
+/// `prev_hexstamp` must equal the prior entry's `hexstamp`, and every
+/// rollback entry (`change_type` prefixed `rollback-`) must not end above
+/// the RoH of the entry it rolls back. The first entry's link is not checked.
+pub fn verify_donutloop_chain(ledger: &DonutloopLedger) -> Result<(), RollbackChainError> {
+    let entries = ledger.entries();
+    for (i, pair) in entries.windows(2).enumerate() {
+        let (prior, entry) = (&pair[0], &pair[1]);
+        let index = i + 1;
+        if entry.prev_hexstamp != prior.hexstamp {
+            return Err(RollbackChainError::BrokenLink {
+                index,
+                expected: prior.hexstamp.clone(),
+                found: entry.prev_hexstamp.clone(),
+            });
+        }
+        if entry.change_type.starts_with("rollback-")
+            && (entry.roh_after.is_nan() || entry.roh_after > prior.roh_after + 1e-6)
+        {
+            return Err(RollbackChainError::RohIncreased {
+                index,
+                rolled_back_roh: prior.roh_after,
+                rollback_roh: entry.roh_after,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    struct MapResolver {
+        by_scope: HashMap<String, ConsentSnapshot>,
+    }
+
+    impl MapResolver {
+        fn new(entries: &[(&str, ConsentState, bool)]) -> Self {
+            let by_scope = entries
+                .iter()
+                .map(|(scope, state, revoked)| {
+                    (
+                        scope.to_string(),
+                        ConsentSnapshot {
+                            subject_id: "subject-a".to_string(),
+                            scope: scope.to_string(),
+                            consent_state: state.clone(),
+                            revoked: *revoked,
+                        },
+                    )
+                })
+                .collect();
+            MapResolver { by_scope }
+        }
+    }
+
+    impl ConsentResolver for MapResolver {
+        fn resolve_consent(&self, _subject_id: &str, scope: &str) -> Result<ConsentSnapshot> {
+            self.by_scope
+                .get(scope)
+                .cloned()
+                .ok_or_else(|| anyhow!("no consent for scope {}", scope))
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-06-01T00:00:00Z".parse().unwrap()
+    }
+
+    fn ledger_entry(entry_id: &str, roh_before: f32, roh_after: f32) -> DonutloopEntry {
+        serde_json::from_value(serde_json::json!({
+            "entry_id": entry_id,
+            "subject_id": "subject-a",
+            "proposal_id": format!("proposal-{}", entry_id),
+            "change_type": "smart-tune",
+            "tsafe_mode": "Active",
+            "roh_before": roh_before,
+            "roh_after": roh_after,
+            "knowledge_factor": 0.5,
+            "cybostate_factor": 0.5,
+            "policy_refs": [],
+            "hexstamp": format!("0x{}", entry_id),
+            "timestamp_utc": "2026-01-01T00:00:00Z",
+            "prev_hexstamp": "0xGENESIS",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rollback_rejects_out_of_ceiling_source_entry() {
+        let last_safe = ledger_entry("e1", 0.10, 0.12);
+        let offending = ledger_entry("e2", 0.12, 0.20);
+        let ok = synthesize_smart_rollback_entry(&offending, &last_safe, "e3", "0xe3").unwrap();
+        assert_eq!(ok.roh_after, 0.12);
+
+        let corrupted = ledger_entry("e2", 0.12, 0.45);
+        let err = synthesize_smart_rollback_entry(&corrupted, &last_safe, "e3", "0xe3").unwrap_err();
+        assert!(err.to_string().contains("roh_after"));
+
+        let mut nan_safe = ledger_entry("e1", 0.10, 0.12);
+        nan_safe.roh_before = f32::NAN;
+        assert!(synthesize_smart_rollback_entry(&offending, &nan_safe, "e3", "0xe3").is_err());
+    }
+
+    fn chained_entry(entry_id: &str, prev: &str, roh_before: f32, roh_after: f32) -> DonutloopEntry {
+        let mut entry = ledger_entry(entry_id, roh_before, roh_after);
+        entry.prev_hexstamp = prev.to_string();
+        entry
+    }
+
+    #[test]
+    fn test_donutloop_chain_valid_across_multiple_rollbacks() {
+        let mut ledger = DonutloopLedger::new();
+        ledger.append(chained_entry("e1", "0xGENESIS", 0.10, 0.12)).unwrap();
+        ledger.append(chained_entry("e2", "0xe1", 0.12, 0.20)).unwrap();
+        rollback_smart_violation(&mut ledger, 1, "e3", "0xe3").unwrap();
+        ledger.append(chained_entry("e4", "0xe3", 0.12, 0.25)).unwrap();
+        rollback_smart_violation(&mut ledger, 3, "e5", "0xe5").unwrap();
+
+        assert_eq!(ledger.entries().len(), 5);
+        assert_eq!(ledger.entries()[4].roh_after, 0.12);
+        assert_eq!(verify_donutloop_chain(&ledger), Ok(()));
+    }
+
+    #[test]
+    fn test_donutloop_chain_reports_first_broken_link() {
+        let mut ledger = DonutloopLedger::new();
+        ledger.append(chained_entry("e1", "0xGENESIS", 0.10, 0.12)).unwrap();
+        ledger.append(chained_entry("e2", "0xe1", 0.12, 0.20)).unwrap();
+        rollback_smart_violation(&mut ledger, 1, "e3", "0xe3").unwrap();
+        ledger.append(chained_entry("e4", "0xe2", 0.12, 0.25)).unwrap();
+        ledger.append(chained_entry("e5", "0xe9", 0.25, 0.25)).unwrap();
+
+        let err = verify_donutloop_chain(&ledger).unwrap_err();
+        assert_eq!(err.index(), 3);
+        assert!(matches!(err, RollbackChainError::BrokenLink { ref found, .. } if found == "0xe2"));
+
+        let mut raised = chained_entry("e2", "0xe1", 0.20, 0.25);
+        raised.change_type = "rollback-smart-tune".to_string();
+        let mut ledger = DonutloopLedger::new();
+        ledger.append(chained_entry("e1", "0xGENESIS", 0.10, 0.20)).unwrap();
+        ledger.append(raised).unwrap();
+        assert!(matches!(
+            verify_donutloop_chain(&ledger),
+            Err(RollbackChainError::RohIncreased { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_effect_bound_tightens_near_roh_ceiling() {
+        let policies = SmartPolicyIndex::new(vec![SmartTokenPolicy {
+            token_id: "smart-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            scope: "motor".to_string(),
+            max_effect_size_l2: 0.5,
+            max_effect_size_linf: None,
+            max_effect_size_l1: None,
+            requires_consent_state: ConsentState::Minimal,
+            expiry_utc: "2027-01-01T00:00:00Z".to_string(),
+        }]);
+        let resolver = MapResolver::new(&[("motor", ConsentState::Extended, false)]);
+        let mut proposal = EvolutionProposalRecord {
+            proposal_id: "p-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            token_kind: "SMART".to_string(),
+            token_id: Some("smart-1".to_string()),
+            scope: "motor".to_string(),
+            ..Default::default()
+        };
+        proposal.effect_bounds.l2_delta_norm = 0.3;
+
+        let at_roh = |roh_current: f32| SmartGuardOptions {
+            roh_headroom: Some(RohHeadroom {
+                roh_current,
+                roh_ceiling: ROH_CEILING,
+            }),
+        };
+
+        // Opt-in is off by default.
+        assert_eq!(
+            evaluate_smart_and_consent_with(
+                &proposal,
+                &policies,
+                &resolver,
+                &SmartGuardOptions::default(),
+                now()
+            ),
+            SmartGuardDecision::Allowed
+        );
+        // Low RoH: headroom 0.9, bound 0.45.
+        assert_eq!(
+            evaluate_smart_and_consent_with(
+                &proposal,
+                &policies,
+                &resolver,
+                &at_roh(0.03),
+                now()
+            ),
+            SmartGuardDecision::Allowed
+        );
+        // Near the ceiling: headroom 0.1, bound 0.05.
+        assert!(matches!(
+            evaluate_smart_and_consent_with(
+                &proposal,
+                &policies,
+                &resolver,
+                &at_roh(0.27),
+                now()
+            ),
+            SmartGuardDecision::Rejected(_)
+        ));
+    }
+
+    #[test]
+    fn test_linf_bound_trips_when_l2_passes() {
+        let policies = SmartPolicyIndex::new(vec![SmartTokenPolicy {
+            token_id: "smart-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            scope: "motor".to_string(),
+            max_effect_size_l2: 0.5,
+            max_effect_size_linf: Some(0.2),
+            max_effect_size_l1: None,
+            requires_consent_state: ConsentState::Minimal,
+            expiry_utc: "2027-01-01T00:00:00Z".to_string(),
+        }]);
+        let resolver = MapResolver::new(&[("motor", ConsentState::Minimal, false)]);
+        let mut proposal = EvolutionProposalRecord {
+            proposal_id: "p-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            token_kind: "SMART".to_string(),
+            token_id: Some("smart-1".to_string()),
+            scope: "motor".to_string(),
+            ..Default::default()
+        };
+        let eval = |proposal: &EvolutionProposalRecord| {
+            evaluate_smart_and_consent_with(
+                proposal,
+                &policies,
+                &resolver,
+                &SmartGuardOptions::default(),
+                now(),
+            )
+        };
+
+        // Spread-out delta: within both bounds. L1 is unbounded for this token.
+        proposal.effect_bounds.l2_delta_norm = 0.3;
+        proposal.effect_bounds.linf_delta_norm = 0.15;
+        proposal.effect_bounds.l1_delta_norm = 5.0;
+        assert_eq!(eval(&proposal), SmartGuardDecision::Allowed);
+
+        // Single-electrode spike: same L2, Linf over its bound.
+        proposal.effect_bounds.linf_delta_norm = 0.28;
+        match eval(&proposal) {
+            SmartGuardDecision::Rejected(msg) => {
+                assert!(msg.contains("linf effect size 0.28 exceeds max_effect_size_linf 0.2"))
+            }
+            other => panic!("expected linf rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_nan_or_negative_effect_norm_rejected() {
+        let policies = SmartPolicyIndex::new(vec![SmartTokenPolicy {
+            token_id: "smart-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            scope: "motor".to_string(),
+            max_effect_size_l2: 0.5,
+            max_effect_size_linf: None,
+            max_effect_size_l1: None,
+            requires_consent_state: ConsentState::Minimal,
+            expiry_utc: "2027-01-01T00:00:00Z".to_string(),
+        }]);
+        let resolver = MapResolver::new(&[("motor", ConsentState::Minimal, false)]);
+        let proposal_with = |l2: f32, linf: f32| {
+            let mut proposal = EvolutionProposalRecord {
+                proposal_id: "p-1".to_string(),
+                subject_id: "subject-a".to_string(),
+                token_kind: "SMART".to_string(),
+                token_id: Some("smart-1".to_string()),
+                scope: "motor".to_string(),
+                ..Default::default()
+            };
+            proposal.effect_bounds.l2_delta_norm = l2;
+            proposal.effect_bounds.linf_delta_norm = linf;
+            proposal
+        };
+        let eval = |proposal: &EvolutionProposalRecord| {
+            evaluate_smart_and_consent_with(
+                proposal,
+                &policies,
+                &resolver,
+                &SmartGuardOptions::default(),
+                now(),
+            )
+        };
+
+        assert_eq!(eval(&proposal_with(0.3, 0.1)), SmartGuardDecision::Allowed);
+        assert_eq!(proposal_with(0.3, 0.3).effect_bounds.validate(), Ok(()));
+        for (l2, linf, expected) in [
+            (f32::NAN, 0.1, "l2_delta_norm = NaN"),
+            (0.3, -0.1, "linf_delta_norm = -0.1"),
+            (0.1, 0.3, "l2_delta_norm 0.1 is below linf_delta_norm 0.3"),
+        ] {
+            let bounds_err = proposal_with(l2, linf).effect_bounds.validate().unwrap_err();
+            assert!(bounds_err.contains(expected), "{}", bounds_err);
+            match eval(&proposal_with(l2, linf)) {
+                SmartGuardDecision::Rejected(msg) => {
+                    assert!(msg.contains("invalid effect bounds"), "{}", msg);
+                    assert!(msg.contains(expected), "{}", msg);
+                }
+                other => panic!("expected rejection for ({}, {}), got {:?}", l2, linf, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_expired_or_malformed_token_rejected() {
+        let policy_expiring = |expiry_utc: &str| {
+            SmartPolicyIndex::new(vec![SmartTokenPolicy {
+                token_id: "smart-1".to_string(),
+                subject_id: "subject-a".to_string(),
+                scope: "motor".to_string(),
+                max_effect_size_l2: 0.5,
+                max_effect_size_linf: None,
+                max_effect_size_l1: None,
+                requires_consent_state: ConsentState::Minimal,
+                expiry_utc: expiry_utc.to_string(),
+            }])
+        };
+        let resolver = MapResolver::new(&[("motor", ConsentState::Minimal, false)]);
+        let proposal = EvolutionProposalRecord {
+            proposal_id: "p-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            token_kind: "SMART".to_string(),
+            token_id: Some("smart-1".to_string()),
+            scope: "motor".to_string(),
+            ..Default::default()
+        };
+        let eval = |policies: &SmartPolicyIndex| {
+            evaluate_smart_and_consent_with(
+                &proposal,
+                policies,
+                &resolver,
+                &SmartGuardOptions::default(),
+                now(),
+            )
+        };
+
+        assert_eq!(
+            eval(&policy_expiring("2026-12-31T23:59:59Z")),
+            SmartGuardDecision::Allowed
+        );
+        assert_eq!(
+            eval(&policy_expiring("2026-05-31T23:59:59Z")),
+            SmartGuardDecision::Rejected(
+                "SMART token guard: token expired at 2026-05-31T23:59:59Z (token smart-1)"
+                    .to_string()
+            )
+        );
+        match eval(&policy_expiring("next tuesday")) {
+            SmartGuardDecision::Rejected(msg) => assert!(msg.contains("malformed expiry_utc")),
+            other => panic!("expected rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_revoked_consent_state_rejected_by_smart_guard() {
+        let policies = SmartPolicyIndex::new(vec![SmartTokenPolicy {
+            token_id: "smart-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            scope: "motor".to_string(),
+            max_effect_size_l2: 0.5,
+            max_effect_size_linf: None,
+            max_effect_size_l1: None,
+            requires_consent_state: ConsentState::Minimal,
+            expiry_utc: "2027-01-01T00:00:00Z".to_string(),
+        }]);
+        let proposal = EvolutionProposalRecord {
+            proposal_id: "p-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            token_kind: "SMART".to_string(),
+            token_id: Some("smart-1".to_string()),
+            scope: "motor".to_string(),
+            ..Default::default()
+        };
+        let eval = |resolver: &MapResolver| {
+            evaluate_smart_and_consent_with(
+                &proposal,
+                &policies,
+                resolver,
+                &SmartGuardOptions::default(),
+                now(),
+            )
+        };
+
+        // Revoked state without the `revoked` flag set is still a rejection.
+        let revoked = MapResolver::new(&[("motor", ConsentState::Revoked, false)]);
+        assert_eq!(
+            eval(&revoked),
+            SmartGuardDecision::Rejected(
+                "SMART token guard: consent revoked for subject/scope".to_string()
+            )
+        );
+        let none = MapResolver::new(&[("motor", ConsentState::None, false)]);
+        assert!(matches!(eval(&none), SmartGuardDecision::Rejected(_)));
+        let minimal = MapResolver::new(&[("motor", ConsentState::Minimal, false)]);
+        assert_eq!(eval(&minimal), SmartGuardDecision::Allowed);
+    }
+
+    #[test]
+    fn test_consent_satisfies_full_required_present_matrix() {
+        use ConsentState::*;
+        let states = [None, Minimal, Extended, Revoked];
+        let depths: Vec<u8> = states.iter().map(|s| s.depth()).collect();
+        assert_eq!(depths, vec![0, 1, 2, 0]);
+
+        // Rows: required; columns: present (None, Minimal, Extended, Revoked).
+        let expected = [
+            [true, true, true, false],
+            [false, true, true, false],
+            [false, false, true, false],
+            [true, true, true, false],
+        ];
+        for (required, row) in states.iter().zip(expected) {
+            for (present, want) in states.iter().zip(row) {
+                assert_eq!(
+                    present.satisfies(required),
+                    want,
+                    "present {:?} vs required {:?}",
+                    present,
+                    required
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_effective_consent_capped_by_minimal_scope() {
+        let resolver = MapResolver::new(&[
+            ("motor", ConsentState::Extended, false),
+            ("sleep", ConsentState::Minimal, false),
+            ("focus", ConsentState::Extended, false),
+        ]);
+        let effective =
+            effective_consent_for_scopes(&resolver, "subject-a", &["motor", "sleep", "focus"])
+                .unwrap();
+        assert_eq!(effective, ConsentState::Minimal);
+    }
+
+    #[test]
+    fn test_effective_consent_revoked_scope_forces_revoked() {
+        let resolver = MapResolver::new(&[
+            ("motor", ConsentState::Extended, false),
+            ("sleep", ConsentState::Extended, true),
+        ]);
+        let effective =
+            effective_consent_for_scopes(&resolver, "subject-a", &["motor", "sleep"]).unwrap();
+        assert_eq!(effective, ConsentState::Revoked);
+    }
+
+    #[test]
+    fn test_effective_consent_unknown_scope_errors() {
+        let resolver = MapResolver::new(&[("motor", ConsentState::Extended, false)]);
+        assert!(effective_consent_for_scopes(&resolver, "subject-a", &["motor", "vision"]).is_err());
+        assert!(effective_consent_for_scopes(&resolver, "subject-a", &[]).is_err());
+    }
+
+    struct CountingResolver {
+        inner: MapResolver,
+        calls: std::cell::Cell<usize>,
+    }
+
+    impl ConsentResolver for CountingResolver {
+        fn resolve_consent(&self, subject_id: &str, scope: &str) -> Result<ConsentSnapshot> {
+            self.calls.set(self.calls.get() + 1);
+            self.inner.resolve_consent(subject_id, scope)
+        }
+    }
+
+    #[test]
+    fn test_batch_resolves_each_scope_once_and_matches_single_calls() {
+        let policy = |token_id: &str, scope: &str| SmartTokenPolicy {
+            token_id: token_id.to_string(),
+            subject_id: "subject-a".to_string(),
+            scope: scope.to_string(),
+            max_effect_size_l2: 0.5,
+            max_effect_size_linf: None,
+            max_effect_size_l1: None,
+            requires_consent_state: ConsentState::Minimal,
+            expiry_utc: "2027-01-01T00:00:00Z".to_string(),
+        };
+        let policies =
+            SmartPolicyIndex::new(vec![policy("smart-motor", "motor"), policy("smart-vision", "vision")]);
+        let proposal = |id: &str, token_id: &str, scope: &str, l2: f32| {
+            let mut p = EvolutionProposalRecord {
+                proposal_id: id.to_string(),
+                subject_id: "subject-a".to_string(),
+                token_kind: "SMART".to_string(),
+                token_id: Some(token_id.to_string()),
+                scope: scope.to_string(),
+                ..Default::default()
+            };
+            p.effect_bounds.l2_delta_norm = l2;
+            p
+        };
+        let mut proposals: Vec<_> = (0..5)
+            .map(|i| proposal(&format!("p-{}", i), "smart-motor", "motor", 0.1 * i as f32))
+            .collect();
+        proposals.push(proposal("p-v1", "smart-vision", "vision", 0.1));
+        proposals.push(proposal("p-v2", "smart-vision", "vision", 0.1));
+
+        let resolver = CountingResolver {
+            inner: MapResolver::new(&[("motor", ConsentState::Extended, false)]),
+            calls: std::cell::Cell::new(0),
+        };
+        let batch = evaluate_smart_batch(&proposals, &policies, &resolver, now());
+        // One resolution for motor, one (failing) for vision.
+        assert_eq!(resolver.calls.get(), 2);
+
+        let single: Vec<_> = proposals
+            .iter()
+            .map(|p| {
+                evaluate_smart_and_consent_with(
+                    p,
+                    &policies,
+                    &resolver.inner,
+                    &SmartGuardOptions::default(),
+                    now(),
+                )
+            })
+            .collect();
+        assert_eq!(batch, single);
+        assert_eq!(batch[0], SmartGuardDecision::Allowed);
+        assert!(matches!(batch[5], SmartGuardDecision::Rejected(_)));
+    }
+
+    struct ContextResolver;
+
+    impl ConsentResolver for ContextResolver {
+        fn resolve_consent(&self, _subject_id: &str, _scope: &str) -> Result<ConsentSnapshot> {
+            Err(anyhow!("line 3 is not valid JSON")).context("consent shard: ledger.jsonl")
+        }
+    }
+
+    #[test]
+    fn test_batch_keeps_full_consent_error_chain() {
+        let policies = SmartPolicyIndex::new(vec![SmartTokenPolicy {
+            token_id: "smart-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            scope: "motor".to_string(),
+            max_effect_size_l2: 0.5,
+            max_effect_size_linf: None,
+            max_effect_size_l1: None,
+            requires_consent_state: ConsentState::Minimal,
+            expiry_utc: "2027-01-01T00:00:00Z".to_string(),
+        }]);
+        let proposal = EvolutionProposalRecord {
+            proposal_id: "p-1".to_string(),
+            subject_id: "subject-a".to_string(),
+            token_kind: "SMART".to_string(),
+            token_id: Some("smart-1".to_string()),
+            scope: "motor".to_string(),
+            ..Default::default()
+        };
+        let proposals = [proposal.clone(), proposal];
+        let batch = evaluate_smart_batch(&proposals, &policies, &ContextResolver, now());
+        assert_eq!(batch[0], batch[1]);
+        assert_eq!(
+            batch[0],
+            SmartGuardDecision::Rejected(
+                "SMART token guard: failed to resolve consent: consent shard: ledger.jsonl: line 3 is not valid JSON"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Canonical, deterministically-constructed example records for cross-tool testing.
+//!
+//! One fully-populated instance of each serializable record type. External
+//! analyzers and dashboards can use these as golden inputs; the committed
+//! snapshot at `SNAPSHOT_PATH` catches format drift. After an intended format
+//! change, regenerate it with `cargo test regenerate_snapshot -- --ignored`
+//! rather than editing the JSON by hand.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Serialize;
+
+use capability_core::CapabilityState as KernelCapabilityState;
+use neuroprint_core::log::{NeuroPrintLogEntry, ProvenanceMode};
+use neuroprint_core::nature::{NatureConflict, NatureLabel, NatureLabels};
+use neuroprint_core::NeuroPrintView;
+use organiccpualn::donutloopledger::DonutloopEntry;
+use policy_engine::hivemind_fence_log::{HiveMindFenceView, ProvenanceMode as FenceProvenanceMode};
+use policy_engine::hivemind_fence_view::{HiveMindFence, HiveMindFenceConfig, HiveMindFenceInput};
+use policyengine::biophysical_consensus::{
+    join_reasons, FairnessReason, FairnessReasonCode, FairnessVerdict,
+};
+use roh_model::RoHProjection;
+
+use crate::safest_first_policy::aln_schema::{
+    ALNPolicy, CapabilityState, CapabilityTransition, ConsentState, EvidenceCategory,
+    EvidenceFreshnessPolicy, EvidenceRef, JurisdictionTag, PolicyStack, Role,
+    UndatedEvidencePolicy,
+};
+
+/// Committed snapshot of `fixtures_json()`, relative to the crate root.
+pub const SNAPSHOT_PATH: &str = "src/main/schemas/examples/canonical_fixtures.json";
+
+/// Fixed timestamp used by every fixture.
+pub const FIXTURE_TIMESTAMP_UTC: &str = "2026-01-01T00:00:00Z";
+const FIXTURE_TIMESTAMP_MS: u64 = 1_767_225_600_000;
+
+/// One instance of each record type, in a stable field order.
+#[derive(Debug, Clone, Serialize)]
+pub struct Fixtures {
+    pub hivemind_fence_view: HiveMindFenceView,
+    pub neuroprint_view: NeuroPrintView,
+    pub neuroprint_log_entry: NeuroPrintLogEntry,
+    pub donutloop_entry: DonutloopEntry,
+    pub aln_policy: ALNPolicy,
+    pub fairness_verdict: FairnessVerdict,
+}
+
+/// Build the canonical fixtures. Same output on every call and platform.
+pub fn canonical_fixtures() -> Fixtures {
+    let neuroprint_view = NeuroPrintView {
+        blood: 0.55,
+        oxygen: 0.8,
+        wave: 0.4,
+        time: 0.25,
+        decay: 0.5,
+        lifeforce: 0.5,
+        brain: 0.6,
+        smart: 0.3,
+        evolve: 0.2,
+        power: 0.45,
+        tech: 0.35,
+        fear: 0.15,
+        pain: 0.1,
+        nano: 0.05,
+        labels: vec![NatureLabel::CalmStable],
+        roh_axis_contributions: Vec::new(),
+    };
+
+    let neuroprint_log_entry = NeuroPrintLogEntry {
+        timestamp_ms: FIXTURE_TIMESTAMP_MS,
+        subject_id: "fixture-subject".to_string(),
+        epoch_index: 42,
+        capability_state: KernelCapabilityState::CapControlledHuman,
+        roh: RoHProjection {
+            before: 0.14,
+            after: 0.15,
+            ceiling: 0.30,
+        },
+        neuroprint: neuroprint_view.clone(),
+        nature: Some(NatureLabels {
+            calm_stable: false,
+            overloaded: true,
+            recovery: false,
+            unfair_drain: true,
+            roh_volatile: false,
+            conflicts: vec![NatureConflict::CalmStableAndOverloaded],
+        }),
+        provenance_mode: ProvenanceMode::Simulation,
+    };
+
+    let fence_input = HiveMindFenceInput {
+        view_id: "fixture-view-42".to_string(),
+        subject_id: "fixture-subject".to_string(),
+        cohort_id: Some("fixture-cohort".to_string()),
+        epoch_index: 42,
+        roh_score: 0.26,
+        tol_fear: Some(0.4),
+        tol_pain: Some(0.3),
+        tol_decay: Some(0.7),
+        tol_lifeforce: Some(0.3),
+        cohort_mean_fear: Some(0.2),
+        cohort_mean_pain: Some(0.2),
+        cohort_decay_gini: Some(0.22),
+        cohort_fear_gini: Some(0.18),
+        cohort_pain_gini: Some(0.4),
+        prev_hexstamp: "0xHMFENCE-GENESIS".to_string(),
+        anchor_id: Some("fixture-anchor".to_string()),
+        timestamp_utc: FIXTURE_TIMESTAMP_UTC.to_string(),
+        provenance_mode: FenceProvenanceMode::Simulation,
+    };
+    let hivemind_fence_view = HiveMindFence::evaluate(&HiveMindFenceConfig::default(), &fence_input);
+
+    let donutloop_entry = DonutloopEntry {
+        entry_id: "fixture-entry-2".to_string(),
+        subject_id: "fixture-subject".to_string(),
+        proposal_id: "fixture-proposal".to_string(),
+        change_type: "smart-tune".to_string(),
+        tsafe_mode: "Observe".to_string(),
+        roh_before: 0.14,
+        roh_after: 0.12,
+        knowledge_factor: 0.5,
+        cybostate_factor: 0.5,
+        policy_refs: vec!["policy-0001-2026".to_string()],
+        hexstamp: "0xfixture-entry-2".to_string(),
+        timestamp_utc: FIXTURE_TIMESTAMP_UTC.to_string(),
+        prev_hexstamp: "0xfixture-entry-1".to_string(),
+    };
+
+    let mut aln_policy = ALNPolicy::new();
+    aln_policy.policy_stack.juris_local.push(JurisdictionTag::JurisLocal);
+    aln_policy.jurisdiction_harms = HashMap::from([(
+        JurisdictionTag::JurisLocal,
+        vec!["subliminal affect priming".to_string()],
+    )]);
+    aln_policy.evidence_freshness = EvidenceFreshnessPolicy {
+        max_evidence_age_secs: Some(180 * 24 * 3600),
+        undated_evidence_policy: UndatedEvidencePolicy::Reject,
+    };
+    aln_policy.default_roles = vec![Role::Learner, Role::Mentor];
+    aln_policy.transitions.push(CapabilityTransition {
+        from: CapabilityState::ModelOnly,
+        to: CapabilityState::LabBench,
+        required_evidence: vec![
+            EvidenceRef::issued_at("cid:QmFixtureBench", FIXTURE_TIMESTAMP_UTC)
+                .with_category(EvidenceCategory::Validation),
+        ],
+        required_consent: ConsentState::Minimal,
+        required_roles: vec![Role::Mentor],
+        policy_stack: PolicyStack::new(),
+        ltl_property: Some("G(lab_bench -> !live_coupling)".to_string()),
+    });
+
+    let fairness_reasons = vec![
+        FairnessReason::new(
+            FairnessReasonCode::VulnerabilityReduced,
+            Some(1),
+            "help-like deed reduced vulnerability at site 1",
+        ),
+        FairnessReason::new(
+            FairnessReasonCode::RailViolation,
+            Some(2),
+            "peer site 2 violates post-state safety rails (weight 0.50)",
+        ),
+    ];
+    let fairness_verdict = FairnessVerdict {
+        fairness_positive: true,
+        fairness_negative: true,
+        fairness_ambiguous: false,
+        reason: join_reasons(&fairness_reasons),
+        reason_events: fairness_reasons,
+        positive_weight: 1.0,
+        negative_weight: 0.5,
+        negative_sites: vec![2],
+    };
+
+    Fixtures {
+        hivemind_fence_view,
+        neuroprint_view,
+        neuroprint_log_entry,
+        donutloop_entry,
+        aln_policy,
+        fairness_verdict,
+    }
+}
+
+/// Pretty-printed JSON of all fixtures; this is the committed snapshot format.
+pub fn fixtures_json() -> String {
+    serde_json::to_string_pretty(&canonical_fixtures())
+        .expect("fixture serialization must not fail")
+}
+
+/// Write `fixtures_json()` to `SNAPSHOT_PATH` under `crate_root`.
+pub fn write_snapshot(crate_root: &Path) -> io::Result<()> {
+    fs::write(crate_root.join(SNAPSHOT_PATH), fixtures_json() + "\n")
+}
+
+/// Write one `<record_type>.json` file per fixture into `dir`.
+pub fn write_fixtures(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let value = serde_json::to_value(canonical_fixtures())?;
+    if let serde_json::Value::Object(records) = value {
+        for (name, record) in records {
+            let json = serde_json::to_string_pretty(&record)?;
+            fs::write(dir.join(format!("{}.json", name)), json + "\n")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNAPSHOT: &str = include_str!("main/schemas/examples/canonical_fixtures.json");
+
+    #[test]
+    fn test_fixtures_match_committed_snapshot() {
+        assert_eq!(
+            fixtures_json(),
+            SNAPSHOT.trim_end(),
+            "snapshot is stale; run `cargo test regenerate_snapshot -- --ignored`"
+        );
+    }
+
+    /// Rewrites the committed snapshot from `canonical_fixtures()`.
+    #[test]
+    #[ignore]
+    fn regenerate_snapshot() {
+        write_snapshot(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    }
+
+    #[test]
+    fn test_write_fixtures_one_file_per_record_type() {
+        let dir = std::env::temp_dir().join(format!("newrow-fixtures-{}", std::process::id()));
+        write_fixtures(&dir).unwrap();
+        for name in [
+            "hivemind_fence_view",
+            "neuroprint_view",
+            "neuroprint_log_entry",
+            "donutloop_entry",
+            "aln_policy",
+            "fairness_verdict",
+        ] {
+            let json = fs::read_to_string(dir.join(format!("{}.json", name))).unwrap();
+            assert!(serde_json::from_str::<serde_json::Value>(&json).is_ok());
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,2 @@
+pub mod fixtures;
+pub mod safest_first_policy;
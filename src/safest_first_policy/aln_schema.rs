@@ -1,11 +1,16 @@
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::path::Path;
 
 /// Directive NR-SAFE-0001 Compliance Note
 /// This schema is a verifiable, non-hypothetical specification.
 /// It defines the formal structure of the ALN policy engine.
 /// All states, transitions, and constraints are implementable, auditable, and testable.
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+/// Variants are declared in safety-lattice order, so the derived `Ord`
+/// matches `rank()`: ModelOnly < LabBench < ControlledHuman < GeneralUse.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum CapabilityState {
     /// Simulation-only: models, proofs, algorithm design.
@@ -23,6 +28,30 @@ pub enum CapabilityState {
     GeneralUse,
 }
 
+impl CapabilityState {
+    /// Position in the safety lattice (ModelOnly = 0 .. GeneralUse = 3), as
+    /// ranked by the policy engine's `capability_rank`.
+    pub fn rank(&self) -> u8 {
+        policyengine::alncore::capability_rank((*self).into())
+    }
+
+    /// True if moving from `self` to `to` lowers capability.
+    pub fn is_downgrade_to(&self, to: CapabilityState) -> bool {
+        to.rank() < self.rank()
+    }
+}
+
+impl From<CapabilityState> for policyengine::alncore::CapabilityState {
+    fn from(state: CapabilityState) -> Self {
+        match state {
+            CapabilityState::ModelOnly => Self::CapModelOnly,
+            CapabilityState::LabBench => Self::CapLabBench,
+            CapabilityState::ControlledHuman => Self::CapControlledHuman,
+            CapabilityState::GeneralUse => Self::CapGeneralUse,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum ConsentState {
@@ -39,6 +68,35 @@ pub enum ConsentState {
     Revoked,
 }
 
+/// A consent decision with its grant time and validity window.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConsentGrant {
+    pub state: ConsentState,
+    /// RFC 3339 UTC time at which consent was granted.
+    pub granted_utc: String,
+    pub valid_for_secs: u64,
+}
+
+impl ConsentGrant {
+    /// Consent in force at `now`. `Revoked` stays revoked; any other grant
+    /// decays to `None` once `valid_for_secs` has elapsed, and a malformed or
+    /// future `granted_utc` is treated as no consent.
+    pub fn effective_state(&self, now: DateTime<Utc>) -> ConsentState {
+        if self.state == ConsentState::Revoked {
+            return ConsentState::Revoked;
+        }
+        let granted = match DateTime::parse_from_rfc3339(&self.granted_utc) {
+            Ok(ts) => ts.with_timezone(&Utc),
+            Err(_) => return ConsentState::None,
+        };
+        let age_secs = now.signed_duration_since(granted).num_seconds();
+        if age_secs < 0 || age_secs as u64 >= self.valid_for_secs {
+            return ConsentState::None;
+        }
+        self.state.clone()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum Role {
@@ -61,7 +119,7 @@ pub enum JurisdictionTag {
     QuantumAiSafety,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct PolicyStack {
     pub base_medical: Vec<JurisdictionTag>,
     pub base_engineering: Vec<JurisdictionTag>,
@@ -90,6 +148,60 @@ impl PolicyStack {
             && !self.quantum_ai_safety.is_empty()
     }
 
+    /// Union of both stacks, category by category: `self`'s tags first, then
+    /// any of `other`'s not already present, each tag at most once per category.
+    pub fn merge(&self, other: &PolicyStack) -> PolicyStack {
+        fn union(a: &[JurisdictionTag], b: &[JurisdictionTag]) -> Vec<JurisdictionTag> {
+            let mut merged: Vec<JurisdictionTag> = Vec::with_capacity(a.len() + b.len());
+            for tag in a.iter().chain(b) {
+                if !merged.contains(tag) {
+                    merged.push(tag.clone());
+                }
+            }
+            merged
+        }
+        PolicyStack {
+            base_medical: union(&self.base_medical, &other.base_medical),
+            base_engineering: union(&self.base_engineering, &other.base_engineering),
+            juris_local: union(&self.juris_local, &other.juris_local),
+            quantum_ai_safety: union(&self.quantum_ai_safety, &other.quantum_ai_safety),
+        }
+    }
+
+    /// Incompatibilities that `merge` would silently paper over. No tags are
+    /// mutually exclusive today, so the only conflict is one tag filed under
+    /// different categories in the two stacks.
+    pub fn conflicts_with(&self, other: &PolicyStack) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        let others = other.categories();
+        for (name, tags) in self.categories() {
+            for tag in tags {
+                let same_category = others.iter().any(|(n, t)| *n == name && t.contains(tag));
+                if same_category {
+                    continue;
+                }
+                for (other_name, other_tags) in &others {
+                    if other_tags.contains(tag) {
+                        conflicts.push(format!(
+                            "{:?} is {} here but {} in the other stack",
+                            tag, name, other_name
+                        ));
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    fn categories(&self) -> [(&'static str, &[JurisdictionTag]); 4] {
+        [
+            ("BASE_MEDICAL", &self.base_medical),
+            ("BASE_ENGINEERING", &self.base_engineering),
+            ("JURIS_LOCAL", &self.juris_local),
+            ("QUANTUM_AI_SAFETY", &self.quantum_ai_safety),
+        ]
+    }
+
     pub fn to_canonical_string(&self) -> String {
         format!(
             "BASE_MEDICAL: {:?} | BASE_ENGINEERING: {:?} | JURIS_LOCAL: {:?} | QUANTUM_AI_SAFETY: {:?}",
@@ -98,23 +210,208 @@ impl PolicyStack {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// What kind of artifact a piece of evidence is.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceCategory {
+    /// Legacy / untagged evidence.
+    #[default]
+    Unspecified,
+    /// Derived from biophysical envelopes or RoH telemetry.
+    Biophysical,
+    /// Regulatory filings, approvals, or ethics decisions.
+    Regulatory,
+    /// Validation studies and test reports.
+    Validation,
+}
+
+/// Evidence object backing a transition (e.g., hash, CID), with optional
+/// issue time for freshness checks and a category.
+///
+/// Deserializes from either a bare identifier string (legacy form) or an
+/// object with `id`, `issued_utc`, and `category`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "EvidenceRefRepr")]
+pub struct EvidenceRef {
+    pub id: String,
+    /// RFC 3339 UTC time at which the evidence was produced, if known.
+    pub issued_utc: Option<String>,
+    pub category: EvidenceCategory,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EvidenceRefRepr {
+    Id(String),
+    Full {
+        id: String,
+        #[serde(default)]
+        issued_utc: Option<String>,
+        #[serde(default)]
+        category: EvidenceCategory,
+    },
+}
+
+impl From<EvidenceRefRepr> for EvidenceRef {
+    fn from(repr: EvidenceRefRepr) -> Self {
+        match repr {
+            EvidenceRefRepr::Id(id) => EvidenceRef::new(&id),
+            EvidenceRefRepr::Full { id, issued_utc, category } => EvidenceRef {
+                id,
+                issued_utc,
+                category,
+            },
+        }
+    }
+}
+
+impl EvidenceRef {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            issued_utc: None,
+            category: EvidenceCategory::Unspecified,
+        }
+    }
+
+    pub fn issued_at(id: &str, issued_utc: &str) -> Self {
+        Self {
+            issued_utc: Some(issued_utc.to_string()),
+            ..Self::new(id)
+        }
+    }
+
+    pub fn with_category(mut self, category: EvidenceCategory) -> Self {
+        self.category = category;
+        self
+    }
+}
+
+impl From<&str> for EvidenceRef {
+    fn from(id: &str) -> Self {
+        EvidenceRef::new(id)
+    }
+}
+
+/// A validated evidence identifier. Accepted formats:
+/// - `cid:` followed by a base58btc content identifier (e.g. `cid:QmZ4HH...`);
+/// - `0x` followed by a 64-digit hex digest (32-byte hash, e.g. SHA-256 / BLAKE3).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EvidenceId {
+    Cid(String),
+    Hex(String),
+}
+
+impl EvidenceId {
+    /// Hex digits expected after `0x`.
+    pub const HEX_DIGEST_LEN: usize = 64;
+
+    const BASE58_ALPHABET: &'static str =
+        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    /// Parse `id`, returning a descriptive error if it is not a recognized format.
+    pub fn parse(id: &str) -> Result<Self, String> {
+        if let Some(cid) = id.strip_prefix("cid:") {
+            if cid.is_empty() {
+                return Err(format!("Evidence id {:?} has an empty CID after `cid:`.", id));
+            }
+            if let Some(bad) = cid.chars().find(|c| !Self::BASE58_ALPHABET.contains(*c)) {
+                return Err(format!(
+                    "Evidence id {:?} is not a base58 CID: invalid character {:?}.",
+                    id, bad
+                ));
+            }
+            return Ok(EvidenceId::Cid(cid.to_string()));
+        }
+        if let Some(hex) = id.strip_prefix("0x") {
+            if hex.len() != Self::HEX_DIGEST_LEN || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(format!(
+                    "Evidence id {:?} is not a 0x-prefixed {}-digit hex hash.",
+                    id,
+                    Self::HEX_DIGEST_LEN
+                ));
+            }
+            return Ok(EvidenceId::Hex(hex.to_ascii_lowercase()));
+        }
+        Err(format!(
+            "Evidence id {:?} is not a recognized content identifier (expected `cid:<base58>` or `0x<{} hex digits>`).",
+            id,
+            Self::HEX_DIGEST_LEN
+        ))
+    }
+}
+
+impl std::fmt::Display for EvidenceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvidenceId::Cid(cid) => write!(f, "cid:{}", cid),
+            EvidenceId::Hex(hex) => write!(f, "0x{}", hex),
+        }
+    }
+}
+
+impl std::str::FromStr for EvidenceId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EvidenceId::parse(s)
+    }
+}
+
+impl From<EvidenceId> for EvidenceRef {
+    fn from(id: EvidenceId) -> Self {
+        EvidenceRef::new(&id.to_string())
+    }
+}
+
+/// How to treat evidence that carries no `issued_utc`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UndatedEvidencePolicy {
+    Reject,
+    Allow,
+}
+
+/// Freshness requirements for transition evidence.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EvidenceFreshnessPolicy {
+    /// Maximum evidence age in seconds; `None` disables the age limit.
+    pub max_evidence_age_secs: Option<u64>,
+    pub undated_evidence_policy: UndatedEvidencePolicy,
+}
+
+impl Default for EvidenceFreshnessPolicy {
+    fn default() -> Self {
+        Self {
+            max_evidence_age_secs: None,
+            undated_evidence_policy: UndatedEvidencePolicy::Allow,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct CapabilityTransition {
     pub from: CapabilityState,
     pub to: CapabilityState,
-    /// Evidence identifiers (e.g., hashes, CIDs).
-    pub required_evidence: Vec<String>,
+    /// Evidence objects (e.g., hashes, CIDs).
+    pub required_evidence: Vec<EvidenceRef>,
     pub required_consent: ConsentState,
     pub required_roles: Vec<Role>,
     pub policy_stack: PolicyStack,
-    /// Optional: temporal-logic property identifier.
+    /// Optional: temporal-logic property, checked by `Ltl::parse`.
     pub ltl_property: Option<String>,
 }
 
 impl CapabilityTransition {
-    pub fn validate(&self) -> Result<(), String> {
-        // 1. Enforce allowed graph (including rollbacks)
-        match (self.from, self.to) {
+    /// True for rollbacks (e.g. ControlledHuman -> LabBench).
+    pub fn is_downgrade(&self) -> bool {
+        self.from.is_downgrade_to(self.to)
+    }
+
+    /// The capability graph (including rollbacks), independent of evidence,
+    /// consent, and roles. Used by `validate` and `ALNPolicy::shortest_transition_path`.
+    pub fn check_graph_edge(from: CapabilityState, to: CapabilityState) -> Result<(), String> {
+        match (from, to) {
             // ModelOnly
             (CapabilityState::ModelOnly, CapabilityState::ModelOnly) => {}
             (CapabilityState::ModelOnly, CapabilityState::LabBench) => {}
@@ -147,11 +444,20 @@ impl CapabilityTransition {
 
             _ => return Err("Invalid capability state transition.".to_string()),
         }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        // 1. Enforce allowed graph (including rollbacks)
+        Self::check_graph_edge(self.from, self.to)?;
 
         // 2. Require evidence for any non-ModelOnly target
         if self.to != CapabilityState::ModelOnly && self.required_evidence.is_empty() {
             return Err("Evidence objects required for transition to non-ModelOnly state.".to_string());
         }
+        for evidence in &self.required_evidence {
+            EvidenceId::parse(&evidence.id)?;
+        }
 
         // 3. Require consent for any non-ModelOnly target
         if self.to != CapabilityState::ModelOnly && self.required_consent == ConsentState::None {
@@ -170,17 +476,549 @@ impl CapabilityTransition {
             return Err("Policy stack not satisfied: missing BASE_MEDICAL, BASE_ENGINEERING, or QUANTUM_AI_SAFETY.".to_string());
         }
 
+        // 6. Temporal-logic property must at least parse
+        if let Some(property) = &self.ltl_property {
+            Ltl::parse(property)
+                .map_err(|e| format!("Invalid ltl_property {:?}: {:?}", property, e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Structural validation plus evidence freshness as of `now`.
+    pub fn validate_at(
+        &self,
+        now: DateTime<Utc>,
+        freshness: &EvidenceFreshnessPolicy,
+    ) -> Result<(), String> {
+        self.validate()?;
+
+        for evidence in &self.required_evidence {
+            let issued = match &evidence.issued_utc {
+                Some(ts) => DateTime::parse_from_rfc3339(ts)
+                    .map_err(|e| {
+                        format!("Evidence {} has malformed issued_utc {:?}: {}", evidence.id, ts, e)
+                    })?
+                    .with_timezone(&Utc),
+                None => match freshness.undated_evidence_policy {
+                    UndatedEvidencePolicy::Allow => continue,
+                    UndatedEvidencePolicy::Reject => {
+                        return Err(format!(
+                            "Evidence {} has no issued_utc and undated evidence is rejected by policy.",
+                            evidence.id
+                        ))
+                    }
+                },
+            };
+
+            let age_secs = now.signed_duration_since(issued).num_seconds();
+            if age_secs < 0 {
+                return Err(format!(
+                    "Evidence {} is dated in the future ({}).",
+                    evidence.id, issued
+                ));
+            }
+            if let Some(max_age) = freshness.max_evidence_age_secs {
+                if age_secs as u64 > max_age {
+                    return Err(format!(
+                        "Evidence {} is stale: age {}s exceeds max_evidence_age {}s.",
+                        evidence.id, age_secs, max_age
+                    ));
+                }
+            }
+        }
+
         Ok(())
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Temporal-logic formula over a finite capability-state trace.
+///
+/// Grammar (loosest binding first): `->` (right-assoc), `||`, `&&`, then the
+/// prefix operators `!`, `G`, `F`, `X`, then atoms and parentheses. Atoms are
+/// `capability_state == <state>`, `capability_state != <state>`, a bare state
+/// name (true while in that state), or any other identifier as an opaque
+/// proposition whose truth value a capability trace cannot tell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ltl {
+    /// True while the trace is in this state.
+    InState(CapabilityState),
+    /// Named proposition not observable from a capability trace; unknown.
+    Prop(String),
+    Not(Box<Ltl>),
+    And(Box<Ltl>, Box<Ltl>),
+    Or(Box<Ltl>, Box<Ltl>),
+    Implies(Box<Ltl>, Box<Ltl>),
+    /// `G`: holds at every remaining position.
+    Globally(Box<Ltl>),
+    /// `F`: holds at some remaining position.
+    Finally(Box<Ltl>),
+    /// `X`: holds at the next position (false at the last one).
+    Next(Box<Ltl>),
+}
+
+/// Why an `ltl_property` string could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LtlParseError {
+    UnexpectedToken { position: usize, token: String },
+    UnexpectedEnd,
+    UnknownState { name: String },
+    /// `==` / `!=` must compare `capability_state` with a state name.
+    InvalidComparison { position: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LtlToken {
+    Ident(String),
+    LParen,
+    RParen,
+    Not,
+    Eq,
+    Ne,
+    And,
+    Or,
+    Implies,
+}
+
+fn tokenize_ltl(input: &str) -> Result<Vec<(usize, LtlToken)>, LtlParseError> {
+    let bytes = input.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        let two = bytes.get(i..i + 2);
+        let (token, len) = match c {
+            b' ' | b'\t' | b'\n' | b'\r' => {
+                i += 1;
+                continue;
+            }
+            b'(' => (LtlToken::LParen, 1),
+            b')' => (LtlToken::RParen, 1),
+            _ if two == Some(b"!=") => (LtlToken::Ne, 2),
+            _ if two == Some(b"==") => (LtlToken::Eq, 2),
+            _ if two == Some(b"&&") => (LtlToken::And, 2),
+            _ if two == Some(b"||") => (LtlToken::Or, 2),
+            _ if two == Some(b"->") => (LtlToken::Implies, 2),
+            b'!' => (LtlToken::Not, 1),
+            _ if c.is_ascii_alphanumeric() || c == b'_' => {
+                let end = bytes[i..]
+                    .iter()
+                    .position(|b| !(b.is_ascii_alphanumeric() || *b == b'_'))
+                    .map_or(bytes.len(), |n| i + n);
+                (LtlToken::Ident(input[i..end].to_string()), end - i)
+            }
+            _ => {
+                let token = input[i..].chars().next().unwrap_or_default().to_string();
+                return Err(LtlParseError::UnexpectedToken { position: i, token });
+            }
+        };
+        tokens.push((i, token));
+        i += len;
+    }
+    Ok(tokens)
+}
+
+fn ltl_state(name: &str) -> Option<CapabilityState> {
+    match name {
+        "model_only" => Some(CapabilityState::ModelOnly),
+        "lab_bench" => Some(CapabilityState::LabBench),
+        "controlled_human" => Some(CapabilityState::ControlledHuman),
+        "general_use" => Some(CapabilityState::GeneralUse),
+        _ => None,
+    }
+}
+
+struct LtlParser {
+    tokens: Vec<(usize, LtlToken)>,
+    pos: usize,
+}
+
+impl LtlParser {
+    fn peek(&self) -> Option<&LtlToken> {
+        self.tokens.get(self.pos).map(|(_, t)| t)
+    }
+
+    fn next(&mut self) -> Result<(usize, LtlToken), LtlParseError> {
+        let token = self.tokens.get(self.pos).cloned().ok_or(LtlParseError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn implies(&mut self) -> Result<Ltl, LtlParseError> {
+        let lhs = self.or()?;
+        if self.peek() == Some(&LtlToken::Implies) {
+            self.pos += 1;
+            return Ok(Ltl::Implies(Box::new(lhs), Box::new(self.implies()?)));
+        }
+        Ok(lhs)
+    }
+
+    fn or(&mut self) -> Result<Ltl, LtlParseError> {
+        let mut lhs = self.and()?;
+        while self.peek() == Some(&LtlToken::Or) {
+            self.pos += 1;
+            lhs = Ltl::Or(Box::new(lhs), Box::new(self.and()?));
+        }
+        Ok(lhs)
+    }
+
+    fn and(&mut self) -> Result<Ltl, LtlParseError> {
+        let mut lhs = self.unary()?;
+        while self.peek() == Some(&LtlToken::And) {
+            self.pos += 1;
+            lhs = Ltl::And(Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Ltl, LtlParseError> {
+        let (position, token) = self.next()?;
+        match token {
+            LtlToken::Not => Ok(Ltl::Not(Box::new(self.unary()?))),
+            LtlToken::Ident(op) if op == "G" => Ok(Ltl::Globally(Box::new(self.unary()?))),
+            LtlToken::Ident(op) if op == "F" => Ok(Ltl::Finally(Box::new(self.unary()?))),
+            LtlToken::Ident(op) if op == "X" => Ok(Ltl::Next(Box::new(self.unary()?))),
+            LtlToken::LParen => {
+                let inner = self.implies()?;
+                match self.next()? {
+                    (_, LtlToken::RParen) => Ok(inner),
+                    (position, token) => Err(LtlParseError::UnexpectedToken {
+                        position,
+                        token: format!("{:?}", token),
+                    }),
+                }
+            }
+            LtlToken::Ident(name) => self.atom(position, name),
+            token => Err(LtlParseError::UnexpectedToken {
+                position,
+                token: format!("{:?}", token),
+            }),
+        }
+    }
+
+    fn atom(&mut self, position: usize, name: String) -> Result<Ltl, LtlParseError> {
+        let negate = match self.peek() {
+            Some(LtlToken::Eq) => false,
+            Some(LtlToken::Ne) => true,
+            _ => {
+                return Ok(match ltl_state(&name) {
+                    Some(state) => Ltl::InState(state),
+                    None => Ltl::Prop(name),
+                })
+            }
+        };
+        self.pos += 1;
+        let (rhs_position, rhs) = self.next()?;
+        let LtlToken::Ident(rhs) = rhs else {
+            return Err(LtlParseError::InvalidComparison { position: rhs_position });
+        };
+        let state_name = match (name.as_str(), rhs.as_str()) {
+            ("capability_state", other) | (other, "capability_state") => other,
+            _ => return Err(LtlParseError::InvalidComparison { position }),
+        };
+        let state = ltl_state(state_name).ok_or_else(|| LtlParseError::UnknownState {
+            name: state_name.to_string(),
+        })?;
+        let atom = Ltl::InState(state);
+        Ok(if negate { Ltl::Not(Box::new(atom)) } else { atom })
+    }
+}
+
+impl Ltl {
+    /// Parse an `ltl_property` string, e.g. `G (capability_state != controlled_human)`.
+    pub fn parse(input: &str) -> Result<Ltl, LtlParseError> {
+        let mut parser = LtlParser { tokens: tokenize_ltl(input)?, pos: 0 };
+        let prop = parser.implies()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(prop),
+            Some((position, token)) => Err(LtlParseError::UnexpectedToken {
+                position: *position,
+                token: format!("{:?}", token),
+            }),
+        }
+    }
+
+    /// Three-valued truth at position `i`: `None` where the result depends
+    /// on a `Prop` the trace cannot observe.
+    fn holds_at(&self, trace: &[CapabilityState], i: usize) -> Option<bool> {
+        match self {
+            Ltl::InState(state) => Some(trace.get(i) == Some(state)),
+            Ltl::Prop(_) => None,
+            Ltl::Not(p) => p.holds_at(trace, i).map(|v| !v),
+            Ltl::And(a, b) => all_of([a.holds_at(trace, i), b.holds_at(trace, i)]),
+            Ltl::Or(a, b) => any_of([a.holds_at(trace, i), b.holds_at(trace, i)]),
+            Ltl::Implies(a, b) => {
+                any_of([a.holds_at(trace, i).map(|v| !v), b.holds_at(trace, i)])
+            }
+            Ltl::Globally(p) => all_of((i..trace.len()).map(|j| p.holds_at(trace, j))),
+            Ltl::Finally(p) => any_of((i..trace.len()).map(|j| p.holds_at(trace, j))),
+            Ltl::Next(p) if i + 1 < trace.len() => p.holds_at(trace, i + 1),
+            Ltl::Next(_) => Some(false),
+        }
+    }
+}
+
+/// Kleene conjunction: false if any is false, else unknown if any is unknown.
+fn all_of(values: impl IntoIterator<Item = Option<bool>>) -> Option<bool> {
+    let mut result = Some(true);
+    for value in values {
+        match value {
+            Some(false) => return Some(false),
+            None => result = None,
+            Some(true) => {}
+        }
+    }
+    result
+}
+
+/// Kleene disjunction: true if any is true, else unknown if any is unknown.
+fn any_of(values: impl IntoIterator<Item = Option<bool>>) -> Option<bool> {
+    all_of(values.into_iter().map(|v| v.map(|v| !v))).map(|v| !v)
+}
+
+/// Evaluate `prop` at the start of a finite capability-state trace.
+///
+/// A property whose verdict depends on a proposition the trace cannot
+/// observe does not hold here (fail closed); use `check_property_tristate`
+/// to tell that case apart from a definite failure.
+pub fn check_property(trace: &[CapabilityState], prop: &Ltl) -> bool {
+    check_property_tristate(trace, prop).unwrap_or(false)
+}
+
+/// Three-valued `check_property`: `None` when the verdict depends on a
+/// proposition the trace cannot observe (e.g. `live_coupling` in
+/// `G(lab_bench -> !live_coupling)` once the trace reaches `lab_bench`), so a
+/// formula is never reported as holding or failing on the strength of a
+/// proposition nobody checked.
+pub fn check_property_tristate(trace: &[CapabilityState], prop: &Ltl) -> Option<bool> {
+    prop.holds_at(trace, 0)
+}
+
+/// Structured action taxonomy that prohibited harms are matched against.
+///
+/// Matching is by kind, never by raw substring: "non-coercive neuromodulation
+/// review" is not `CoerciveNeuromodulation`, but "apply coercive
+/// neuromodulation to subject" is.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionKind {
+    CoerciveNeuromodulation,
+    NonConsensualNeuralSurveillance,
+    EmotionalManipulation,
+    NeuroDataMonetization,
+    AutomatedNeuroBehavioralProfiling,
+    /// Anything outside the built-in taxonomy, keyed by its normalized label
+    /// (lowercase, `_`/`-` as spaces). Jurisdiction overlays use this.
+    Other(String),
+}
+
+/// Normalized harm phrases of the built-in taxonomy. Longer phrases come
+/// first so the most specific one is reported.
+const HARM_PHRASES: &[(&str, ActionKind)] = &[
+    (
+        "neuro data monetization without explicit revocable consent",
+        ActionKind::NeuroDataMonetization,
+    ),
+    (
+        "emotional manipulation via neurostimulation",
+        ActionKind::EmotionalManipulation,
+    ),
+    (
+        "non consensual neural surveillance",
+        ActionKind::NonConsensualNeuralSurveillance,
+    ),
+    (
+        "nonconsensual neural surveillance",
+        ActionKind::NonConsensualNeuralSurveillance,
+    ),
+    (
+        "automated neuro behavioral profiling",
+        ActionKind::AutomatedNeuroBehavioralProfiling,
+    ),
+    (
+        "automated neurobehavioral profiling",
+        ActionKind::AutomatedNeuroBehavioralProfiling,
+    ),
+    ("coercive neuromodulation", ActionKind::CoerciveNeuromodulation),
+    ("neuro data monetization", ActionKind::NeuroDataMonetization),
+    ("neurodata monetization", ActionKind::NeuroDataMonetization),
+    ("emotional manipulation", ActionKind::EmotionalManipulation),
+];
+
+/// Words that negate a harm phrase they directly precede
+/// ("non coercive neuromodulation", "no emotional manipulation").
+const NEGATIONS: &[&str] = &["non", "not", "no", "anti"];
+
+fn normalize_label(label: &str) -> String {
+    label
+        .to_lowercase()
+        .replace(['_', '-'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// True if normalized `label` contains normalized `phrase` on word
+/// boundaries, at a position not directly preceded by a negation word.
+fn contains_harm_phrase(label: &str, phrase: &str) -> bool {
+    let words: Vec<&str> = label.split(' ').collect();
+    let phrase: Vec<&str> = phrase.split(' ').collect();
+    if phrase.is_empty() || phrase.len() > words.len() {
+        return false;
+    }
+    (0..=words.len() - phrase.len()).any(|start| {
+        words[start..start + phrase.len()] == phrase[..]
+            && (start == 0 || !NEGATIONS.contains(&words[start - 1]))
+    })
+}
+
+impl ActionKind {
+    /// Map a legacy action or harm label onto the taxonomy.
+    /// Case, `_`/`-` separators, and repeated whitespace are ignored; a label
+    /// that contains a non-negated harm phrase is classified as that harm.
+    pub fn from_label(label: &str) -> Self {
+        let normalized = normalize_label(label);
+        HARM_PHRASES
+            .iter()
+            .find(|(phrase, _)| contains_harm_phrase(&normalized, phrase))
+            .map(|(_, kind)| kind.clone())
+            .unwrap_or(ActionKind::Other(normalized))
+    }
+
+    /// True if an action of this kind falls under the prohibited harm `harm`.
+    /// Built-in harms compare by kind; any other harm matches an `Other`
+    /// label containing it as a non-negated phrase.
+    pub fn is_covered_by(&self, harm: &str) -> bool {
+        match (ActionKind::from_label(harm), self) {
+            (ActionKind::Other(phrase), ActionKind::Other(label)) => {
+                contains_harm_phrase(label, &phrase)
+            }
+            (harm_kind, kind) => harm_kind == *kind,
+        }
+    }
+}
+
+/// An action submitted for a permit check.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Action {
+    pub kind: ActionKind,
+    /// Free-form labels carried for audit; not used for harm matching.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl Action {
+    pub fn new(kind: ActionKind) -> Self {
+        Self {
+            kind,
+            labels: vec![],
+        }
+    }
+
+    /// Backward-compatible constructor from a legacy action label.
+    pub fn from_label(label: &str) -> Self {
+        Self {
+            kind: ActionKind::from_label(label),
+            labels: vec![label.to_string()],
+        }
+    }
+}
+
+/// A condition `check_action` found satisfied on the way to a permit.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "condition", rename_all = "snake_case")]
+pub enum PermitCondition {
+    /// Action kind matched no base or jurisdiction-overlay prohibited harm.
+    NoProhibitedHarmMatch,
+    /// Current state is ModelOnly; caller restricts to simulation-only actions.
+    ModelOnlySimulation,
+    /// Consent is Minimal or Extended.
+    ConsentAtLeastMinimal { consent: ConsentState },
+    /// At least one role is held.
+    RolePresent { roles: Vec<Role> },
+}
+
+/// Self-explaining outcome of `ALNPolicy::check_action`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum ActionReason {
+    /// Permitted; `satisfied` lists every check that passed, in check order.
+    Permitted { satisfied: Vec<PermitCondition> },
+    /// Action kind matched a prohibited harm.
+    DeniedProhibitedHarm { harm: String },
+    /// Live action without at least Minimal consent.
+    DeniedInsufficientConsent { consent: ConsentState },
+    /// Live action with no role held.
+    DeniedNoRole,
+}
+
+impl ActionReason {
+    pub fn is_permitted(&self) -> bool {
+        matches!(self, ActionReason::Permitted { .. })
+    }
+
+    /// Human-readable one-liner, e.g.
+    /// "permitted because: no prohibited-harm match + ModelOnly + simulation-only".
+    pub fn explain(&self) -> String {
+        match self {
+            ActionReason::Permitted { satisfied } => {
+                let parts: Vec<String> = satisfied
+                    .iter()
+                    .map(|c| match c {
+                        PermitCondition::NoProhibitedHarmMatch => {
+                            "no prohibited-harm match".to_string()
+                        }
+                        PermitCondition::ModelOnlySimulation => {
+                            "ModelOnly + simulation-only".to_string()
+                        }
+                        PermitCondition::ConsentAtLeastMinimal { consent } => {
+                            format!("consent {:?}", consent)
+                        }
+                        PermitCondition::RolePresent { roles } => format!("roles {:?}", roles),
+                    })
+                    .collect();
+                format!("permitted because: {}", parts.join(" + "))
+            }
+            ActionReason::DeniedProhibitedHarm { harm } => {
+                format!("denied: matches prohibited harm \"{}\"", harm)
+            }
+            ActionReason::DeniedInsufficientConsent { consent } => {
+                format!("denied: consent {:?} is below Minimal", consent)
+            }
+            ActionReason::DeniedNoRole => "denied: no role held".to_string(),
+        }
+    }
+}
+
+/// Why an `ALNPolicy` could not be loaded from or saved to disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PolicyIoError {
+    Io { path: String, message: String },
+    Parse { path: String, message: String },
+    Serialize { message: String },
+    /// `transitions[index]` failed `CapabilityTransition::validate`.
+    InvalidTransition { index: usize, message: String },
+    /// `.toml` path in a build without the `toml` feature.
+    UnsupportedFormat { path: String },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ALNPolicy {
     pub id: String,
     pub policy_stack: PolicyStack,
     pub transitions: Vec<CapabilityTransition>,
     /// Names/labels of prohibited harms.
     pub prohibited_harms: Vec<String>,
+    /// Per-jurisdiction prohibited harms, applied on top of `prohibited_harms`
+    /// for every jurisdiction present in `policy_stack`.
+    ///
+    /// Overlays are additive, never subtractive: a jurisdiction can add harms
+    /// but cannot un-prohibit anything in the base list.
+    #[serde(default)]
+    pub jurisdiction_harms: HashMap<JurisdictionTag, Vec<String>>,
+    /// Freshness requirements applied by `add_transition_at`.
+    #[serde(default)]
+    pub evidence_freshness: EvidenceFreshnessPolicy,
     pub default_capability: CapabilityState,
     pub default_consent: ConsentState,
     pub default_roles: Vec<Role>,
@@ -199,6 +1037,8 @@ impl ALNPolicy {
                 "neuro-data monetization without explicit revocable consent".to_string(),
                 "automated neuro-behavioral profiling".to_string(),
             ],
+            jurisdiction_harms: HashMap::new(),
+            evidence_freshness: EvidenceFreshnessPolicy::default(),
             default_capability: CapabilityState::ModelOnly,
             default_consent: ConsentState::None,
             default_roles: vec![Role::Learner],
@@ -211,47 +1051,258 @@ impl ALNPolicy {
         Ok(())
     }
 
+    /// Like `add_transition`, but also enforces `evidence_freshness` as of `now`.
+    pub fn add_transition_at(
+        &mut self,
+        transition: CapabilityTransition,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        transition.validate_at(now, &self.evidence_freshness)?;
+        self.transitions.push(transition);
+        Ok(())
+    }
+
+    /// Load a policy from `path` (TOML for `.toml` with the `toml` feature,
+    /// JSON otherwise), rejecting it if any transition fails `validate()`.
+    pub fn load_from_path(path: &Path) -> Result<Self, PolicyIoError> {
+        let text = std::fs::read_to_string(path).map_err(|e| PolicyIoError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        let parse_err = |message: String| PolicyIoError::Parse {
+            path: path.display().to_string(),
+            message,
+        };
+        let policy: ALNPolicy = if is_toml_path(path) {
+            #[cfg(feature = "toml")]
+            {
+                toml::from_str(&text).map_err(|e| parse_err(e.to_string()))?
+            }
+            #[cfg(not(feature = "toml"))]
+            {
+                return Err(PolicyIoError::UnsupportedFormat {
+                    path: path.display().to_string(),
+                });
+            }
+        } else {
+            serde_json::from_str(&text).map_err(|e| parse_err(e.to_string()))?
+        };
+
+        for (index, transition) in policy.transitions.iter().enumerate() {
+            transition
+                .validate()
+                .map_err(|message| PolicyIoError::InvalidTransition { index, message })?;
+        }
+        Ok(policy)
+    }
+
+    /// Write this policy to `path`, in the same format `load_from_path` reads.
+    pub fn save_to_path(&self, path: &Path) -> Result<(), PolicyIoError> {
+        let ser_err = |message: String| PolicyIoError::Serialize { message };
+        let text = if is_toml_path(path) {
+            #[cfg(feature = "toml")]
+            {
+                toml::to_string_pretty(self).map_err(|e| ser_err(e.to_string()))?
+            }
+            #[cfg(not(feature = "toml"))]
+            {
+                return Err(PolicyIoError::UnsupportedFormat {
+                    path: path.display().to_string(),
+                });
+            }
+        } else {
+            serde_json::to_string_pretty(self).map_err(|e| ser_err(e.to_string()))?
+        };
+        std::fs::write(path, text).map_err(|e| PolicyIoError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })
+    }
+
     /// Check if a concrete action is allowed, given current state, consent, and roles.
+    /// Consent is taken from `grant` as of `now`; expired consent counts as absent.
     /// NOTE: This is intentionally conservative and should be refined per-action later.
     pub fn is_action_permitted(
         &self,
         current_state: CapabilityState,
-        consent: ConsentState,
+        grant: &ConsentGrant,
+        now: DateTime<Utc>,
         roles: &[Role],
         action_label: &str,
     ) -> bool {
-        // 1. Hard prohibitions: block if action label matches any prohibited harm pattern.
-        let action_lower = action_label.to_lowercase();
-        if self
-            .prohibited_harms
-            .iter()
-            .any(|h| action_lower.contains(&h.to_lowercase()))
+        self.check_action(current_state, grant.effective_state(now), roles, action_label)
+            .is_permitted()
+    }
+
+    /// Same checks as `is_action_permitted`, but returns the reason for the
+    /// decision: the first failed check on deny, or every satisfied condition
+    /// on permit. The label is classified with `Action::from_label`.
+    pub fn check_action(
+        &self,
+        current_state: CapabilityState,
+        consent: ConsentState,
+        roles: &[Role],
+        action_label: &str,
+    ) -> ActionReason {
+        self.check_structured_action(
+            current_state,
+            consent,
+            roles,
+            &Action::from_label(action_label),
+        )
+    }
+
+    /// `check_action` for an already-classified action.
+    pub fn check_structured_action(
+        &self,
+        current_state: CapabilityState,
+        consent: ConsentState,
+        roles: &[Role],
+        action: &Action,
+    ) -> ActionReason {
+        // 1. Hard prohibitions: block if the action kind matches any prohibited harm,
+        //    from the base list or any active jurisdiction overlay.
+        if let Some(harm) = self
+            .effective_prohibited_harms()
+            .into_iter()
+            .find(|h| action.kind.is_covered_by(h))
         {
-            return false;
+            return ActionReason::DeniedProhibitedHarm {
+                harm: harm.to_string(),
+            };
         }
+        let mut satisfied = vec![PermitCondition::NoProhibitedHarmMatch];
 
         // 2. ModelOnly: permit analysis/simulation actions only.
         if current_state == CapabilityState::ModelOnly {
             // For now, assume caller filters to simulation-only actions at this state.
-            return true;
+            satisfied.push(PermitCondition::ModelOnlySimulation);
+            return ActionReason::Permitted { satisfied };
         }
 
         // 3. Non-ModelOnly: require at least Minimal consent.
         if consent == ConsentState::None || consent == ConsentState::Revoked {
-            return false;
+            return ActionReason::DeniedInsufficientConsent { consent };
         }
+        satisfied.push(PermitCondition::ConsentAtLeastMinimal { consent });
 
         // 4. Require at least one role present (to be aligned with transition-level checks).
         if roles.is_empty() {
-            return false;
+            return ActionReason::DeniedNoRole;
         }
+        satisfied.push(PermitCondition::RolePresent {
+            roles: roles.to_vec(),
+        });
+
+        ActionReason::Permitted { satisfied }
+    }
 
-        true
+    /// Base prohibited harms plus the overlays of every jurisdiction in the
+    /// policy stack. The base list is always included, so an overlay can only
+    /// widen the set of blocked actions.
+    pub fn effective_prohibited_harms(&self) -> Vec<&str> {
+        let stack = &self.policy_stack;
+        let mut harms: Vec<&str> = self.prohibited_harms.iter().map(String::as_str).collect();
+        for tag in stack
+            .base_medical
+            .iter()
+            .chain(stack.base_engineering.iter())
+            .chain(stack.juris_local.iter())
+            .chain(stack.quantum_ai_safety.iter())
+        {
+            if let Some(extra) = self.jurisdiction_harms.get(tag) {
+                for h in extra {
+                    if !harms.contains(&h.as_str()) {
+                        harms.push(h.as_str());
+                    }
+                }
+            }
+        }
+        harms
+    }
+
+    /// Fewest-step legal route from `from` to `to` over the capability graph
+    /// (rollbacks included), endpoints inclusive. `from == to` yields `[from]`;
+    /// `None` if `to` is unreachable.
+    pub fn shortest_transition_path(
+        &self,
+        from: CapabilityState,
+        to: CapabilityState,
+    ) -> Option<Vec<CapabilityState>> {
+        use CapabilityState::*;
+        const ALL: [CapabilityState; 4] = [ModelOnly, LabBench, ControlledHuman, GeneralUse];
+
+        let mut previous: HashMap<CapabilityState, CapabilityState> = HashMap::new();
+        let mut queue = std::collections::VecDeque::from([from]);
+        while let Some(state) = queue.pop_front() {
+            if state == to {
+                let mut path = vec![to];
+                let mut cursor = to;
+                while let Some(&p) = previous.get(&cursor) {
+                    path.push(p);
+                    cursor = p;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in ALL {
+                if next != from
+                    && !previous.contains_key(&next)
+                    && CapabilityTransition::check_graph_edge(state, next).is_ok()
+                {
+                    previous.insert(next, state);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
     }
 
     pub fn valid_transitions_from(&self, from: CapabilityState) -> Vec<&CapabilityTransition> {
         self.transitions.iter().filter(|t| t.from == from).collect()
     }
+
+    /// Graphviz digraph of `self.transitions`: one node per `CapabilityState`
+    /// and one edge per added transition, labelled with its consent and roles.
+    /// Rollback edges are drawn red and dashed.
+    pub fn to_dot(&self) -> String {
+        use CapabilityState::*;
+        fn node(state: CapabilityState) -> &'static str {
+            match state {
+                ModelOnly => "model_only",
+                LabBench => "lab_bench",
+                ControlledHuman => "controlled_human",
+                GeneralUse => "general_use",
+            }
+        }
+
+        let mut dot = format!("digraph \"{}\" {{\n    rankdir=LR;\n", self.id.replace('"', "\\\""));
+        for state in [ModelOnly, LabBench, ControlledHuman, GeneralUse] {
+            dot.push_str(&format!("    {} [label=\"{:?}\"];\n", node(state), state));
+        }
+        for t in &self.transitions {
+            let roles = if t.required_roles.is_empty() {
+                "none".to_string()
+            } else {
+                t.required_roles.iter().map(|r| format!("{:?}", r)).collect::<Vec<_>>().join(", ")
+            };
+            let style = if t.is_downgrade() { ", color=red, style=dashed" } else { "" };
+            dot.push_str(&format!(
+                "    {} -> {} [label=\"consent: {:?}\\nroles: {}\"{}];\n",
+                node(t.from),
+                node(t.to),
+                t.required_consent,
+                roles,
+                style
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn is_toml_path(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
 }
 
 #[cfg(test)]
@@ -281,7 +1332,7 @@ mod tests {
         let transition = CapabilityTransition {
             from: CapabilityState::ModelOnly,
             to: CapabilityState::LabBench,
-            required_evidence: vec!["cid:QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR".to_string()],
+            required_evidence: vec!["cid:QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR".into()],
             required_consent: ConsentState::Minimal,
             required_roles: vec![Role::Teacher],
             policy_stack: PolicyStack::new(),
@@ -291,6 +1342,109 @@ mod tests {
         policy.add_transition(transition).unwrap();
     }
 
+    #[test]
+    fn test_ltl_property_parses_and_checks_trace() {
+        use CapabilityState::*;
+        let prop = Ltl::parse("G (capability_state != controlled_human)").unwrap();
+        assert_eq!(
+            prop,
+            Ltl::Globally(Box::new(Ltl::Not(Box::new(Ltl::InState(ControlledHuman)))))
+        );
+        assert!(check_property(&[ModelOnly, LabBench, LabBench], &prop));
+        assert!(!check_property(
+            &[ModelOnly, LabBench, ControlledHuman, LabBench],
+            &prop
+        ));
+
+        let reaches = Ltl::parse("F (capability_state == lab_bench && X !model_only)").unwrap();
+        assert!(check_property(&[ModelOnly, LabBench, LabBench], &reaches));
+        assert!(!check_property(&[ModelOnly, LabBench], &reaches));
+    }
+
+    #[test]
+    fn test_unobservable_proposition_gives_no_verdict() {
+        use CapabilityState::*;
+        // The canonical fixture's property: `live_coupling` is not in a trace.
+        let guarded = Ltl::parse("G(lab_bench -> !live_coupling)").unwrap();
+        assert_eq!(check_property_tristate(&[ModelOnly, LabBench], &guarded), None);
+        assert!(!check_property(&[ModelOnly, LabBench], &guarded));
+        // Never reaching lab_bench decides it without the proposition.
+        assert_eq!(check_property_tristate(&[ModelOnly, ModelOnly], &guarded), Some(true));
+
+        let coupled = Ltl::parse("G live_coupling").unwrap();
+        assert_eq!(check_property_tristate(&[ModelOnly], &coupled), None);
+        assert_eq!(check_property_tristate(&[], &coupled), Some(true));
+        // A definite conjunct still decides the formula.
+        let capped = Ltl::parse("live_coupling && controlled_human").unwrap();
+        assert_eq!(check_property_tristate(&[ModelOnly], &capped), Some(false));
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_ltl_property() {
+        let mut transition = CapabilityTransition {
+            from: CapabilityState::ModelOnly,
+            to: CapabilityState::LabBench,
+            required_evidence: vec!["cid:QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR".into()],
+            required_consent: ConsentState::Minimal,
+            required_roles: vec![Role::Teacher],
+            policy_stack: PolicyStack::new(),
+            ltl_property: Some("G (capability_state != ".to_string()),
+        };
+        assert!(transition.validate().unwrap_err().contains("ltl_property"));
+        transition.ltl_property = Some("G (capability_state == orbit)".to_string());
+        assert!(transition.validate().is_err());
+        assert_eq!(
+            Ltl::parse("G (a == b)"),
+            Err(LtlParseError::InvalidComparison { position: 3 })
+        );
+    }
+
+    #[test]
+    fn test_capability_state_ordering_follows_safety_lattice() {
+        use CapabilityState::*;
+        let mut states = vec![GeneralUse, ModelOnly, ControlledHuman, LabBench];
+        states.sort();
+        assert_eq!(states, vec![ModelOnly, LabBench, ControlledHuman, GeneralUse]);
+        for pair in states.windows(2) {
+            assert!(pair[0].rank() < pair[1].rank());
+        }
+
+        let rollback = CapabilityTransition {
+            from: ControlledHuman,
+            to: LabBench,
+            required_evidence: vec![EvidenceRef::new("cid:QmRevert")],
+            required_consent: ConsentState::Minimal,
+            required_roles: vec![Role::RegulatoryGuardian],
+            policy_stack: PolicyStack::new(),
+            ltl_property: None,
+        };
+        assert!(rollback.is_downgrade());
+        assert!(!LabBench.is_downgrade_to(ControlledHuman));
+        assert!(!LabBench.is_downgrade_to(LabBench));
+    }
+
+    #[test]
+    fn test_shortest_transition_path() {
+        use CapabilityState::*;
+        let policy = ALNPolicy::new();
+        assert_eq!(
+            policy.shortest_transition_path(ModelOnly, GeneralUse),
+            Some(vec![ModelOnly, LabBench, ControlledHuman, GeneralUse])
+        );
+        assert_eq!(
+            policy.shortest_transition_path(LabBench, ControlledHuman),
+            Some(vec![LabBench, ControlledHuman])
+        );
+        assert_eq!(
+            policy.shortest_transition_path(GeneralUse, ModelOnly),
+            Some(vec![GeneralUse, ModelOnly])
+        );
+        assert_eq!(
+            policy.shortest_transition_path(ControlledHuman, ControlledHuman),
+            Some(vec![ControlledHuman])
+        );
+    }
+
     #[test]
     fn test_capability_transition_invalid_direct_model_to_controlled() {
         let transition = CapabilityTransition {
@@ -305,12 +1459,288 @@ mod tests {
         assert!(transition.validate().is_err());
     }
 
+    fn lab_to_controlled_with(evidence: EvidenceRef) -> CapabilityTransition {
+        CapabilityTransition {
+            from: CapabilityState::LabBench,
+            to: CapabilityState::ControlledHuman,
+            required_evidence: vec![evidence],
+            required_consent: ConsentState::Extended,
+            required_roles: vec![Role::RegulatoryGuardian],
+            policy_stack: PolicyStack::new(),
+            ltl_property: None,
+        }
+    }
+
+    fn six_month_freshness(undated: UndatedEvidencePolicy) -> EvidenceFreshnessPolicy {
+        EvidenceFreshnessPolicy {
+            max_evidence_age_secs: Some(180 * 24 * 3600),
+            undated_evidence_policy: undated,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_evidence_fresh_accepted() {
+        let t = lab_to_controlled_with(EvidenceRef::issued_at("cid:QmFresh", "2026-05-01T00:00:00Z"));
+        assert!(t
+            .validate_at(now(), &six_month_freshness(UndatedEvidencePolicy::Reject))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_evidence_stale_rejected() {
+        let t = lab_to_controlled_with(EvidenceRef::issued_at("cid:QmExpired", "2025-05-01T00:00:00Z"));
+        let err = t
+            .validate_at(now(), &six_month_freshness(UndatedEvidencePolicy::Allow))
+            .unwrap_err();
+        assert!(err.contains("stale"));
+    }
+
+    #[test]
+    fn test_evidence_undated_follows_policy() {
+        let t = lab_to_controlled_with(EvidenceRef::new("cid:QmUndated"));
+        assert!(t
+            .validate_at(now(), &six_month_freshness(UndatedEvidencePolicy::Allow))
+            .is_ok());
+        assert!(t
+            .validate_at(now(), &six_month_freshness(UndatedEvidencePolicy::Reject))
+            .is_err());
+    }
+
+    fn temp_policy_path(name: &str, ext: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("aln-policy-{}-{}.{}", name, std::process::id(), ext))
+    }
+
+    fn round_trip_policy() -> ALNPolicy {
+        let mut policy = ALNPolicy::new();
+        policy
+            .add_transition(lab_to_controlled_with(EvidenceRef::issued_at(
+                "cid:QmRoundTrip",
+                "2026-01-01T00:00:00Z",
+            )))
+            .unwrap();
+        policy
+    }
+
+    #[test]
+    fn test_policy_json_round_trip() {
+        let policy = round_trip_policy();
+        let mut extensions = vec!["json"];
+        if cfg!(feature = "toml") {
+            extensions.push("toml");
+        }
+        for ext in extensions {
+            let path = temp_policy_path("round-trip", ext);
+            policy.save_to_path(&path).unwrap();
+            let loaded = ALNPolicy::load_from_path(&path);
+            std::fs::remove_file(&path).ok();
+            assert_eq!(loaded, Ok(policy.clone()), "{} round trip", ext);
+        }
+    }
+
+    #[test]
+    fn test_policy_load_rejects_illegal_transition() {
+        let mut policy = round_trip_policy();
+        let mut illegal = lab_to_controlled_with(EvidenceRef::new("cid:QmSkip"));
+        illegal.from = CapabilityState::ModelOnly;
+        policy.transitions.push(illegal);
+
+        let path = temp_policy_path("illegal", "json");
+        policy.save_to_path(&path).unwrap();
+        let loaded = ALNPolicy::load_from_path(&path);
+        std::fs::remove_file(&path).ok();
+        match loaded {
+            Err(PolicyIoError::InvalidTransition { index: 1, message }) => {
+                assert!(message.contains("ModelOnly → ControlledHuman"), "{}", message)
+            }
+            other => panic!("expected InvalidTransition, got {:?}", other),
+        }
+
+        assert!(matches!(
+            ALNPolicy::load_from_path(&temp_policy_path("missing", "json")),
+            Err(PolicyIoError::Io { .. })
+        ));
+    }
+
+    fn grant(state: ConsentState) -> ConsentGrant {
+        ConsentGrant {
+            state,
+            granted_utc: "2026-01-01T00:00:00Z".to_string(),
+            valid_for_secs: 30 * 24 * 3600,
+        }
+    }
+
+    /// A time inside the validity window of every `grant(..)`.
+    fn granted_now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-15T00:00:00Z").unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_consent_grant_fresh_expired_and_revoked() {
+        let fresh = granted_now();
+        let expired = DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let policy = ALNPolicy::new();
+        let roles = [Role::Mentor];
+
+        let extended = grant(ConsentState::Extended);
+        assert_eq!(extended.effective_state(fresh), ConsentState::Extended);
+        assert!(policy.is_action_permitted(
+            CapabilityState::LabBench,
+            &extended,
+            fresh,
+            &roles,
+            "observe"
+        ));
+
+        assert_eq!(extended.effective_state(expired), ConsentState::None);
+        assert!(!policy.is_action_permitted(
+            CapabilityState::LabBench,
+            &extended,
+            expired,
+            &roles,
+            "observe"
+        ));
+
+        let revoked = grant(ConsentState::Revoked);
+        assert_eq!(revoked.effective_state(fresh), ConsentState::Revoked);
+        assert_eq!(revoked.effective_state(expired), ConsentState::Revoked);
+
+        let malformed = ConsentGrant {
+            granted_utc: "yesterday".to_string(),
+            ..extended
+        };
+        assert_eq!(malformed.effective_state(fresh), ConsentState::None);
+    }
+
+    #[test]
+    fn test_policy_stack_merge_dedups_and_adds_juris_local() {
+        let base = PolicyStack::new();
+        let local = PolicyStack {
+            base_medical: vec![JurisdictionTag::EuMdr],
+            base_engineering: vec![],
+            juris_local: vec![JurisdictionTag::JurisLocal],
+            quantum_ai_safety: vec![JurisdictionTag::QuantumAiSafety],
+        };
+
+        let merged = base.merge(&local);
+        assert_eq!(merged.base_medical, vec![JurisdictionTag::Fda, JurisdictionTag::EuMdr]);
+        assert_eq!(merged.base_engineering, base.base_engineering);
+        assert_eq!(merged.juris_local, vec![JurisdictionTag::JurisLocal]);
+        assert_eq!(merged.quantum_ai_safety, vec![JurisdictionTag::QuantumAiSafety]);
+        assert!(merged.is_satisfied());
+        assert!(base.conflicts_with(&local).is_empty());
+
+        // The local addition alone lacks BASE_ENGINEERING; merging two such stacks still does.
+        assert!(!local.is_satisfied());
+        assert!(!local.merge(&local).is_satisfied());
+    }
+
+    #[test]
+    fn test_policy_stack_conflicts_on_tag_in_different_categories() {
+        let base = PolicyStack::new();
+        let misfiled = PolicyStack {
+            base_medical: vec![],
+            base_engineering: vec![],
+            juris_local: vec![JurisdictionTag::Fda],
+            quantum_ai_safety: vec![],
+        };
+        assert_eq!(
+            base.conflicts_with(&misfiled),
+            vec!["Fda is BASE_MEDICAL here but JURIS_LOCAL in the other stack".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_to_dot_lists_states_and_added_transitions() {
+        let mut policy = ALNPolicy::new();
+        policy.transitions.clear();
+        policy.transitions.push(CapabilityTransition {
+            from: CapabilityState::LabBench,
+            to: CapabilityState::ControlledHuman,
+            required_evidence: vec!["cid:QmForward".into()],
+            required_consent: ConsentState::Extended,
+            required_roles: vec![Role::RegulatoryGuardian, Role::Mentor],
+            policy_stack: PolicyStack::new(),
+            ltl_property: None,
+        });
+        policy.transitions.push(CapabilityTransition {
+            from: CapabilityState::ControlledHuman,
+            to: CapabilityState::LabBench,
+            required_evidence: vec!["cid:QmRevert".into()],
+            required_consent: ConsentState::Minimal,
+            required_roles: vec![],
+            policy_stack: PolicyStack::new(),
+            ltl_property: None,
+        });
+
+        let dot = policy.to_dot();
+        assert!(dot.starts_with(&format!("digraph \"{}\" {{", policy.id)));
+        for node in [
+            "    model_only [label=\"ModelOnly\"];",
+            "    lab_bench [label=\"LabBench\"];",
+            "    controlled_human [label=\"ControlledHuman\"];",
+            "    general_use [label=\"GeneralUse\"];",
+        ] {
+            assert!(dot.contains(node), "missing {}", node);
+        }
+        assert!(dot.contains(
+            "    lab_bench -> controlled_human [label=\"consent: Extended\\nroles: RegulatoryGuardian, Mentor\"];"
+        ));
+        assert!(dot.contains(
+            "    controlled_human -> lab_bench [label=\"consent: Minimal\\nroles: none\", color=red, style=dashed];"
+        ));
+        assert_eq!(dot.matches(" -> ").count(), 2);
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_evidence_id_formats() {
+        assert_eq!(
+            EvidenceId::parse("cid:QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR"),
+            Ok(EvidenceId::Cid("QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR".to_string()))
+        );
+        let digest = format!("0x{}", "AB".repeat(32));
+        let hex: EvidenceId = digest.parse().unwrap();
+        assert_eq!(hex.to_string(), format!("0x{}", "ab".repeat(32)));
+
+        assert!(EvidenceId::parse("").unwrap_err().contains("not a recognized"));
+        assert!(EvidenceId::parse("not-a-hash").unwrap_err().contains("not a recognized"));
+        assert!(EvidenceId::parse("cid:").unwrap_err().contains("empty CID"));
+        assert!(EvidenceId::parse("cid:Qm0OIl").unwrap_err().contains("invalid character"));
+        assert!(EvidenceId::parse("0xabc").unwrap_err().contains("64-digit"));
+    }
+
+    #[test]
+    fn test_capability_transition_rejects_malformed_evidence_ids() {
+        for bad in ["", "not-a-hash"] {
+            let err = lab_to_controlled_with(EvidenceRef::new(bad)).validate().unwrap_err();
+            assert!(err.contains("not a recognized content identifier"), "{}", err);
+        }
+        let valid = EvidenceId::parse("cid:QmZ4HHEJgpNmDcc4yfqPQUjpA8nkMpN2JuaKPfsZKscbqR").unwrap();
+        assert!(lab_to_controlled_with(valid.into()).validate().is_ok());
+    }
+
+    #[test]
+    fn test_evidence_ref_deserializes_from_bare_string() {
+        let refs: Vec<EvidenceRef> =
+            serde_json::from_str(r#"["cid:QmA", {"id": "cid:QmB", "issued_utc": "2026-01-01T00:00:00Z"}]"#)
+                .unwrap();
+        assert_eq!(refs[0], EvidenceRef::new("cid:QmA"));
+        assert_eq!(refs[1].issued_utc.as_deref(), Some("2026-01-01T00:00:00Z"));
+    }
+
     #[test]
     fn test_action_permitted_model_only() {
         let policy = ALNPolicy::new();
         assert!(policy.is_action_permitted(
             CapabilityState::ModelOnly,
-            ConsentState::None,
+            &grant(ConsentState::None),
+            granted_now(),
             &[],
             "simulation_only_analysis"
         ));
@@ -321,23 +1751,182 @@ mod tests {
         let policy = ALNPolicy::new();
         assert!(!policy.is_action_permitted(
             CapabilityState::ControlledHuman,
-            ConsentState::None,
+            &grant(ConsentState::None),
+            granted_now(),
             &[Role::Learner],
             "live_coupling"
         ));
     }
 
+    #[test]
+    fn test_check_action_explains_controlled_human_permit() {
+        let policy = ALNPolicy::new();
+        let reason = policy.check_action(
+            CapabilityState::ControlledHuman,
+            ConsentState::Minimal,
+            &[Role::Mentor],
+            "bounded_eeg_observation",
+        );
+        assert_eq!(
+            reason,
+            ActionReason::Permitted {
+                satisfied: vec![
+                    PermitCondition::NoProhibitedHarmMatch,
+                    PermitCondition::ConsentAtLeastMinimal {
+                        consent: ConsentState::Minimal
+                    },
+                    PermitCondition::RolePresent {
+                        roles: vec![Role::Mentor]
+                    },
+                ]
+            }
+        );
+
+        let model_only = policy.check_action(
+            CapabilityState::ModelOnly,
+            ConsentState::None,
+            &[],
+            "simulation_only_analysis",
+        );
+        assert_eq!(
+            model_only.explain(),
+            "permitted because: no prohibited-harm match + ModelOnly + simulation-only"
+        );
+
+        assert_eq!(
+            policy.check_action(
+                CapabilityState::ControlledHuman,
+                ConsentState::Minimal,
+                &[],
+                "bounded_eeg_observation",
+            ),
+            ActionReason::DeniedNoRole
+        );
+    }
+
     #[test]
     fn test_prohibited_harms_blocked() {
         let policy = ALNPolicy::new();
         assert!(!policy.is_action_permitted(
             CapabilityState::GeneralUse,
-            ConsentState::Extended,
+            &grant(ConsentState::Extended),
+            granted_now(),
             &[Role::Learner],
             "coercive neuromodulation"
         ));
     }
 
+    #[test]
+    fn test_harm_matching_uses_action_kind_not_substring() {
+        let policy = ALNPolicy::new();
+        let permitted = |label: &str| {
+            policy.is_action_permitted(
+                CapabilityState::GeneralUse,
+                &grant(ConsentState::Extended),
+                granted_now(),
+                &[Role::Learner],
+                label,
+            )
+        };
+        assert!(permitted("non-coercive neuromodulation review"));
+        assert!(!permitted("Coercive_Neuromodulation"));
+        assert!(!permitted("automated neurobehavioral profiling"));
+        assert!(permitted("audit of no emotional manipulation claims"));
+
+        let reason = policy.check_structured_action(
+            CapabilityState::GeneralUse,
+            ConsentState::Extended,
+            &[Role::Learner],
+            &Action {
+                kind: ActionKind::CoerciveNeuromodulation,
+                labels: vec!["closed-loop stim protocol v2".to_string()],
+            },
+        );
+        assert_eq!(
+            reason,
+            ActionReason::DeniedProhibitedHarm {
+                harm: "coercive neuromodulation".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_composite_label_with_harm_phrase_denied() {
+        let mut policy = ALNPolicy::new();
+        let check = |policy: &ALNPolicy, label: &str| {
+            policy.check_action(
+                CapabilityState::GeneralUse,
+                ConsentState::Extended,
+                &[Role::Learner],
+                label,
+            )
+        };
+        assert_eq!(
+            ActionKind::from_label("apply coercive neuromodulation to subject"),
+            ActionKind::CoerciveNeuromodulation
+        );
+        assert_eq!(
+            check(&policy, "apply coercive neuromodulation to subject"),
+            ActionReason::DeniedProhibitedHarm {
+                harm: "coercive neuromodulation".to_string()
+            }
+        );
+        assert!(check(&policy, "run non-coercive neuromodulation to subject").is_permitted());
+
+        policy.policy_stack.juris_local.push(JurisdictionTag::JurisLocal);
+        policy
+            .jurisdiction_harms
+            .insert(JurisdictionTag::JurisLocal, vec!["subliminal affect priming".to_string()]);
+        assert!(!check(&policy, "Nightly subliminal-affect-priming batch").is_permitted());
+        assert!(check(&policy, "anti subliminal affect priming filter").is_permitted());
+    }
+
+    #[test]
+    fn test_jurisdiction_overlay_blocks_action() {
+        let mut policy = ALNPolicy::new();
+        let action = "subliminal affect priming";
+        assert!(policy.is_action_permitted(
+            CapabilityState::GeneralUse,
+            &grant(ConsentState::Extended),
+            granted_now(),
+            &[Role::Learner],
+            action
+        ));
+
+        policy.policy_stack.juris_local.push(JurisdictionTag::JurisLocal);
+        policy
+            .jurisdiction_harms
+            .insert(JurisdictionTag::JurisLocal, vec!["subliminal affect priming".to_string()]);
+        assert!(!policy.is_action_permitted(
+            CapabilityState::GeneralUse,
+            &grant(ConsentState::Extended),
+            granted_now(),
+            &[Role::Learner],
+            action
+        ));
+    }
+
+    #[test]
+    fn test_jurisdiction_overlay_inactive_outside_stack() {
+        let mut policy = ALNPolicy::new();
+        policy
+            .jurisdiction_harms
+            .insert(JurisdictionTag::JurisLocal, vec!["subliminal affect priming".to_string()]);
+        // JURIS_LOCAL is not part of the default stack, so its overlay does not apply,
+        // but base harms are still enforced.
+        assert!(policy.is_action_permitted(
+            CapabilityState::GeneralUse,
+            &grant(ConsentState::Extended),
+            granted_now(),
+            &[Role::Learner],
+            "subliminal affect priming"
+        ));
+        assert_eq!(
+            policy.effective_prohibited_harms().len(),
+            policy.prohibited_harms.len()
+        );
+    }
+
     #[test]
     fn test_default_policy_structure() {
         let policy = ALNPolicy::new();
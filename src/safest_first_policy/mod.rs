@@ -0,0 +1 @@
+pub mod aln_schema;